@@ -53,6 +53,8 @@ pub async fn fetch_data_planes<'a, 'b>(
             data_plane_fqdn,
             broker_address,
             reactor_address,
+            connector_cpu_limit_max,
+            connector_memory_limit_max,
             ops_logs_name as "ops_logs_name: models::Collection",
             ops_stats_name as "ops_stats_name: models::Collection"
         from data_planes
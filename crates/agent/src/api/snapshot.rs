@@ -201,6 +201,8 @@ async fn try_fetch(pg_pool: &sqlx::PgPool) -> anyhow::Result<Snapshot> {
                 hmac_keys,
                 broker_address,
                 reactor_address,
+                connector_cpu_limit_max,
+                connector_memory_limit_max,
                 ops_logs_name as "ops_logs_name: models::Collection",
                 ops_stats_name as "ops_stats_name: models::Collection"
             from data_planes
@@ -85,6 +85,7 @@ async fn do_update_l2_reporting(
 
     let models::DeriveUsing::Typescript(models::DeriveUsingTypescript {
         module: l2_stats_module_raw,
+        ..
     }) = l2_stats_using
     else {
         anyhow::bail!("L2 stats derivation must be a TypeScript module")
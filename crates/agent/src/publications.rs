@@ -562,13 +562,7 @@ pub fn partitions(projections: &BTreeMap<models::Field, models::Projection>) ->
     projections
         .iter()
         .filter_map(|(field, proj)| {
-            if matches!(
-                proj,
-                models::Projection::Extended {
-                    partition: true,
-                    ..
-                }
-            ) {
+            if proj.as_parts().1 {
                 Some(field.to_string())
             } else {
                 None
@@ -276,6 +276,7 @@ pub fn shard_template(
         read_channel_size,
         ring_buffer_size,
         log_level,
+        resources,
     } = shard;
 
     // We hard-code that recovery logs always have prefix "recovery".
@@ -320,6 +321,29 @@ pub fn shard_template(
         (labels::TASK_TYPE, &task_type.to_string()),
     ]);
 
+    // Resources are validated, and invalid memory sizes are not expected here.
+    if let Some(models::ConnectorResources {
+        cpu_request,
+        cpu_limit,
+        memory_request,
+        memory_limit,
+    }) = resources
+    {
+        let memory = |size: &Option<String>| {
+            size.as_deref()
+                .and_then(models::ConnectorResources::parse_memory)
+        };
+        labels = labels::shard::encode_connector_resources(
+            labels,
+            &labels::shard::ConnectorResources {
+                cpu_request: *cpu_request,
+                cpu_limit: *cpu_limit,
+                memory_request: memory(memory_request),
+                memory_limit: memory(memory_limit),
+            },
+        );
+    }
+
     // Only add a hostname if the task actually exposes any ports.
     if !ports.is_empty() {
         labels = labels::add_value(labels, labels::HOSTNAME, &shard_hostname_label(task_name));
@@ -594,4 +618,27 @@ mod test {
         let labels = journal_selector(&collection, Some(&selector));
         insta::assert_debug_snapshot!(labels);
     }
+
+    #[test]
+    fn shard_template_encodes_connector_resources() {
+        let shard = models::ShardTemplate {
+            resources: Some(models::ConnectorResources {
+                cpu_request: Some(0.5),
+                cpu_limit: Some(1.5),
+                memory_request: Some("512m".to_string()),
+                memory_limit: None,
+            }),
+            ..Default::default()
+        };
+        let spec = shard_template(
+            models::Id::zero(),
+            "acmeCo/a/capture",
+            "capture",
+            &shard,
+            "capture/acmeCo/a/capture/0011223344556677",
+            false,
+            &[],
+        );
+        insta::assert_debug_snapshot!(spec.labels);
+    }
 }
@@ -418,6 +418,8 @@ impl tables::CatalogResolver for NoOpCatalogResolver {
                 models::Collection::new("ops/stats"),
                 "broker:address".to_string(),
                 "reactor:address".to_string(),
+                4.0,
+                8 << 30,
             );
 
             live
@@ -0,0 +1,200 @@
+use super::Mapping;
+use anyhow::Context;
+use futures::channel::mpsc;
+use futures::{SinkExt, StreamExt};
+use proto_flow::{
+    derive::{request, response, Request, Response},
+    flow,
+};
+
+pub fn connector<R>(request_rx: R) -> mpsc::Receiver<anyhow::Result<Response>>
+where
+    R: futures::stream::Stream<Item = Request> + Send + 'static,
+{
+    let (mut response_tx, response_rx) = mpsc::channel(16);
+
+    tokio::runtime::Handle::current().spawn_blocking(move || {
+        futures::executor::block_on(async move {
+            if let Err(status) = serve(request_rx, &mut response_tx).await {
+                _ = response_tx.send(Err(status)).await;
+            }
+        })
+    });
+
+    response_rx
+}
+
+async fn serve<R>(
+    mut request_rx: R,
+    response_tx: &mut mpsc::Sender<anyhow::Result<Response>>,
+) -> anyhow::Result<()>
+where
+    R: futures::stream::Stream<Item = Request>,
+{
+    let mut request_rx = std::pin::pin!(request_rx);
+    // Compiled mappings of the last Request.Open, indexed by transform.
+    let mut maybe_mappings: Option<Vec<(String, Mapping)>> = None;
+
+    loop {
+        match request_rx.next().await {
+            None => return Ok(()),
+            Some(Request {
+                validate: Some(validate),
+                ..
+            }) => {
+                let validated = do_validate(validate).map_err(anyhow_to_status)?;
+
+                let _ = response_tx
+                    .send(Ok(Response {
+                        validated: Some(validated),
+                        ..Default::default()
+                    }))
+                    .await;
+            }
+            Some(Request {
+                open: Some(open), ..
+            }) => {
+                maybe_mappings = Some(parse_open(open).map_err(anyhow_to_status)?);
+
+                // Mappings are stateless, so there's no runtime checkpoint to recover.
+                let _ = response_tx
+                    .send(Ok(Response {
+                        opened: Some(response::Opened {}),
+                        ..Default::default()
+                    }))
+                    .await;
+            }
+            Some(Request {
+                read: Some(read), ..
+            }) => {
+                let mappings = maybe_mappings
+                    .as_ref()
+                    .ok_or_else(|| tonic::Status::invalid_argument("Read without Open"))?;
+
+                if let Some(doc_json) = do_read(mappings, read).map_err(anyhow_to_status)? {
+                    let _ = response_tx
+                        .send(Ok(Response {
+                            published: Some(response::Published { doc_json }),
+                            ..Default::default()
+                        }))
+                        .await;
+                }
+            }
+            Some(Request {
+                flush: Some(request::Flush {}),
+                ..
+            }) => {
+                // Send Flushed to runtime.
+                let _ = response_tx
+                    .send(Ok(Response {
+                        flushed: Some(response::Flushed {}),
+                        ..Default::default()
+                    }))
+                    .await;
+            }
+            Some(Request {
+                start_commit: Some(request::StartCommit { .. }),
+                ..
+            }) => {
+                // Send StartedCommit to runtime.
+                let _ = response_tx
+                    .send(Ok(Response {
+                        started_commit: Some(response::StartedCommit { state: None }),
+                        ..Default::default()
+                    }))
+                    .await;
+            }
+            Some(Request {
+                reset: Some(request::Reset {}),
+                ..
+            }) => {
+                // Mappings have no state to reset.
+            }
+            Some(malformed) => Err(tonic::Status::invalid_argument(format!(
+                "invalid request {malformed:?}"
+            )))?,
+        }
+    }
+}
+
+fn do_validate(validate: request::Validate) -> anyhow::Result<response::Validated> {
+    let request::Validate { transforms, .. } = &validate;
+
+    let transforms = transforms
+        .iter()
+        .map(|transform| {
+            let request::validate::Transform {
+                name,
+                lambda_config_json,
+                shuffle_lambda_config_json,
+                ..
+            } = transform;
+
+            if !shuffle_lambda_config_json.is_empty() {
+                anyhow::bail!(
+                    "transform {name} uses a shuffle lambda, which mapping derivations do not support"
+                );
+            }
+            Mapping::parse(lambda_config_json)
+                .with_context(|| format!("invalid mapping of transform {name}"))?;
+
+            // Mappings are stateless, and are always read-only.
+            Ok(response::validated::Transform { read_only: true })
+        })
+        .collect::<anyhow::Result<_>>()?;
+
+    Ok(response::Validated {
+        transforms,
+        generated_files: Default::default(),
+    })
+}
+
+fn parse_open(open: request::Open) -> anyhow::Result<Vec<(String, Mapping)>> {
+    let flow::CollectionSpec { derivation, .. } = open
+        .collection
+        .context("`open` missing required `collection`")?;
+    let flow::collection_spec::Derivation { transforms, .. } = derivation
+        .as_ref()
+        .context("`collection` missing required `derivation`")?;
+
+    transforms
+        .iter()
+        .map(|transform| {
+            let flow::collection_spec::derivation::Transform {
+                name,
+                lambda_config_json,
+                ..
+            } = transform;
+
+            let mapping = Mapping::parse(lambda_config_json)
+                .with_context(|| format!("invalid mapping of transform {name}"))?;
+
+            Ok((name.clone(), mapping))
+        })
+        .collect()
+}
+
+fn do_read(mappings: &[(String, Mapping)], read: request::Read) -> anyhow::Result<Option<String>> {
+    let request::Read {
+        transform,
+        doc_json,
+        ..
+    } = read;
+
+    let (name, mapping) = mappings
+        .get(transform as usize)
+        .with_context(|| format!("invalid transform index {transform}"))?;
+
+    let doc: serde_json::Value = serde_json::from_str(&doc_json)
+        .with_context(|| format!("couldn't parse read document as JSON: {doc_json}"))?;
+
+    let output = mapping.apply(&doc).with_context(|| {
+        format!("failed to map document of transform {name:?}\nDocument was {doc_json}")
+    })?;
+
+    Ok(output.map(|output| output.to_string()))
+}
+
+fn anyhow_to_status(err: anyhow::Error) -> tonic::Status {
+    tonic::Status::internal(format!("{err:#}"))
+}
@@ -0,0 +1,5 @@
+mod connector;
+mod mapping;
+
+pub use connector::connector;
+pub use mapping::Mapping;
@@ -0,0 +1,346 @@
+use anyhow::Context;
+use serde_json::Value;
+use std::cmp::Ordering;
+
+/// Mapping is a compiled MappingLambda of a transform.
+#[derive(Debug)]
+pub struct Mapping {
+    predicates: Vec<(doc::Pointer, Test)>,
+    fields: Vec<(doc::Pointer, Source, Option<models::MappingCast>)>,
+}
+
+#[derive(Debug)]
+enum Test {
+    Eq(Value),
+    In(Vec<Value>),
+    Exists(bool),
+    Range(Option<Value>, Option<Value>),
+}
+
+#[derive(Debug)]
+enum Source {
+    From(doc::Pointer),
+    Const(Value),
+}
+
+impl Mapping {
+    /// Parse and compile the JSON-encoded MappingLambda of a transform.
+    pub fn parse(lambda_config_json: &str) -> anyhow::Result<Self> {
+        let lambda: models::MappingLambda = serde_json::from_str(lambda_config_json)
+            .with_context(|| format!("failed to parse mapping lambda: {lambda_config_json}"))?;
+
+        Self::new(lambda)
+    }
+
+    pub fn new(lambda: models::MappingLambda) -> anyhow::Result<Self> {
+        let models::MappingLambda { filter, fields } = lambda;
+
+        let predicates = filter
+            .into_iter()
+            .map(|predicate| {
+                let models::MappingPredicate {
+                    ptr,
+                    eq,
+                    in_,
+                    exists,
+                    range,
+                } = predicate;
+
+                let test = match (eq, in_, exists, range) {
+                    (Some(eq), None, None, None) => Test::Eq(eq),
+                    (None, Some(in_), None, None) => Test::In(in_),
+                    (None, None, Some(exists), None) => Test::Exists(exists),
+                    (None, None, None, Some(models::MappingRange { minimum, maximum })) => {
+                        Test::Range(minimum, maximum)
+                    }
+                    _ => anyhow::bail!(
+                        "predicate of {ptr} must have exactly one of `eq`, `in`, `exists`, or `range`"
+                    ),
+                };
+                Ok((doc::Pointer::from_str(&ptr), test))
+            })
+            .collect::<anyhow::Result<_>>()?;
+
+        let fields = fields
+            .into_iter()
+            .map(|(ptr, field)| {
+                let models::MappingField { from, const_, cast } = field;
+
+                let source = match (from, const_) {
+                    (Some(from), None) => Source::From(doc::Pointer::from_str(&from)),
+                    (None, Some(value)) => Source::Const(value),
+                    _ => anyhow::bail!("field {ptr} must have exactly one of `from` or `const`"),
+                };
+                Ok((doc::Pointer::from_str(&ptr), source, cast))
+            })
+            .collect::<anyhow::Result<_>>()?;
+
+        Ok(Self { predicates, fields })
+    }
+
+    /// Map a source document into its output document,
+    /// or return None if the document doesn't match the mapping's filter.
+    pub fn apply(&self, doc: &Value) -> anyhow::Result<Option<Value>> {
+        for (ptr, test) in &self.predicates {
+            if !test.matches(ptr.query(doc)) {
+                return Ok(None);
+            }
+        }
+
+        let mut output = Value::Object(Default::default());
+
+        for (ptr, source, cast) in &self.fields {
+            let value = match source {
+                Source::From(from) => match from.query(doc) {
+                    Some(value) => value.clone(),
+                    None => continue, // Omit locations which don't exist.
+                },
+                Source::Const(value) => value.clone(),
+            };
+            let value = match cast {
+                Some(cast) => apply_cast(*cast, value).with_context(|| format!("field {ptr}"))?,
+                None => value,
+            };
+
+            let location = ptr
+                .create_value(&mut output)
+                .with_context(|| format!("output location {ptr} conflicts with another field"))?;
+            *location = value;
+        }
+
+        Ok(Some(output))
+    }
+}
+
+impl Test {
+    fn matches(&self, value: Option<&Value>) -> bool {
+        match (self, value) {
+            (Test::Exists(exists), value) => *exists == value.is_some(),
+            (_, None) => false,
+            (Test::Eq(eq), Some(value)) => doc::compare(eq, value) == Ordering::Equal,
+            (Test::In(values), Some(value)) => values
+                .iter()
+                .any(|eq| doc::compare(eq, value) == Ordering::Equal),
+            (Test::Range(minimum, maximum), Some(value)) => {
+                in_range(minimum.as_ref(), value, Ordering::Less)
+                    && in_range(maximum.as_ref(), value, Ordering::Greater)
+            }
+        }
+    }
+}
+
+// Is `value` on the correct side of an inclusive `bound`?
+// Numbers are only comparable with numbers, and strings with strings.
+fn in_range(bound: Option<&Value>, value: &Value, outside: Ordering) -> bool {
+    match (bound, value) {
+        (None, _) => true,
+        (Some(bound @ Value::Number(_)), Value::Number(_))
+        | (Some(bound @ Value::String(_)), Value::String(_)) => {
+            doc::compare(value, bound) != outside
+        }
+        _ => false,
+    }
+}
+
+fn apply_cast(cast: models::MappingCast, value: Value) -> anyhow::Result<Value> {
+    use models::MappingCast as Cast;
+
+    Ok(match (cast, value) {
+        // Nulls are not cast.
+        (_, Value::Null) => Value::Null,
+
+        (Cast::Boolean, Value::Bool(b)) => Value::Bool(b),
+        (Cast::Boolean, Value::Number(n)) => Value::Bool(n.as_f64() != Some(0.0)),
+        (Cast::Boolean, Value::String(s)) => match s.as_str() {
+            "true" => Value::Bool(true),
+            "false" => Value::Bool(false),
+            _ => anyhow::bail!("cannot cast string {s:?} to a boolean"),
+        },
+
+        (Cast::Integer, Value::Bool(b)) => Value::from(b as i64),
+        (Cast::Integer, Value::Number(n)) if n.is_i64() || n.is_u64() => Value::Number(n),
+        (Cast::Integer, Value::Number(n)) => {
+            let f = n.as_f64().unwrap().trunc();
+            // Bounds are exclusive because i64::MAX and u64::MAX round up as f64.
+            if f >= i64::MIN as f64 && f < i64::MAX as f64 {
+                Value::from(f as i64)
+            } else if f >= 0.0 && f < u64::MAX as f64 {
+                Value::from(f as u64)
+            } else {
+                anyhow::bail!("cannot cast number {n} to an integer, as it's out of range")
+            }
+        }
+        (Cast::Integer, Value::String(s)) => {
+            if let Ok(i) = s.trim().parse::<i64>() {
+                Value::from(i)
+            } else if let Ok(u) = s.trim().parse::<u64>() {
+                Value::from(u)
+            } else {
+                anyhow::bail!("cannot cast string {s:?} to an integer")
+            }
+        }
+
+        (Cast::Number, Value::Bool(b)) => Value::from(b as i64 as f64),
+        (Cast::Number, Value::Number(n)) => Value::Number(n),
+        (Cast::Number, Value::String(s)) => match s.trim().parse::<f64>() {
+            Ok(f) if f.is_finite() => Value::from(f),
+            _ => anyhow::bail!("cannot cast string {s:?} to a number"),
+        },
+
+        (Cast::String, Value::String(s)) => Value::String(s),
+        (Cast::String, Value::Bool(b)) => Value::String(b.to_string()),
+        (Cast::String, Value::Number(n)) => Value::String(n.to_string()),
+        (Cast::String, value @ (Value::Array(_) | Value::Object(_))) => {
+            Value::String(value.to_string())
+        }
+
+        (cast, value) => anyhow::bail!("cannot cast {value} to {cast:?}"),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    fn mapping(lambda: Value) -> Mapping {
+        Mapping::new(serde_json::from_value(lambda).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_projection_and_constants() {
+        let m = mapping(json!({
+            "fields": {
+                "/id": {"from": "/order_id"},
+                "/customer/name": {"from": "/name"},
+                "/missing": {"from": "/not/there"},
+                "/source": {"const": "orders"},
+                "/tags/0": {"const": true},
+            }
+        }));
+
+        let out = m
+            .apply(&json!({"order_id": 32, "name": "Jane", "other": 1}))
+            .unwrap();
+
+        assert_eq!(
+            out,
+            Some(json!({
+                "id": 32,
+                "customer": {"name": "Jane"},
+                "source": "orders",
+                "tags": [true],
+            }))
+        );
+    }
+
+    #[test]
+    fn test_predicates() {
+        let m = mapping(json!({
+            "filter": [
+                {"ptr": "/status", "in": ["active", "pending"]},
+                {"ptr": "/kind", "eq": 1},
+                {"ptr": "/deleted", "exists": false},
+                {"ptr": "/amount", "range": {"minimum": 0, "maximum": 100.5}},
+            ],
+            "fields": {"/id": {"from": "/id"}}
+        }));
+
+        let cases = [
+            (
+                json!({"id": 1, "status": "active", "kind": 1.0, "amount": 0}),
+                true,
+            ),
+            (
+                json!({"id": 2, "status": "pending", "kind": 1, "amount": 100.5}),
+                true,
+            ),
+            (
+                json!({"id": 3, "status": "closed", "kind": 1, "amount": 10}),
+                false,
+            ),
+            (
+                json!({"id": 4, "status": "active", "kind": 2, "amount": 10}),
+                false,
+            ),
+            (
+                json!({"id": 5, "status": "active", "kind": 1, "amount": 10, "deleted": null}),
+                false,
+            ),
+            (
+                json!({"id": 6, "status": "active", "kind": 1, "amount": -1}),
+                false,
+            ),
+            (
+                json!({"id": 7, "status": "active", "kind": 1, "amount": 101}),
+                false,
+            ),
+            (
+                json!({"id": 8, "status": "active", "kind": 1, "amount": "10"}),
+                false,
+            ),
+            (json!({"id": 9, "status": "active", "kind": 1}), false),
+        ];
+
+        for (doc, expect) in cases {
+            let out = m.apply(&doc).unwrap();
+            assert_eq!(out.is_some(), expect, "{doc}");
+        }
+    }
+
+    #[test]
+    fn test_casts() {
+        let m = mapping(json!({
+            "fields": {
+                "/b": {"from": "/b", "cast": "boolean"},
+                "/i": {"from": "/i", "cast": "integer"},
+                "/n": {"from": "/n", "cast": "number"},
+                "/s": {"from": "/s", "cast": "string"},
+                "/null": {"from": "/null", "cast": "integer"},
+            }
+        }));
+
+        let out = m
+            .apply(&json!({"b": "true", "i": "-42", "n": "1.5", "s": {"a": 1}, "null": null}))
+            .unwrap();
+        assert_eq!(
+            out,
+            Some(json!({"b": true, "i": -42, "n": 1.5, "s": "{\"a\":1}", "null": null}))
+        );
+
+        let out = m
+            .apply(&json!({"b": 0, "i": 3.7, "n": true, "s": 12}))
+            .unwrap();
+        assert_eq!(out, Some(json!({"b": false, "i": 3, "n": 1.0, "s": "12"})));
+
+        let out = m.apply(&json!({"i": 1.5e19})).unwrap();
+        assert_eq!(out, Some(json!({"i": 15000000000000000000u64})));
+
+        let err = m.apply(&json!({"i": "nope"})).unwrap_err();
+        assert_eq!(
+            format!("{err:#}"),
+            r#"field /i: cannot cast string "nope" to an integer"#
+        );
+
+        let err = m.apply(&json!({"i": -1e30})).unwrap_err();
+        assert_eq!(
+            format!("{err:#}"),
+            "field /i: cannot cast number -1e30 to an integer, as it's out of range"
+        );
+    }
+
+    #[test]
+    fn test_invalid_lambdas() {
+        let err = Mapping::parse(r#"{"fields": {"/a": {"from": "/a", "const": 1}}}"#).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "field /a must have exactly one of `from` or `const`"
+        );
+
+        let err = Mapping::parse(r#"{"filter": [{"ptr": "/a"}], "fields": {}}"#).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "predicate of /a must have exactly one of `eq`, `in`, `exists`, or `range`"
+        );
+    }
+}
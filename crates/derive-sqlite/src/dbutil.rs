@@ -1,4 +1,4 @@
-use super::{is_url_to_generate, Error, Lambda, Transform};
+use super::{functions, is_url_to_generate, Error, Lambda, Transform};
 use anyhow::Context;
 use prost::Message;
 use proto_flow::RuntimeCheckpoint;
@@ -6,6 +6,7 @@ use rusqlite::Connection;
 
 pub fn open(uri: &str, migrations: &[String]) -> anyhow::Result<(Connection, RuntimeCheckpoint)> {
     let conn = Connection::open(uri)?;
+    functions::register(&conn).context("failed to register SQL functions")?;
 
     // TODO(johnny): Lock it down.
 
@@ -0,0 +1,362 @@
+use rusqlite::functions::{Aggregate, Context, FunctionFlags};
+use rusqlite::types::{Value, ValueRef};
+use rusqlite::Connection;
+
+/// Register Flow-specific SQL functions with the connection:
+///
+/// * `flow_ptr(doc, ptr)` extracts the location of JSON Pointer `ptr` from
+///   JSON document `doc`. Missing locations and JSON nulls are NULL, booleans
+///   are integers 0 or 1, and nested arrays and objects are JSON text.
+/// * `flow_uuid_timestamp(uuid)` decodes the publication timestamp of a Flow
+///   UUID, such as `$_meta$uuid`, as RFC 3339 text.
+/// * `flow_pack(v1, v2, ...)` packs its arguments as a FoundationDB tuple,
+///   equivalent to the packing of a collection key with `tuple::pack`.
+/// * `approx_count_distinct(v)` is an aggregate which estimates the number
+///   of distinct, non-NULL values using a HyperLogLog sketch.
+pub fn register(conn: &Connection) -> rusqlite::Result<()> {
+    // FunctionFlags isn't Copy, so build it for each registration.
+    let flags = || FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC;
+
+    conn.create_scalar_function("flow_ptr", 2, flags(), flow_ptr)?;
+    conn.create_scalar_function("flow_uuid_timestamp", 1, flags(), flow_uuid_timestamp)?;
+    conn.create_scalar_function("flow_pack", -1, flags(), flow_pack)?;
+    conn.create_aggregate_function("approx_count_distinct", 1, flags(), ApproxCountDistinct)?;
+
+    Ok(())
+}
+
+fn flow_ptr(ctx: &Context<'_>) -> rusqlite::Result<Value> {
+    let doc = match ctx.get_raw(0) {
+        ValueRef::Null => return Ok(Value::Null),
+        ValueRef::Text(doc) | ValueRef::Blob(doc) => doc,
+        _ => return Err(user_error("flow_ptr document must be JSON text")),
+    };
+    let ptr: String = ctx.get(1)?;
+
+    let doc: serde_json::Value = serde_json::from_slice(doc).map_err(user_error)?;
+    let ptr = doc::Pointer::from_str(&ptr);
+
+    Ok(match ptr.query(&doc) {
+        None | Some(serde_json::Value::Null) => Value::Null,
+        Some(serde_json::Value::Bool(b)) => Value::Integer(*b as i64),
+        Some(serde_json::Value::Number(n)) => {
+            if let Some(i) = n.as_i64() {
+                Value::Integer(i)
+            } else {
+                Value::Real(n.as_f64().unwrap_or(f64::NAN))
+            }
+        }
+        Some(serde_json::Value::String(s)) => Value::Text(s.clone()),
+        Some(nested) => Value::Text(nested.to_string()),
+    })
+}
+
+fn flow_uuid_timestamp(ctx: &Context<'_>) -> rusqlite::Result<Option<String>> {
+    let uuid = match ctx.get_raw(0) {
+        ValueRef::Null => return Ok(None),
+        ValueRef::Text(uuid) => std::str::from_utf8(uuid).map_err(user_error)?,
+        _ => return Err(user_error("flow_uuid_timestamp UUID must be text")),
+    };
+    let (_producer, clock, _flags) = proto_gazette::uuid::parse_str(uuid).map_err(user_error)?;
+
+    let (seconds, nanos) = clock.to_unix();
+    let ts = time::OffsetDateTime::from_unix_timestamp_nanos(
+        seconds as i128 * 1_000_000_000 + nanos as i128,
+    )
+    .map_err(user_error)?;
+
+    Ok(Some(
+        ts.format(&time::format_description::well_known::Rfc3339)
+            .map_err(user_error)?,
+    ))
+}
+
+fn flow_pack(ctx: &Context<'_>) -> rusqlite::Result<Vec<u8>> {
+    let elements: Vec<tuple::Element> = (0..ctx.len())
+        .map(|index| value_to_element(ctx.get_raw(index)))
+        .collect::<rusqlite::Result<_>>()?;
+
+    Ok(tuple::pack(&elements))
+}
+
+// Map a SQLite value into a tuple Element.
+// SQLite has no boolean type, and booleans are packed as integers.
+fn value_to_element<'v>(value: ValueRef<'v>) -> rusqlite::Result<tuple::Element<'v>> {
+    Ok(match value {
+        ValueRef::Null => tuple::Element::Nil,
+        ValueRef::Integer(i) => tuple::Element::Int(i),
+        ValueRef::Real(f) => tuple::Element::Double(f),
+        ValueRef::Text(s) => {
+            tuple::Element::String(std::str::from_utf8(s).map_err(user_error)?.into())
+        }
+        ValueRef::Blob(b) => tuple::Element::Bytes(b.into()),
+    })
+}
+
+/// ApproxCountDistinct estimates the cardinality of its values
+/// using a HyperLogLog sketch having 2^PRECISION registers.
+struct ApproxCountDistinct;
+
+impl ApproxCountDistinct {
+    const PRECISION: u32 = 12;
+    const REGISTERS: usize = 1 << Self::PRECISION;
+}
+
+impl Aggregate<Vec<u8>, i64> for ApproxCountDistinct {
+    fn init(&self, _ctx: &mut Context<'_>) -> rusqlite::Result<Vec<u8>> {
+        Ok(vec![0; Self::REGISTERS])
+    }
+
+    fn step(&self, ctx: &mut Context<'_>, registers: &mut Vec<u8>) -> rusqlite::Result<()> {
+        let value = ctx.get_raw(0);
+        if let ValueRef::Null = value {
+            return Ok(()); // Like COUNT(DISTINCT), NULLs are not counted.
+        }
+        // Hash the packed value, so that values of different types are distinct.
+        let hash = xxhash_rust::xxh3::xxh3_64(&tuple::pack(&value_to_element(value)?));
+
+        let index = (hash >> (64 - Self::PRECISION)) as usize;
+        let rank = ((hash << Self::PRECISION) | (1 << (Self::PRECISION - 1))).leading_zeros() + 1;
+
+        registers[index] = registers[index].max(rank as u8);
+        Ok(())
+    }
+
+    fn finalize(
+        &self,
+        _ctx: &mut Context<'_>,
+        registers: Option<Vec<u8>>,
+    ) -> rusqlite::Result<i64> {
+        let Some(registers) = registers else {
+            return Ok(0); // No rows were aggregated.
+        };
+        let m = Self::REGISTERS as f64;
+
+        let sum: f64 = registers.iter().map(|r| 2f64.powi(-(*r as i32))).sum();
+        let zeros = registers.iter().filter(|r| **r == 0).count();
+
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let mut estimate = alpha * m * m / sum;
+
+        // Use linear counting for small cardinalities.
+        if estimate <= 2.5 * m && zeros != 0 {
+            estimate = m * (m / zeros as f64).ln();
+        }
+        Ok(estimate.round() as i64)
+    }
+}
+
+fn user_error<E>(err: E) -> rusqlite::Error
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+{
+    rusqlite::Error::UserFunctionError(err.into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_param;
+    use super::super::Lambda;
+    use serde_json::json;
+
+    fn open() -> rusqlite::Connection {
+        let db = rusqlite::Connection::open_in_memory().unwrap();
+        super::register(&db).unwrap();
+        db
+    }
+
+    #[test]
+    fn test_flow_ptr() {
+        let db = open();
+        let mut lambda = Lambda::new(
+            &db,
+            r#"
+            select
+              flow_ptr($doc, '/str') as str,
+              flow_ptr($doc, '/int') as int,
+              flow_ptr($doc, '/float') as float,
+              flow_ptr($doc, '/bool') as bool,
+              flow_ptr($doc, '/null') as "null",
+              flow_ptr($doc, '/missing') as missing,
+              flow_ptr($doc, '/arr/1') as arr_item,
+              flow_ptr($doc, '/obj') as obj,
+              flow_ptr($doc, '/obj/a~1b') as escaped,
+              flow_ptr(null, '/str') as null_doc;
+            "#,
+            &[test_param("doc", "", false, false, false)],
+        )
+        .unwrap();
+
+        let output = lambda
+            .invoke_vec(&json!({
+                "str": "hello",
+                "int": -42,
+                "float": 1.5,
+                "bool": true,
+                "null": null,
+                "arr": ["zero", "one"],
+                "obj": {"a/b": 1},
+            }))
+            .unwrap();
+
+        insta::assert_json_snapshot!(output, @r###"
+        [
+          {
+            "arr_item": "one",
+            "bool": 1,
+            "escaped": 1,
+            "float": 1.5,
+            "int": -42,
+            "missing": null,
+            "null": null,
+            "null_doc": null,
+            "obj": {
+              "a/b": 1
+            },
+            "str": "hello"
+          }
+        ]
+        "###);
+
+        let err = Lambda::new(&db, "select flow_ptr('{not json', '/a');", &[])
+            .unwrap()
+            .invoke_vec(&serde_json::Value::Null)
+            .unwrap_err();
+        assert!(err.to_string().contains("key must be a string"), "{err}");
+
+        // Integers which overflow i64 are mapped to REAL.
+        let output = Lambda::new(
+            &db,
+            "select flow_ptr('[18446744073709551615]', '/0') as big;",
+            &[],
+        )
+        .unwrap()
+        .invoke_vec(&serde_json::Value::Null)
+        .unwrap();
+        assert_eq!(output[0]["big"], json!(u64::MAX as f64));
+    }
+
+    #[test]
+    fn test_flow_uuid_timestamp() {
+        let db = open();
+        let mut lambda = Lambda::new(
+            &db,
+            r#"
+            select
+              flow_uuid_timestamp($_meta$uuid) as ts,
+              unixepoch(flow_uuid_timestamp($_meta$uuid)) as unix,
+              flow_uuid_timestamp(null) as null_uuid;
+            "#,
+            &[test_param("_meta/uuid", "/_meta/uuid", false, false, false)],
+        )
+        .unwrap();
+
+        let output = lambda
+            .invoke_vec(&json!({"_meta": {"uuid": "9f2952f3-c6a3-11ea-8802-080607050309"}}))
+            .unwrap();
+
+        insta::assert_json_snapshot!(output, @r###"
+        [
+          {
+            "null_uuid": null,
+            "ts": "2020-07-15T14:01:04.047589108Z",
+            "unix": 1594821664
+          }
+        ]
+        "###);
+
+        let err = Lambda::new(&db, "select flow_uuid_timestamp('not-a-uuid');", &[])
+            .unwrap()
+            .invoke_vec(&serde_json::Value::Null)
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("failed to parse document UUID"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_flow_pack() {
+        let db = open();
+        let mut lambda = Lambda::new(
+            &db,
+            "select flow_pack($a, $b, 3.5, null, cast('bytes' as blob)) as packed;",
+            &[
+                test_param("a", "/a", false, false, false),
+                test_param("b", "/b", false, false, false),
+            ],
+        )
+        .unwrap();
+
+        let output = lambda.invoke_vec(&json!({"a": "one", "b": 2})).unwrap();
+
+        // Packed blobs are mapped into base64.
+        let packed = base64::decode(output[0]["packed"].as_str().unwrap()).unwrap();
+        let expect = tuple::pack(&(
+            "one",
+            2i64,
+            3.5f64,
+            Option::<i64>::None,
+            tuple::Bytes::from(b"bytes".as_slice()),
+        ));
+        assert_eq!(packed, expect);
+
+        // Packing matches that of a key extracted from a document.
+        let extractors = [
+            doc::Extractor::new("/a", &doc::SerPolicy::noop()),
+            doc::Extractor::new("/b", &doc::SerPolicy::noop()),
+        ];
+        let mut key = Vec::new();
+        for ex in &extractors {
+            ex.extract(&json!({"a": "one", "b": 2}), &mut key).unwrap();
+        }
+        let output = Lambda::new(&db, "select flow_pack('one', 2) as packed;", &[])
+            .unwrap()
+            .invoke_vec(&serde_json::Value::Null)
+            .unwrap();
+        assert_eq!(
+            base64::decode(output[0]["packed"].as_str().unwrap()).unwrap(),
+            key
+        );
+    }
+
+    #[test]
+    fn test_approx_count_distinct() {
+        let db = open();
+        db.execute_batch(
+            r#"
+            create table fixture (value);
+            with recursive n(i) as (select 0 union all select i + 1 from n where i < 99999)
+            insert into fixture (value) select i % 25000 from n;
+            insert into fixture (value) values (null), ('a'), ('a'), ('1');
+            "#,
+        )
+        .unwrap();
+
+        let output = Lambda::new(
+            &db,
+            r#"
+            select
+              count(distinct value) as exact,
+              approx_count_distinct(value) as approx,
+              approx_count_distinct(value) filter (where value < 10) as small,
+              (select approx_count_distinct(value) from fixture where 0) as empty
+            from fixture;
+            "#,
+            &[],
+        )
+        .unwrap()
+        .invoke_vec(&serde_json::Value::Null)
+        .unwrap();
+
+        let row = &output[0];
+        assert_eq!(row["exact"], json!(25002));
+        let small = row["small"].as_i64().unwrap();
+        assert!((9..=11).contains(&small), "{small}");
+        assert_eq!(row["empty"], json!(0));
+
+        // Standard error of the sketch is about 1.6%.
+        let approx = row["approx"].as_i64().unwrap();
+        assert!((24_000..26_000).contains(&approx), "{approx}");
+    }
+}
@@ -31,6 +31,7 @@ pub enum Error {
 
 mod connector;
 mod dbutil;
+mod functions;
 mod lambda;
 mod param;
 mod validate;
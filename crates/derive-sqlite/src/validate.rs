@@ -30,10 +30,18 @@ pub fn parse_validate(
                 name,
                 collection: source,
                 lambda_config_json,
-                shuffle_lambda_config_json: _,
+                shuffle_lambda_config_json,
                 backfill: _,
             } = transform;
 
+            // The runtime doesn't yet evaluate shuffle lambdas when routing
+            // source documents to shards, so they cannot be supported here.
+            if !shuffle_lambda_config_json.is_empty() {
+                anyhow::bail!(
+                    "transform {name} uses a shuffle lambda, which SQLite derivations do not yet support (use a shuffle key instead)"
+                );
+            }
+
             let source = source.as_ref().unwrap();
             let params = source
                 .projections
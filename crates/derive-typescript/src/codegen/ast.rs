@@ -13,7 +13,7 @@ pub enum AST {
     Literal { value: Value },
     Array { of: Box<AST> },
     Tuple(ASTTuple),
-    Object { properties: Vec<ASTProperty> },
+    Object(ASTObject),
     Union { variants: Vec<AST> },
     Anchor(String),
 }
@@ -32,6 +32,25 @@ pub struct ASTProperty {
     pub is_required: bool,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct ASTObject {
+    pub properties: Vec<ASTProperty>,
+    // Pattern and additional properties are modeled by an INDEXER_FIELD
+    // property of `properties`. They're also kept here for runtime validators,
+    // which check each property against its specific pattern or additional schema.
+    pub patterns: Vec<ASTPattern>,
+    pub additional: Option<Box<AST>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ASTPattern {
+    pub pattern: String,
+    pub value: AST,
+}
+
+// Field of an ASTProperty which indexes additional or pattern properties.
+pub const INDEXER_FIELD: &str = "[k: string]";
+
 pub struct Context<'a> {
     pub into: &'a mut String,
     pub indent: usize,
@@ -69,10 +88,10 @@ impl AST {
             AST::Literal { value } => ctx.into.push_str(&value.to_string()),
             AST::Array { of } => Self::render_array(ctx, &of),
             AST::Tuple(tuple) => Self::render_tuple(ctx, tuple),
-            AST::Object { properties } if properties.is_empty() => {
+            AST::Object(obj) if obj.properties.is_empty() => {
                 ctx.into.push_str("Record<string, unknown>")
             }
-            AST::Object { properties } => Self::render_object(ctx, properties),
+            AST::Object(obj) => Self::render_object(ctx, &obj.properties),
             AST::Union { variants } => Self::render_disjunction(ctx, variants),
             AST::Anchor(anchor) => ctx.into.push_str(anchor),
         }
@@ -146,6 +165,224 @@ impl AST {
         ctx.into.push('}');
     }
 
+    // Render a Zod schema which validates documents of this AST at runtime.
+    // Anchors are rendered as lazy references to their `{anchor}Schema` validators.
+    pub fn render_zod(&self, ctx: &mut Context) {
+        match self {
+            AST::Comment { body, of } => {
+                ctx.into.push_str("/* ");
+                ctx.into.push_str(body);
+                ctx.into.push_str(" */ ");
+                of.render_zod(ctx);
+            }
+            AST::Never => ctx.into.push_str("z.never()"),
+            AST::Unknown => ctx.into.push_str("z.unknown()"),
+            AST::Boolean => ctx.into.push_str("z.boolean()"),
+            AST::Null => ctx.into.push_str("z.null()"),
+            AST::Number => ctx.into.push_str("z.number()"),
+            AST::String => ctx.into.push_str("z.string()"),
+            AST::Undefined => ctx.into.push_str("z.undefined()"),
+            AST::Literal { value } => Self::render_zod_literal(ctx, value),
+            AST::Array { of } => {
+                ctx.into.push_str("z.array(");
+                of.render_zod(ctx);
+                ctx.into.push(')');
+            }
+            AST::Tuple(tuple) => Self::render_zod_tuple(ctx, tuple),
+            AST::Object(obj) if obj.properties.is_empty() => {
+                ctx.into.push_str("z.record(z.string(), z.unknown())")
+            }
+            AST::Object(obj) => Self::render_zod_object(ctx, obj),
+            AST::Union { variants } => Self::render_zod_disjunction(ctx, variants),
+            AST::Anchor(anchor) => {
+                // Anchors may be declared after their use, or be recursive.
+                ctx.into.push_str("z.lazy(() => ");
+                ctx.into.push_str(anchor);
+                ctx.into.push_str("Schema)");
+            }
+        }
+    }
+
+    fn render_zod_literal(ctx: &mut Context, value: &Value) {
+        match value {
+            Value::Array(_) | Value::Object(_) => {
+                // Zod literals are limited to scalars,
+                // so compare structured values by their encoding.
+                let value = value.to_string();
+                ctx.into.push_str("z.custom<");
+                ctx.into.push_str(&value);
+                ctx.into
+                    .push_str(">((v) => JSON.stringify(v) === JSON.stringify(");
+                ctx.into.push_str(&value);
+                ctx.into.push_str("))");
+            }
+            _ => {
+                ctx.into.push_str("z.literal(");
+                ctx.into.push_str(&value.to_string());
+                ctx.into.push(')');
+            }
+        }
+    }
+
+    fn render_zod_tuple(ctx: &mut Context, tuple: &ASTTuple) {
+        ctx.into.push_str("z.tuple([");
+        for (ind, item) in tuple.items.iter().enumerate() {
+            if ind != 0 {
+                ctx.into.push_str(", ");
+            }
+            item.render_zod(ctx);
+
+            if ind >= tuple.min_items {
+                ctx.into.push_str(".optional()");
+            }
+        }
+        ctx.into.push_str("])");
+
+        // Tack on spread AST, if present.
+        if let Some(spread) = &tuple.spread {
+            ctx.into.push_str(".rest(");
+            spread.render_zod(ctx);
+            ctx.into.push(')');
+        }
+    }
+
+    fn render_zod_disjunction(ctx: &mut Context, variants: &[AST]) {
+        // Zod unions require at least two variants.
+        match variants {
+            [] => return ctx.into.push_str("z.never()"),
+            [variant] => return variant.render_zod(ctx),
+            _ => (),
+        }
+        ctx.into.push_str("z.union([");
+        for (ind, item) in variants.iter().enumerate() {
+            if ind != 0 {
+                ctx.into.push_str(", ");
+            }
+            item.render_zod(ctx);
+        }
+        ctx.into.push_str("])");
+    }
+
+    fn render_zod_object(ctx: &mut Context, obj: &ASTObject) {
+        let ASTObject {
+            properties,
+            patterns,
+            additional,
+        } = obj;
+
+        ctx.into.push_str("z.object({");
+
+        // An indexer property is modeled as a catch-all of any other properties.
+        let mut catchall = None;
+
+        for prop in properties.iter() {
+            if prop.field == INDEXER_FIELD {
+                catchall = Some(&prop.value);
+                continue;
+            }
+            ctx.indent += 1;
+
+            Self::push_newline(ctx);
+            ctx.into.push_str(&prop.field);
+            ctx.into.push_str(": ");
+            prop.value.render_zod(ctx);
+            if !prop.is_required {
+                ctx.into.push_str(".optional()");
+            }
+            ctx.into.push(',');
+            ctx.indent -= 1;
+        }
+        Self::push_newline(ctx);
+        ctx.into.push_str("})");
+
+        if let Some(catchall) = catchall {
+            ctx.into.push_str(".catchall(");
+            catchall.render_zod(ctx);
+            ctx.into.push(')');
+        } else {
+            // Don't strip properties which aren't known to the schema.
+            ctx.into.push_str(".passthrough()");
+        }
+
+        if !patterns.is_empty() {
+            Self::render_zod_pattern_refinement(ctx, properties, patterns, additional.as_deref());
+        }
+    }
+
+    // The catch-all of an indexer property admits any type of any pattern or
+    // additional property. Refine it by checking each property against the schema
+    // of each pattern it matches, and unmatched properties against the additional
+    // properties schema.
+    fn render_zod_pattern_refinement(
+        ctx: &mut Context,
+        properties: &[ASTProperty],
+        patterns: &[ASTPattern],
+        additional: Option<&AST>,
+    ) {
+        ctx.into.push_str(".superRefine((doc, ctx) => {");
+        ctx.indent += 1;
+        Self::push_newline(ctx);
+        ctx.into
+            .push_str("for (const [key, value] of Object.entries(doc)) {");
+        ctx.indent += 1;
+        Self::push_newline(ctx);
+        ctx.into.push_str("let matched = false;");
+
+        for ASTPattern { pattern, value } in patterns {
+            Self::push_newline(ctx);
+            ctx.into.push_str("if (new RegExp(");
+            ctx.into
+                .push_str(&Value::String(pattern.clone()).to_string());
+            ctx.into.push_str(").test(key)) {");
+            ctx.indent += 1;
+            Self::push_newline(ctx);
+            ctx.into.push_str("matched = true;");
+            Self::push_newline(ctx);
+            ctx.into.push_str("refineProperty(ctx, key, value, ");
+            value.render_zod(ctx);
+            ctx.into.push_str(");");
+            ctx.indent -= 1;
+            Self::push_newline(ctx);
+            ctx.into.push('}');
+        }
+
+        if let Some(additional) = additional {
+            // Fields are either bare variable names or JSON-quoted strings.
+            let fields = properties
+                .iter()
+                .filter(|prop| prop.field != INDEXER_FIELD)
+                .map(|prop| {
+                    if prop.field.starts_with('"') {
+                        prop.field.clone()
+                    } else {
+                        format!("\"{}\"", prop.field)
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            Self::push_newline(ctx);
+            ctx.into.push_str("if (!matched && ![");
+            ctx.into.push_str(&fields);
+            ctx.into.push_str("].includes(key)) {");
+            ctx.indent += 1;
+            Self::push_newline(ctx);
+            ctx.into.push_str("refineProperty(ctx, key, value, ");
+            additional.render_zod(ctx);
+            ctx.into.push_str(");");
+            ctx.indent -= 1;
+            Self::push_newline(ctx);
+            ctx.into.push('}');
+        }
+
+        ctx.indent -= 1;
+        Self::push_newline(ctx);
+        ctx.into.push('}');
+        ctx.indent -= 1;
+        Self::push_newline(ctx);
+        ctx.into.push_str("})");
+    }
+
     fn push_newline(ctx: &mut Context) {
         ctx.into.push('\n');
         ctx.into.extend(std::iter::repeat(' ').take(ctx.indent * 4));
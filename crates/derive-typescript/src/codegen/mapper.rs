@@ -1,4 +1,4 @@
-use super::ast::{ASTProperty, ASTTuple, AST};
+use super::ast::{ASTObject, ASTPattern, ASTProperty, ASTTuple, AST, INDEXER_FIELD};
 use doc::shape::{ArrayShape, ObjShape, Provenance, Shape};
 use json::schema::{types, Keyword};
 use regex::Regex;
@@ -209,14 +209,32 @@ impl Mapper {
             };
 
             props.push(ASTProperty {
-                field: "[k: string]".to_owned(),
+                field: INDEXER_FIELD.to_owned(),
                 value: merged,
                 // Optional '?' has no meaning for variadic properties.
                 is_required: true,
             });
         }
 
-        AST::Object { properties: props }
+        let patterns = obj
+            .pattern_properties
+            .iter()
+            .map(|prop| ASTPattern {
+                pattern: prop.re.as_str().to_string(),
+                value: self.to_ast(&prop.shape),
+            })
+            .collect();
+
+        let additional = obj
+            .additional_properties
+            .as_ref()
+            .map(|addl| Box::new(self.to_ast(addl)));
+
+        AST::Object(ASTObject {
+            properties: props,
+            patterns,
+            additional,
+        })
     }
 
     fn array_to_ast(&self, arr: &ArrayShape) -> AST {
@@ -311,4 +329,41 @@ mod test {
 
         insta::assert_snapshot!(w);
     }
+
+    #[test]
+    fn zod_generation() {
+        let fixture = serde_yaml::from_slice(include_bytes!("mapper_test.yaml")).unwrap();
+        let mut sources = sources::scenarios::evaluate_fixtures(Default::default(), &fixture);
+        sources::inline_draft_catalog(&mut sources);
+
+        let tables::DraftCatalog {
+            collections,
+            errors,
+            ..
+        } = sources;
+
+        if !errors.is_empty() {
+            panic!("unexpected errors: {errors:?}");
+        }
+        let mut w = String::new();
+
+        for collection in collections.iter() {
+            let schema = collection.model.clone().unwrap().schema.unwrap();
+
+            for (prefix, label) in [("Doc", "with"), ("", "without")] {
+                let m = Mapper::new(schema.get(), prefix);
+                writeln!(
+                    &mut w,
+                    "Validator for {name} with CURI {curi} {label} anchors:",
+                    name = collection.collection.as_str(),
+                    curi = m.root(),
+                )
+                .unwrap();
+                m.map(m.root()).render_zod(&mut Context::new(&mut w));
+                w.push_str("\n\n");
+            }
+        }
+
+        insta::assert_snapshot!(w);
+    }
 }
@@ -6,15 +6,20 @@ mod ast;
 mod mapper;
 
 use super::LambdaConfig;
-use ast::Context;
+use ast::{Context, AST};
 use mapper::Mapper;
 
 pub fn types_ts(
     collection: &flow::CollectionSpec,
     transforms: &[(&str, &flow::CollectionSpec, LambdaConfig)],
+    validators: bool,
 ) -> String {
     let mut w = String::with_capacity(4096);
 
+    if validators {
+        w.push_str(ZOD_PRELUDE);
+    }
+
     let (w_mapper, r_mapper) = collection_mappers(collection, "Document");
 
     // Generate Document* types.
@@ -27,12 +32,14 @@ export type Document = "#,
     )
     .unwrap();
 
-    w_mapper
-        .map(w_mapper.root())
-        .render(&mut Context::new(&mut w));
+    let ast = w_mapper.map(w_mapper.root());
+    ast.render(&mut Context::new(&mut w));
     write!(w, ";\n\n").unwrap();
 
-    generate_anchors(&mut w, &w_mapper, r_mapper.as_ref(), "Document");
+    if validators {
+        generate_validator(&mut w, &ast, "Document");
+    }
+    generate_anchors(&mut w, &w_mapper, r_mapper.as_ref(), "Document", validators);
 
     // Generate Source{name} collection types for each transform.
     for (name, collection, _config) in transforms {
@@ -50,12 +57,20 @@ export type {source_name} = "#,
         )
         .unwrap();
 
-        source_mapper
-            .map(source_mapper.root())
-            .render(&mut Context::new(&mut w));
+        let ast = source_mapper.map(source_mapper.root());
+        ast.render(&mut Context::new(&mut w));
         write!(w, ";\n\n").unwrap();
 
-        generate_anchors(&mut w, &w_mapper, r_mapper.as_ref(), &source_name);
+        if validators {
+            generate_validator(&mut w, &ast, &source_name);
+        }
+        generate_anchors(
+            &mut w,
+            &w_mapper,
+            r_mapper.as_ref(),
+            &source_name,
+            validators,
+        );
     }
 
     // Generate the IDerivation abstract class.
@@ -166,7 +181,13 @@ export class Derivation extends IDerivation {{
     w
 }
 
-fn generate_anchors(w: &mut String, w_mapper: &Mapper, r_mapper: Option<&Mapper>, prefix: &str) {
+fn generate_anchors(
+    w: &mut String,
+    w_mapper: &Mapper,
+    r_mapper: Option<&Mapper>,
+    prefix: &str,
+    validators: bool,
+) {
     let anchor_mapper = r_mapper.unwrap_or(w_mapper);
 
     for (anchor_url, anchor_name) in anchor_mapper.top_level.iter() {
@@ -179,10 +200,42 @@ export type {prefix}{anchor_name} = "#,
         )
         .unwrap();
 
-        anchor_mapper.map(anchor_url).render(&mut Context::new(w));
+        let ast = anchor_mapper.map(anchor_url);
+        ast.render(&mut Context::new(w));
         write!(w, ";\n\n").unwrap();
+
+        if validators {
+            generate_validator(w, &ast, &format!("{prefix}{anchor_name}"));
+        }
+    }
+}
+
+// Imports and helpers of generated Zod validators.
+const ZOD_PRELUDE: &str = r#"import { z } from "npm:zod@3";
+
+// Validate property `key` of a document against `schema`, adding any issues to `ctx`.
+function refineProperty(ctx: z.RefinementCtx, key: string, value: unknown, schema: z.ZodTypeAny) {
+    const result = schema.safeParse(value);
+    if (!result.success) {
+        for (const issue of result.error.issues) {
+            ctx.addIssue({ ...issue, path: [key, ...issue.path] });
+        }
     }
 }
+"#;
+
+// Generate a Zod schema `{name}Schema` which validates documents of type `{name}`.
+fn generate_validator(w: &mut String, ast: &AST, name: &str) {
+    write!(
+        w,
+        r#"// Generated runtime validator of type {name}.
+export const {name}Schema: z.ZodType<{name}> = "#,
+    )
+    .unwrap();
+
+    ast.render_zod(&mut Context::new(w));
+    write!(w, ";\n\n").unwrap();
+}
 
 fn collection_mappers(c: &flow::CollectionSpec, anchor_prefix: &str) -> (Mapper, Option<Mapper>) {
     // We extract anchors from just one schema:
@@ -213,3 +266,85 @@ fn camel_case(name: &str, mut upper: bool) -> String {
     }
     w
 }
+
+#[cfg(test)]
+mod test {
+    use super::{types_ts, LambdaConfig};
+    use proto_flow::flow;
+
+    #[test]
+    fn types_ts_with_validators() {
+        let collection = flow::CollectionSpec {
+            name: "acmeCo/derived".to_string(),
+            write_schema_json: serde_json::json!({
+                "type": "object",
+                "properties": { "id": { "type": "string" } },
+                "required": ["id"],
+                "patternProperties": { "^x-": { "type": "boolean" } },
+                "additionalProperties": false,
+            })
+            .to_string(),
+            ..Default::default()
+        };
+        let source = flow::CollectionSpec {
+            name: "acmeCo/source".to_string(),
+            write_schema_json: serde_json::json!({
+                "type": "object",
+                "properties": { "n": { "type": "integer" } },
+            })
+            .to_string(),
+            ..Default::default()
+        };
+        let transforms = [("fromSource", &source, LambdaConfig { read_only: false })];
+
+        let out = types_ts(&collection, &transforms, true);
+        insta::assert_snapshot!(out);
+    }
+
+    #[test]
+    fn types_ts_validators_type_check() {
+        let fixture = serde_yaml::from_slice(include_bytes!("mapper_test.yaml")).unwrap();
+        let mut sources = sources::scenarios::evaluate_fixtures(Default::default(), &fixture);
+        sources::inline_draft_catalog(&mut sources);
+
+        if !sources.errors.is_empty() {
+            panic!("unexpected errors: {:?}", sources.errors);
+        }
+
+        for collection in sources.collections.iter() {
+            let schema = collection.model.clone().unwrap().schema.unwrap();
+            let spec = flow::CollectionSpec {
+                name: collection.collection.to_string(),
+                write_schema_json: schema.get().to_string(),
+                ..Default::default()
+            };
+            let transforms = [("fromSelf", &spec, LambdaConfig { read_only: false })];
+            let out = types_ts(&spec, &transforms, true);
+
+            // Each generated validator is annotated as `z.ZodType<T>` of its
+            // generated type, so a passing check means they agree.
+            let temp_dir = tempfile::TempDir::new().unwrap();
+            std::fs::write(temp_dir.path().join("types.ts"), out).unwrap();
+
+            let output = match std::process::Command::new("deno")
+                .current_dir(temp_dir.path())
+                .args(["check", "types.ts"])
+                .output()
+            {
+                Ok(output) => output,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                    eprintln!("skipping type check: deno is not installed");
+                    return;
+                }
+                Err(err) => panic!("failed to run deno: {err}"),
+            };
+
+            assert!(
+                output.status.success(),
+                "generated types of {} failed to type-check:\n{}",
+                spec.name,
+                String::from_utf8_lossy(&output.stderr),
+            );
+        }
+    }
+}
@@ -79,7 +79,7 @@ pub fn run() -> anyhow::Result<()> {
 
     std::fs::write(
         temp_dir.join(TYPES_NAME),
-        codegen::types_ts(&collection, &transforms),
+        codegen::types_ts(&collection, &transforms, config.validators),
     )?;
     std::fs::write(
         temp_dir.join("deno.json"),
@@ -116,6 +116,8 @@ pub fn run() -> anyhow::Result<()> {
 #[serde(rename_all = "camelCase")]
 pub struct Config {
     module: String,
+    #[serde(default)]
+    validators: bool,
 }
 
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -177,7 +179,7 @@ fn validate(validate: derive::request::Validate) -> anyhow::Result<derive::respo
         .collect();
 
     let types_url = format!("{project_root}/{GENERATED_PREFIX}/{}.ts", collection.name);
-    let types_content = codegen::types_ts(&collection, &transforms);
+    let types_content = codegen::types_ts(&collection, &transforms, config.validators);
 
     let mut generated_files: Vec<(String, String)> = vec![
         (types_url.clone(), types_content.clone()),
@@ -0,0 +1,308 @@
+use super::{parse_config, Instance, Module};
+use anyhow::Context;
+use futures::channel::mpsc;
+use futures::{SinkExt, StreamExt};
+use proto_flow::{
+    derive::{request, response, Request, Response},
+    flow,
+};
+
+pub fn connector<R>(request_rx: R) -> mpsc::Receiver<anyhow::Result<Response>>
+where
+    R: futures::stream::Stream<Item = Request> + Send + 'static,
+{
+    let (mut response_tx, response_rx) = mpsc::channel(16);
+
+    tokio::runtime::Handle::current().spawn_blocking(move || {
+        futures::executor::block_on(async move {
+            if let Err(status) = serve(request_rx, &mut response_tx).await {
+                _ = response_tx.send(Err(status)).await;
+            }
+        })
+    });
+
+    response_rx
+}
+
+async fn serve<R>(
+    mut request_rx: R,
+    response_tx: &mut mpsc::Sender<anyhow::Result<Response>>,
+) -> anyhow::Result<()>
+where
+    R: futures::stream::Stream<Item = Request>,
+{
+    let mut request_rx = std::pin::pin!(request_rx);
+    // Compiled module of the last Request.Open.
+    let mut maybe_module: Option<Module> = None;
+    // A possibly instantiated module.
+    let mut maybe_instance: Option<Instance> = None;
+
+    loop {
+        match request_rx.next().await {
+            None => return Ok(()),
+            Some(Request {
+                validate: Some(validate),
+                ..
+            }) => {
+                let validated = do_validate(validate).map_err(anyhow_to_status)?;
+
+                let _ = response_tx
+                    .send(Ok(Response {
+                        validated: Some(validated),
+                        ..Default::default()
+                    }))
+                    .await;
+            }
+            Some(Request {
+                open: Some(open), ..
+            }) => {
+                let (module, instance) = do_open(open).map_err(anyhow_to_status)?;
+                maybe_instance = Some(instance);
+                maybe_module = Some(module);
+
+                let _ = response_tx
+                    .send(Ok(Response {
+                        opened: Some(response::Opened {}),
+                        ..Default::default()
+                    }))
+                    .await;
+            }
+            Some(Request {
+                read: Some(read), ..
+            }) => {
+                let instance = maybe_instance
+                    .as_mut()
+                    .ok_or_else(|| tonic::Status::invalid_argument("Read without Open"))?;
+
+                let published = do_read(instance, read).map_err(anyhow_to_status)?;
+
+                for doc_json in published {
+                    let _ = response_tx
+                        .send(Ok(Response {
+                            published: Some(response::Published { doc_json }),
+                            ..Default::default()
+                        }))
+                        .await;
+                }
+            }
+            Some(Request {
+                flush: Some(request::Flush {}),
+                ..
+            }) => {
+                // Send Flushed to runtime.
+                let _ = response_tx
+                    .send(Ok(Response {
+                        flushed: Some(response::Flushed {}),
+                        ..Default::default()
+                    }))
+                    .await;
+            }
+            Some(Request {
+                start_commit: Some(request::StartCommit { .. }),
+                ..
+            }) => {
+                let instance = maybe_instance
+                    .as_mut()
+                    .ok_or_else(|| tonic::Status::invalid_argument("StartCommit without Open"))?;
+
+                let state = do_start_commit(instance).map_err(anyhow_to_status)?;
+
+                // Send StartedCommit to runtime.
+                let _ = response_tx
+                    .send(Ok(Response {
+                        started_commit: Some(response::StartedCommit { state }),
+                        ..Default::default()
+                    }))
+                    .await;
+            }
+            Some(Request {
+                reset: Some(request::Reset {}),
+                ..
+            }) => {
+                // Replace with a new instance having empty state.
+                let module = maybe_module
+                    .as_ref()
+                    .ok_or_else(|| tonic::Status::invalid_argument("Reset without Open"))?;
+                maybe_instance = Some(module.instantiate().map_err(anyhow_to_status)?);
+            }
+            Some(malformed) => Err(tonic::Status::invalid_argument(format!(
+                "invalid request {malformed:?}"
+            )))?,
+        }
+    }
+}
+
+fn do_validate(validate: request::Validate) -> anyhow::Result<response::Validated> {
+    let request::Validate {
+        config_json,
+        transforms,
+        ..
+    } = &validate;
+
+    let module = Module::new(&parse_config(config_json)?)?;
+
+    let transforms = transforms
+        .iter()
+        .map(|transform| {
+            if !transform.shuffle_lambda_config_json.is_empty() {
+                anyhow::bail!(
+                    "transform {} uses a shuffle lambda, which WebAssembly derivations do not support",
+                    transform.name
+                );
+            }
+            Ok(response::validated::Transform {
+                read_only: module.is_read_only(),
+            })
+        })
+        .collect::<anyhow::Result<_>>()?;
+
+    Ok(response::Validated {
+        transforms,
+        generated_files: Default::default(),
+    })
+}
+
+fn do_open(open: request::Open) -> anyhow::Result<(Module, Instance)> {
+    let request::Open {
+        collection,
+        state_json,
+        ..
+    } = open;
+
+    let flow::CollectionSpec { derivation, .. } =
+        collection.context("`open` missing required `collection`")?;
+    let flow::collection_spec::Derivation { config_json, .. } =
+        derivation.context("`collection` missing required `derivation`")?;
+
+    let module = Module::new(&parse_config(&config_json)?)?;
+    let mut instance = module.instantiate()?;
+
+    // Restore module state which was persisted by a previous StartCommit.
+    let state: serde_json::Value = if state_json.is_empty() {
+        serde_json::Value::Null
+    } else {
+        serde_json::from_str(&state_json)
+            .with_context(|| format!("failed to parse connector state: {state_json}"))?
+    };
+    if let Some(state) = state.get(STATE_MODULE) {
+        instance
+            .restore(state)
+            .context("failed to restore WebAssembly module state")?;
+    }
+
+    Ok((module, instance))
+}
+
+fn do_start_commit(instance: &mut Instance) -> anyhow::Result<Option<flow::ConnectorState>> {
+    let Some(state) = instance
+        .snapshot()
+        .context("failed to snapshot WebAssembly module state")?
+    else {
+        return Ok(None); // Read-only modules have no state.
+    };
+
+    Ok(Some(flow::ConnectorState {
+        updated_json: serde_json::json!({ STATE_MODULE: state }).to_string(),
+        merge_patch: false,
+    }))
+}
+
+// Property of the connector state which holds the module's state snapshot.
+const STATE_MODULE: &str = "module";
+
+fn do_read(instance: &mut Instance, read: request::Read) -> anyhow::Result<Vec<String>> {
+    let request::Read {
+        transform,
+        doc_json,
+        ..
+    } = read;
+
+    instance.invoke(transform, &doc_json).with_context(|| {
+        format!("failed to invoke WebAssembly transform {transform}\nDocument was {doc_json}")
+    })
+}
+
+fn anyhow_to_status(err: anyhow::Error) -> tonic::Status {
+    tonic::Status::internal(format!("{err:#}"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::module::test::ECHO;
+
+    // Run a session of the connector over `requests`, returning its responses.
+    async fn run_session(requests: Vec<Request>) -> Vec<Response> {
+        connector(futures::stream::iter(requests))
+            .map(|response| response.unwrap())
+            .collect()
+            .await
+    }
+
+    fn open(state_json: &str) -> Request {
+        let config_json = serde_json::json!({ "module": ECHO }).to_string();
+
+        Request {
+            open: Some(request::Open {
+                collection: Some(flow::CollectionSpec {
+                    derivation: Some(flow::collection_spec::Derivation {
+                        config_json,
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                state_json: state_json.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn read(doc_json: &str) -> Request {
+        Request {
+            read: Some(request::Read {
+                doc_json: doc_json.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn start_commit() -> Request {
+        Request {
+            start_commit: Some(request::StartCommit::default()),
+            ..Default::default()
+        }
+    }
+
+    fn started_state(responses: &[Response]) -> String {
+        let Some(Response {
+            started_commit: Some(response::StartedCommit { state: Some(state) }),
+            ..
+        }) = responses.last()
+        else {
+            panic!("expected a StartedCommit with state, not {responses:?}");
+        };
+        assert!(!state.merge_patch);
+        state.updated_json.clone()
+    }
+
+    #[tokio::test]
+    async fn test_state_is_restored_across_sessions() {
+        let responses = run_session(vec![
+            open("{}"),
+            read(r#"{"a":1}"#),
+            read(r#"{"a":2}"#),
+            start_commit(),
+        ])
+        .await;
+
+        assert_eq!(responses.len(), 4); // Opened, two Published, and StartedCommit.
+        let state = started_state(&responses);
+        assert_eq!(state, r#"{"module":2}"#);
+
+        // A restarted session resumes from the persisted module state.
+        let responses = run_session(vec![open(&state), read(r#"{"a":3}"#), start_commit()]).await;
+        assert_eq!(started_state(&responses), r#"{"module":3}"#);
+    }
+}
@@ -0,0 +1,13 @@
+mod connector;
+mod module;
+
+pub use connector::connector;
+pub use module::{Instance, Module};
+
+// Parse the connector configuration of a WebAssembly derivation.
+fn parse_config(config_json: &str) -> anyhow::Result<models::DeriveUsingWasm> {
+    use anyhow::Context;
+
+    serde_json::from_str(config_json)
+        .with_context(|| format!("failed to parse WebAssembly configuration: {config_json}"))
+}
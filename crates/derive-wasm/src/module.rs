@@ -0,0 +1,385 @@
+use anyhow::Context;
+
+/// Module is a compiled WebAssembly derivation module,
+/// along with the limits of its instances.
+pub struct Module {
+    module: wasmtime::Module,
+    fuel: u64,
+    memory_limit: usize,
+}
+
+/// Instance is an instantiated Module, which holds the module's
+/// in-memory state across invocations.
+pub struct Instance {
+    store: wasmtime::Store<wasmtime::StoreLimits>,
+    memory: wasmtime::Memory,
+    alloc: wasmtime::TypedFunc<i32, i32>,
+    update: Option<Stateful>,
+    publish: wasmtime::TypedFunc<(i32, i32, i32), i64>,
+    fuel: u64,
+}
+
+// Exports of a stateful module, which updates, snapshots, and restores its state.
+struct Stateful {
+    update: wasmtime::TypedFunc<(i32, i32, i32), ()>,
+    snapshot: wasmtime::TypedFunc<(), i64>,
+    restore: wasmtime::TypedFunc<(i32, i32), ()>,
+}
+
+impl Module {
+    /// Compile the Module of a WebAssembly derivation configuration.
+    /// The configured module must be either WebAssembly text,
+    /// or a data URL of a base64 binary WebAssembly module.
+    pub fn new(config: &models::DeriveUsingWasm) -> anyhow::Result<Self> {
+        let models::DeriveUsingWasm {
+            module,
+            fuel,
+            memory_limit,
+        } = config;
+
+        let module: String =
+            serde_json::from_str(module.get()).context("WebAssembly module must be a string")?;
+
+        let module = if let Some(b64) =
+            module.strip_prefix(models::DeriveUsingWasm::DATA_URL_PREFIX)
+        {
+            base64::decode(b64).context("failed to decode base64 WebAssembly module")?
+        } else if !models::DeriveUsingWasm::is_inline_module(&module) {
+            anyhow::bail!(
+                "WebAssembly module {module} was not loaded (expected WebAssembly text or a data URL)"
+            );
+        } else {
+            module.into_bytes()
+        };
+
+        let memory_limit = match memory_limit {
+            Some(limit) => models::ConnectorResources::parse_memory(limit)
+                .with_context(|| format!("invalid WebAssembly memory limit {limit:?}"))?,
+            None => models::DeriveUsingWasm::DEFAULT_MEMORY_LIMIT,
+        };
+
+        let mut engine_config = wasmtime::Config::new();
+        engine_config.consume_fuel(true);
+
+        let engine = wasmtime::Engine::new(&engine_config)?;
+        let module = wasmtime::Module::new(&engine, &module)
+            .context("failed to compile WebAssembly module")?;
+
+        for name in ["memory", "alloc", "publish"] {
+            if module.get_export(name).is_none() {
+                anyhow::bail!("WebAssembly module must export `{name}`");
+            }
+        }
+        // Stateful modules must be able to snapshot and restore their state,
+        // so that it may be persisted across restarts of the task.
+        if module.get_export("update").is_some() {
+            for name in ["snapshot", "restore"] {
+                if module.get_export(name).is_none() {
+                    anyhow::bail!(
+                        "WebAssembly module which exports `update` must also export `{name}`"
+                    );
+                }
+            }
+        }
+
+        Ok(Self {
+            module,
+            fuel: fuel.unwrap_or(models::DeriveUsingWasm::DEFAULT_FUEL),
+            memory_limit: memory_limit as usize,
+        })
+    }
+
+    /// Is this Module read-only?
+    /// Modules which don't export an `update` function have no state to update.
+    pub fn is_read_only(&self) -> bool {
+        self.module.get_export("update").is_none()
+    }
+
+    /// Instantiate the Module with fresh, empty state.
+    pub fn instantiate(&self) -> anyhow::Result<Instance> {
+        let limits = wasmtime::StoreLimitsBuilder::new()
+            .memory_size(self.memory_limit)
+            .build();
+
+        let mut store = wasmtime::Store::new(self.module.engine(), limits);
+        store.limiter(|limits| limits);
+        store.set_fuel(self.fuel)?;
+
+        let instance = wasmtime::Instance::new(&mut store, &self.module, &[])
+            .context("failed to instantiate WebAssembly module (modules may not have imports)")?;
+
+        let memory = instance
+            .get_memory(&mut store, "memory")
+            .context("WebAssembly module export `memory` is not a memory")?;
+        let alloc = instance
+            .get_typed_func(&mut store, "alloc")
+            .context("WebAssembly module export `alloc` has an unexpected type")?;
+        let publish = instance
+            .get_typed_func(&mut store, "publish")
+            .context("WebAssembly module export `publish` has an unexpected type")?;
+        let update = if self.is_read_only() {
+            None
+        } else {
+            Some(Stateful {
+                update: instance
+                    .get_typed_func(&mut store, "update")
+                    .context("WebAssembly module export `update` has an unexpected type")?,
+                snapshot: instance
+                    .get_typed_func(&mut store, "snapshot")
+                    .context("WebAssembly module export `snapshot` has an unexpected type")?,
+                restore: instance
+                    .get_typed_func(&mut store, "restore")
+                    .context("WebAssembly module export `restore` has an unexpected type")?,
+            })
+        };
+
+        Ok(Instance {
+            store,
+            memory,
+            alloc,
+            update,
+            publish,
+            fuel: self.fuel,
+        })
+    }
+}
+
+impl Instance {
+    /// Invoke the instance with a source document of the indexed transform,
+    /// returning the documents which it published.
+    pub fn invoke(&mut self, transform: u32, doc_json: &str) -> anyhow::Result<Vec<String>> {
+        let transform = transform as i32;
+        let (ptr, len) = self.write_input(doc_json)?;
+
+        if let Some(Stateful { update, .. }) = &self.update {
+            self.store.set_fuel(self.fuel)?;
+            update
+                .call(&mut self.store, (transform, ptr, len))
+                .context("failed to invoke `update`")?;
+        }
+
+        self.store.set_fuel(self.fuel)?;
+        let packed = self
+            .publish
+            .call(&mut self.store, (transform, ptr, len))
+            .context("failed to invoke `publish`")?;
+
+        let output = self
+            .read_output(packed)
+            .context("invalid `publish` output")?;
+
+        // Output is newline-delimited JSON documents.
+        Ok(output
+            .split('\n')
+            .map(str::trim)
+            .filter(|doc| !doc.is_empty())
+            .map(str::to_string)
+            .collect())
+    }
+
+    /// Snapshot the JSON state of a stateful instance,
+    /// or return None if the instance is read-only.
+    pub fn snapshot(&mut self) -> anyhow::Result<Option<serde_json::Value>> {
+        let Some(Stateful { snapshot, .. }) = &self.update else {
+            return Ok(None);
+        };
+
+        self.store.set_fuel(self.fuel)?;
+        let packed = snapshot
+            .call(&mut self.store, ())
+            .context("failed to invoke `snapshot`")?;
+
+        let state = self
+            .read_output(packed)
+            .context("invalid `snapshot` output")?;
+        let state = serde_json::from_str(state).context("`snapshot` output is not JSON")?;
+
+        Ok(Some(state))
+    }
+
+    /// Restore the JSON state of a stateful instance from a previous snapshot.
+    pub fn restore(&mut self, state: &serde_json::Value) -> anyhow::Result<()> {
+        let Some(Stateful { restore, .. }) = &self.update else {
+            anyhow::bail!("WebAssembly module is read-only and cannot restore state");
+        };
+        let restore = restore.clone();
+
+        let (ptr, len) = self.write_input(&state.to_string())?;
+
+        self.store.set_fuel(self.fuel)?;
+        restore
+            .call(&mut self.store, (ptr, len))
+            .context("failed to invoke `restore`")?;
+
+        Ok(())
+    }
+
+    // Copy `input` into memory allocated by the module, returning its pointer and length.
+    fn write_input(&mut self, input: &str) -> anyhow::Result<(i32, i32)> {
+        let len = i32::try_from(input.len()).context("input is too large")?;
+
+        self.store.set_fuel(self.fuel)?;
+        let ptr = self
+            .alloc
+            .call(&mut self.store, len)
+            .context("failed to invoke `alloc`")?;
+        self.memory
+            .write(&mut self.store, ptr as u32 as usize, input.as_bytes())
+            .context("`alloc` returned an out-of-bounds allocation")?;
+
+        Ok((ptr, len))
+    }
+
+    // Read UTF-8 output of the module, having a pointer and length packed as (ptr << 32 | len).
+    fn read_output(&self, packed: i64) -> anyhow::Result<&str> {
+        let (ptr, len) = ((packed as u64 >> 32) as usize, packed as u32 as usize);
+
+        let output = self
+            .memory
+            .data(&self.store)
+            .get(ptr..ptr + len)
+            .context("output is out-of-bounds")?;
+
+        std::str::from_utf8(output).context("output is not UTF-8")
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+
+    fn config(
+        module: &str,
+        fuel: Option<u64>,
+        memory_limit: Option<&str>,
+    ) -> models::DeriveUsingWasm {
+        models::DeriveUsingWasm {
+            module: models::RawValue::from_value(&serde_json::json!(module)),
+            fuel,
+            memory_limit: memory_limit.map(str::to_string),
+        }
+    }
+
+    // ECHO is a module which publishes each of its input documents,
+    // and counts documents in its `update` function. Its state is the count,
+    // held as a single ASCII digit at memory offset zero.
+    pub(crate) const ECHO: &str = r#"
+    (module
+      (memory (export "memory") 1)
+      (data (i32.const 0) "0")
+      (global $next (mut i32) (i32.const 1024))
+      (func (export "alloc") (param $len i32) (result i32)
+        (local $ptr i32)
+        (local.set $ptr (global.get $next))
+        (global.set $next (i32.add (global.get $next) (local.get $len)))
+        (local.get $ptr))
+      (func (export "update") (param $transform i32) (param $ptr i32) (param $len i32)
+        (i32.store8 (i32.const 0) (i32.add (i32.load8_u (i32.const 0)) (i32.const 1))))
+      (func (export "publish") (param $transform i32) (param $ptr i32) (param $len i32) (result i64)
+        (i64.or
+          (i64.shl (i64.extend_i32_u (local.get $ptr)) (i64.const 32))
+          (i64.extend_i32_u (local.get $len))))
+      (func (export "snapshot") (result i64)
+        (i64.const 1))
+      (func (export "restore") (param $ptr i32) (param $len i32)
+        (i32.store8 (i32.const 0) (i32.load8_u (local.get $ptr))))
+    )
+    "#;
+
+    // SPIN is a read-only module which never returns from `publish`.
+    const SPIN: &str = r#"
+    (module
+      (memory (export "memory") 1)
+      (func (export "alloc") (param $len i32) (result i32)
+        (i32.const 1024))
+      (func (export "publish") (param $transform i32) (param $ptr i32) (param $len i32) (result i64)
+        (loop $spin (br $spin))
+        (i64.const 0))
+    )
+    "#;
+
+    #[test]
+    fn test_echo() {
+        let module = Module::new(&config(ECHO, None, None)).unwrap();
+        assert!(!module.is_read_only());
+
+        let mut instance = module.instantiate().unwrap();
+        let out = instance.invoke(0, r#"{"a":1}"#).unwrap();
+        assert_eq!(out, vec![r#"{"a":1}"#.to_string()]);
+        let out = instance.invoke(1, r#"{"b":[true,null]}"#).unwrap();
+        assert_eq!(out, vec![r#"{"b":[true,null]}"#.to_string()]);
+        assert_eq!(instance.snapshot().unwrap(), Some(serde_json::json!(2)));
+
+        // A new instance restores the state of the prior one.
+        let mut instance = module.instantiate().unwrap();
+        assert_eq!(instance.snapshot().unwrap(), Some(serde_json::json!(0)));
+        instance.restore(&serde_json::json!(2)).unwrap();
+        instance.invoke(0, "{}").unwrap();
+        assert_eq!(instance.snapshot().unwrap(), Some(serde_json::json!(3)));
+    }
+
+    #[test]
+    fn test_binary_data_url() {
+        let binary = wat::parse_str(ECHO).unwrap();
+        let module = format!(
+            "{}{}",
+            models::DeriveUsingWasm::DATA_URL_PREFIX,
+            base64::encode(&binary)
+        );
+
+        let module = Module::new(&config(&module, None, None)).unwrap();
+        let out = module.instantiate().unwrap().invoke(0, "[1,2,3]").unwrap();
+        assert_eq!(out, vec!["[1,2,3]".to_string()]);
+    }
+
+    #[test]
+    fn test_fuel_exhaustion() {
+        let module = Module::new(&config(SPIN, Some(10_000), None)).unwrap();
+        assert!(module.is_read_only());
+
+        let mut instance = module.instantiate().unwrap();
+        assert_eq!(instance.snapshot().unwrap(), None);
+
+        let err = instance.invoke(0, "{}").unwrap_err();
+        assert!(format!("{err:#}").contains("failed to invoke `publish`"));
+    }
+
+    #[test]
+    fn test_errors() {
+        let err = Module::new(&config("path/to/module.wasm", None, None))
+            .err()
+            .unwrap();
+        insta::assert_snapshot!(err, @"WebAssembly module path/to/module.wasm was not loaded (expected WebAssembly text or a data URL)");
+
+        // Text modules are recognized even if they have no whitespace.
+        let err = Module::new(&config("(module)", None, None)).err().unwrap();
+        insta::assert_snapshot!(err, @"WebAssembly module must export `memory`");
+
+        let err = Module::new(&config(
+            "(module (memory (export \"memory\") 1))",
+            None,
+            None,
+        ))
+        .err()
+        .unwrap();
+        insta::assert_snapshot!(err, @"WebAssembly module must export `alloc`");
+
+        let err = Module::new(&config(
+            &ECHO.replace("\"restore\"", "\"other\""),
+            None,
+            None,
+        ))
+        .err()
+        .unwrap();
+        insta::assert_snapshot!(err, @"WebAssembly module which exports `update` must also export `restore`");
+
+        let err = Module::new(&config(ECHO, None, Some("lots")))
+            .err()
+            .unwrap();
+        insta::assert_snapshot!(err, @r###"invalid WebAssembly memory limit "lots""###);
+
+        // Memory limits are enforced when instantiating the module.
+        let module = Module::new(&config(ECHO, None, Some("1k"))).unwrap();
+        assert!(module.instantiate().is_err());
+    }
+}
@@ -0,0 +1,201 @@
+use crate::{AsNode, Node, Pointer, SerPolicy};
+use serde_json::Value;
+
+/// Expression computes a value from locations of a document.
+/// It's the evaluated form of a computed projection,
+/// and deserializes from a JSON-encoded models::ProjectionExpression.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub enum Expression {
+    Concat {
+        #[serde(deserialize_with = "de_ptrs")]
+        of: Vec<Pointer>,
+        #[serde(default)]
+        separator: String,
+    },
+    Lower(#[serde(deserialize_with = "de_ptr")] Pointer),
+    Upper(#[serde(deserialize_with = "de_ptr")] Pointer),
+    Hash(#[serde(deserialize_with = "de_ptrs")] Vec<Pointer>),
+    Coalesce(#[serde(deserialize_with = "de_ptrs")] Vec<Pointer>),
+    DateTrunc {
+        #[serde(deserialize_with = "de_ptr")]
+        location: Pointer,
+        unit: DateTruncUnit,
+    },
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DateTruncUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Month,
+    Year,
+}
+
+impl Expression {
+    /// Evaluate the Expression over a document.
+    /// Evaluation is infallible: locations which don't exist or have
+    /// an unexpected type are skipped, or produce a null value.
+    pub fn eval<N: AsNode>(&self, doc: &N) -> Value {
+        match self {
+            Self::Concat { of, separator } => {
+                let parts: Vec<String> = of
+                    .iter()
+                    .filter_map(|ptr| ptr.query(doc))
+                    .filter_map(string_form)
+                    .collect();
+
+                Value::String(parts.join(separator))
+            }
+            Self::Lower(ptr) => match ptr.query(doc).map(AsNode::as_node) {
+                Some(Node::String(s)) => Value::String(s.to_lowercase()),
+                _ => Value::Null,
+            },
+            Self::Upper(ptr) => match ptr.query(doc).map(AsNode::as_node) {
+                Some(Node::String(s)) => Value::String(s.to_uppercase()),
+                _ => Value::Null,
+            },
+            Self::Hash(of) => {
+                let values: Vec<Value> = of
+                    .iter()
+                    .map(|ptr| ptr.query(doc).map(to_value).unwrap_or_default())
+                    .collect();
+
+                let digest = md5::compute(serde_json::to_vec(&values).unwrap());
+                Value::String(format!("{digest:x}"))
+            }
+            Self::Coalesce(of) => of
+                .iter()
+                .filter_map(|ptr| ptr.query(doc))
+                .find(|node| !matches!(node.as_node(), Node::Null))
+                .map(to_value)
+                .unwrap_or_default(),
+            Self::DateTrunc { location, unit } => match location.query(doc).map(AsNode::as_node) {
+                Some(Node::String(s)) => {
+                    date_trunc(s, *unit).map(Value::String).unwrap_or_default()
+                }
+                _ => Value::Null,
+            },
+        }
+    }
+}
+
+// String form of a node, or None if it's null.
+fn string_form<N: AsNode>(node: &N) -> Option<String> {
+    match node.as_node() {
+        Node::Null => None,
+        Node::String(s) => Some(s.to_string()),
+        Node::Bool(b) => Some(b.to_string()),
+        Node::PosInt(n) => Some(n.to_string()),
+        Node::NegInt(n) => Some(n.to_string()),
+        Node::Float(n) => Some(n.to_string()),
+        _ => Some(to_value(node).to_string()),
+    }
+}
+
+fn to_value<N: AsNode>(node: &N) -> Value {
+    serde_json::to_value(&SerPolicy::noop().on(node)).unwrap()
+}
+
+fn date_trunc(s: &str, unit: DateTruncUnit) -> Option<String> {
+    let dt = json::schema::formats::parse_date_time(s)
+        .ok()?
+        .to_offset(time::UtcOffset::UTC);
+
+    let (d, t) = (dt.date(), dt.time());
+    let (d, t) = match unit {
+        DateTruncUnit::Second => (d, t.replace_nanosecond(0).ok()?),
+        DateTruncUnit::Minute => (d, time::Time::from_hms(t.hour(), t.minute(), 0).ok()?),
+        DateTruncUnit::Hour => (d, time::Time::from_hms(t.hour(), 0, 0).ok()?),
+        DateTruncUnit::Day => (d, time::Time::MIDNIGHT),
+        DateTruncUnit::Month => (d.replace_day(1).ok()?, time::Time::MIDNIGHT),
+        DateTruncUnit::Year => (
+            time::Date::from_calendar_date(d.year(), time::Month::January, 1).ok()?,
+            time::Time::MIDNIGHT,
+        ),
+    };
+
+    // Use a fixed-width format so that lexicographic ordering matches temporal ordering.
+    let format =
+        time::macros::format_description!("[year]-[month]-[day]T[hour]:[minute]:[second]Z");
+    d.with_time(t).assume_utc().format(format).ok()
+}
+
+fn de_ptr<'de, D: serde::Deserializer<'de>>(d: D) -> Result<Pointer, D::Error> {
+    let ptr: String = serde::Deserialize::deserialize(d)?;
+    Ok(Pointer::from_str(&ptr))
+}
+
+fn de_ptrs<'de, D: serde::Deserializer<'de>>(d: D) -> Result<Vec<Pointer>, D::Error> {
+    let ptrs: Vec<String> = serde::Deserialize::deserialize(d)?;
+    Ok(ptrs.iter().map(|ptr| Pointer::from_str(ptr)).collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_expressions() {
+        let doc = json!({
+            "first": "Jane",
+            "last": "Doe",
+            "age": 42,
+            "nil": null,
+            "tags": ["a", 1],
+            "ts": "2024-03-15T13:45:30.123456789-02:00",
+        });
+
+        let cases = [
+            (
+                json!({"concat": {"of": ["/first", "/nil", "/last", "/age", "/missing"], "separator": " "}}),
+                json!("Jane Doe 42"),
+            ),
+            (
+                json!({"concat": {"of": ["/first", "/tags"]}}),
+                json!("Jane[\"a\",1]"),
+            ),
+            (json!({"lower": "/first"}), json!("jane")),
+            (json!({"upper": "/last"}), json!("DOE")),
+            (json!({"upper": "/age"}), json!(null)),
+            (
+                json!({"coalesce": ["/missing", "/nil", "/age", "/first"]}),
+                json!(42),
+            ),
+            (json!({"coalesce": ["/missing", "/nil"]}), json!(null)),
+            (
+                json!({"hash": ["/first", "/missing"]}),
+                json!(format!("{:x}", md5::compute(r#"["Jane",null]"#))),
+            ),
+            (
+                json!({"dateTrunc": {"location": "/ts", "unit": "second"}}),
+                json!("2024-03-15T15:45:30Z"),
+            ),
+            (
+                json!({"dateTrunc": {"location": "/ts", "unit": "hour"}}),
+                json!("2024-03-15T15:00:00Z"),
+            ),
+            (
+                json!({"dateTrunc": {"location": "/ts", "unit": "month"}}),
+                json!("2024-03-01T00:00:00Z"),
+            ),
+            (
+                json!({"dateTrunc": {"location": "/ts", "unit": "year"}}),
+                json!("2024-01-01T00:00:00Z"),
+            ),
+            (
+                json!({"dateTrunc": {"location": "/first", "unit": "day"}}),
+                json!(null),
+            ),
+        ];
+
+        for (expression, expect) in cases {
+            let parsed: Expression = serde_json::from_value(expression.clone()).unwrap();
+            assert_eq!(parsed.eval(&doc), expect, "{expression}");
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{compare::compare, AsNode, Node, OwnedNode, Pointer, SerPolicy};
+use crate::{compare::compare, AsNode, Expression, Node, OwnedNode, Pointer, SerPolicy};
 use bytes::BufMut;
 use std::{
     borrow::Cow,
@@ -19,10 +19,11 @@ pub struct Extractor {
     magic: Option<Magic>,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 enum Magic {
     UuidV1DateTime,
     TruncationIndicator,
+    Computed(Expression),
 }
 
 impl Extractor {
@@ -67,6 +68,16 @@ impl Extractor {
         }
     }
 
+    /// Build an extractor which evaluates an Expression over documents.
+    pub fn for_expression(expression: Expression, policy: &SerPolicy) -> Self {
+        Self {
+            ptr: Pointer::empty(),
+            policy: policy.clone(),
+            default: serde_json::Value::Null,
+            magic: Some(Magic::Computed(expression)),
+        }
+    }
+
     /// Query the value extracted from the document.
     /// The result is Ok if a literal document node is extracted,
     /// or Err if the document value doesn't exist and an alternative
@@ -75,7 +86,8 @@ impl Extractor {
     /// Most commonly this is a default value for the location
     /// and a borrowed Value is returned.
     ///
-    /// Or, it may be a dynamic Value extracted from a UUID timestamp.
+    /// Or, it may be a dynamic Value extracted from a UUID timestamp
+    /// or computed by an Expression.
     pub fn query<'s, 'n, N: AsNode>(
         &'s self,
         doc: &'n N,
@@ -84,7 +96,7 @@ impl Extractor {
             return Err(Cow::Borrowed(&self.default));
         };
 
-        match self.magic {
+        match &self.magic {
             None => { /* sorry, kid, I guess your parents aren't coming back */ }
             Some(Magic::UuidV1DateTime) => {
                 if let Some(date_time) = match node.as_node() {
@@ -119,6 +131,9 @@ impl Extractor {
                 // value retroactively as part of `extract_all_indicate_truncation`.
                 return Err(Cow::Owned(serde_json::Value::Bool(false)));
             }
+            Some(Magic::Computed(expression)) => {
+                return Err(Cow::Owned(expression.eval(doc)));
+            }
         }
 
         Ok(node)
@@ -88,6 +88,10 @@ pub use ptr::Pointer;
 mod extractor;
 pub use extractor::{Extractor, TRUNCATION_INDICATOR_PTR};
 
+// Expressions compute values of documents, for computed projections.
+pub mod expression;
+pub use expression::Expression;
+
 // Walker is a medium-term integration joint between AsNode implementations
 // and our JSON-schema validator. We may seek to get rid of this and have
 // JSON-schema validation evaluate directly over AsNode.
@@ -116,6 +120,9 @@ pub use validation::{
 // Doc implementations may be reduced.
 pub mod reduce;
 
+// Sensitive document locations may be redacted.
+pub mod redact;
+
 // Documents may be combined.
 #[cfg(feature = "combine")]
 pub mod combine;
@@ -132,6 +139,9 @@ pub use shape::Shape;
 pub mod diff;
 pub use diff::diff;
 
+// RFC 6902 JSON Patch and RFC 7396 JSON Merge Patch between documents.
+pub mod patch;
+
 #[cfg(test)]
 mod test {
 
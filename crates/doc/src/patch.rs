@@ -0,0 +1,517 @@
+use super::{ptr::Token, AsNode, BumpVec, Field, Fields, HeapNode, Node, Pointer, SerPolicy};
+use itertools::{
+    EitherOrBoth::{Both, Left, Right},
+    Itertools,
+};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// Operation is a single operation of an RFC 6902 JSON Patch.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase", deny_unknown_fields)]
+pub enum Operation {
+    /// Add a value at `path`. An existing object property is replaced,
+    /// while array items are inserted before the given index.
+    Add { path: Pointer, value: Value },
+    /// Remove the value at `path`, which must exist.
+    Remove { path: Pointer },
+    /// Replace the value at `path`, which must exist.
+    Replace { path: Pointer, value: Value },
+    /// Remove the value at `from` and add it at `path`.
+    Move { from: Pointer, path: Pointer },
+    /// Add a copy of the value at `from` at `path`.
+    Copy { from: Pointer, path: Pointer },
+    /// Test that the value at `path` is equal to `value`.
+    Test { path: Pointer, value: Value },
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("patch location '{0}' does not exist")]
+    NotFound(Pointer),
+    #[error("cannot move '{from}' into its own child location '{path}'")]
+    MoveIntoChild { from: Pointer, path: Pointer },
+    #[error("patch test of location '{0}' failed")]
+    TestFailed(Pointer),
+}
+
+/// Compute an RFC 6902 JSON Patch which transforms document `from` into `to`.
+/// Object properties are added, removed, or recursively patched,
+/// and arrays are patched item-wise and then grown or truncated at their end.
+/// Other differing locations are replaced.
+pub fn json_patch<L: AsNode, R: AsNode>(from: &L, to: &R) -> Vec<Operation> {
+    let mut out = Vec::new();
+    json_patch_inner(from, to, &mut Pointer::empty(), &mut out);
+    out
+}
+
+/// Apply an RFC 6902 JSON Patch to the HeapNode document.
+/// The patch is applied atomically: if any operation fails then
+/// an Error is returned and the document is left unmodified.
+pub fn apply_json_patch<'alloc>(
+    doc: &mut HeapNode<'alloc>,
+    patch: &[Operation],
+    alloc: &'alloc bumpalo::Bump,
+) -> Result<(), Error> {
+    let mut scratch = HeapNode::from_node(doc, alloc);
+
+    for op in patch {
+        apply_operation(&mut scratch, op, alloc)?;
+    }
+    *doc = scratch;
+
+    Ok(())
+}
+
+/// Compute an RFC 7396 JSON Merge Patch which transforms document `from` into `to`.
+/// Merge patches cannot represent an explicit null value of an object property:
+/// such properties of `to` are instead removed when the patch is applied.
+pub fn merge_patch<L: AsNode, R: AsNode>(from: &L, to: &R) -> Value {
+    match (from.as_node(), to.as_node()) {
+        (Node::Object(from), Node::Object(to)) => {
+            let mut patch = serde_json::Map::new();
+
+            for eob in from
+                .iter()
+                .merge_join_by(to.iter(), |l, r| l.property().cmp(r.property()))
+            {
+                match eob {
+                    Left(from) => {
+                        patch.insert(from.property().to_string(), Value::Null);
+                    }
+                    Right(to) => {
+                        patch.insert(to.property().to_string(), to_value(to.value()));
+                    }
+                    Both(from, to) => {
+                        if !super::compare(from.value(), to.value()).is_eq() {
+                            patch.insert(
+                                to.property().to_string(),
+                                merge_patch(from.value(), to.value()),
+                            );
+                        }
+                    }
+                }
+            }
+            Value::Object(patch)
+        }
+        _ => to_value(to),
+    }
+}
+
+/// Apply an RFC 7396 JSON Merge Patch to the HeapNode document.
+pub fn apply_merge_patch<'alloc, N: AsNode>(
+    doc: &mut HeapNode<'alloc>,
+    patch: &N,
+    alloc: &'alloc bumpalo::Bump,
+) {
+    let Node::Object(patch_fields) = patch.as_node() else {
+        *doc = HeapNode::from_node(patch, alloc);
+        return;
+    };
+
+    if !matches!(doc, HeapNode::Object(_)) {
+        *doc = HeapNode::Object(BumpVec::new());
+    }
+    let HeapNode::Object(doc_fields) = doc else {
+        unreachable!("doc is an object")
+    };
+
+    for field in patch_fields.iter() {
+        if let Node::Null = field.value().as_node() {
+            doc_fields.remove_property(field.property());
+        } else {
+            apply_merge_patch(
+                doc_fields.insert_property(field.property(), alloc),
+                field.value(),
+                alloc,
+            );
+        }
+    }
+}
+
+fn json_patch_inner<L: AsNode, R: AsNode>(
+    from: &L,
+    to: &R,
+    path: &mut Pointer,
+    out: &mut Vec<Operation>,
+) {
+    match (from.as_node(), to.as_node()) {
+        (Node::Object(from), Node::Object(to)) => {
+            for eob in from
+                .iter()
+                .merge_join_by(to.iter(), |l, r| l.property().cmp(r.property()))
+            {
+                match eob {
+                    Left(from) => {
+                        path.push(Token::from_str(from.property()));
+                        out.push(Operation::Remove { path: path.clone() });
+                        path.0.pop();
+                    }
+                    Right(to) => {
+                        path.push(Token::from_str(to.property()));
+                        out.push(Operation::Add {
+                            path: path.clone(),
+                            value: to_value(to.value()),
+                        });
+                        path.0.pop();
+                    }
+                    Both(from, to) => {
+                        path.push(Token::from_str(from.property()));
+                        json_patch_inner(from.value(), to.value(), path, out);
+                        path.0.pop();
+                    }
+                }
+            }
+        }
+        (Node::Array(from), Node::Array(to)) => {
+            for (index, (from, to)) in from.iter().zip(to.iter()).enumerate() {
+                path.push(Token::Index(index));
+                json_patch_inner(from, to, path, out);
+                path.0.pop();
+            }
+            for (index, to) in to.iter().enumerate().skip(from.len()) {
+                path.push(Token::Index(index));
+                out.push(Operation::Add {
+                    path: path.clone(),
+                    value: to_value(to),
+                });
+                path.0.pop();
+            }
+            // Remove trailing items from the end, so that indices remain stable.
+            for index in (to.len()..from.len()).rev() {
+                path.push(Token::Index(index));
+                out.push(Operation::Remove { path: path.clone() });
+                path.0.pop();
+            }
+        }
+        _ if super::compare(from, to).is_eq() => {}
+        _ => out.push(Operation::Replace {
+            path: path.clone(),
+            value: to_value(to),
+        }),
+    }
+}
+
+fn apply_operation<'alloc>(
+    doc: &mut HeapNode<'alloc>,
+    op: &Operation,
+    alloc: &'alloc bumpalo::Bump,
+) -> Result<(), Error> {
+    match op {
+        Operation::Add { path, value } => add(doc, path, HeapNode::from_node(value, alloc), alloc),
+        Operation::Remove { path } => remove(doc, path).map(|_removed| ()),
+        Operation::Replace { path, value } => {
+            let node = query_mut(doc, &path.0).ok_or_else(|| Error::NotFound(path.clone()))?;
+            *node = HeapNode::from_node(value, alloc);
+            Ok(())
+        }
+        Operation::Move { from, path } => {
+            if path.0.len() > from.0.len() && path.0.starts_with(&from.0) {
+                return Err(Error::MoveIntoChild {
+                    from: from.clone(),
+                    path: path.clone(),
+                });
+            }
+            let node = remove(doc, from)?;
+            add(doc, path, node, alloc)
+        }
+        Operation::Copy { from, path } => {
+            let node = from
+                .query(&*doc)
+                .map(|node| HeapNode::from_node(node, alloc))
+                .ok_or_else(|| Error::NotFound(from.clone()))?;
+            add(doc, path, node, alloc)
+        }
+        Operation::Test { path, value } => match path.query(&*doc) {
+            Some(node) if super::compare(node, value).is_eq() => Ok(()),
+            _ => Err(Error::TestFailed(path.clone())),
+        },
+    }
+}
+
+fn add<'alloc>(
+    doc: &mut HeapNode<'alloc>,
+    path: &Pointer,
+    value: HeapNode<'alloc>,
+    alloc: &'alloc bumpalo::Bump,
+) -> Result<(), Error> {
+    let Some((last, parents)) = path.0.split_last() else {
+        *doc = value; // Replace the document root.
+        return Ok(());
+    };
+
+    match (query_mut(doc, parents), last) {
+        (Some(HeapNode::Object(fields)), token) => match object_property(token) {
+            Some(property) => *fields.insert_property(&property, alloc) = value,
+            None => return Err(Error::NotFound(path.clone())),
+        },
+        (Some(HeapNode::Array(items)), Token::Index(index)) if *index <= items.len() => {
+            items.insert(*index, value, alloc)
+        }
+        (Some(HeapNode::Array(items)), Token::NextIndex) => items.push(value, alloc),
+        (Some(HeapNode::Array(items)), Token::Property(property)) if property == "-" => {
+            items.push(value, alloc)
+        }
+        _ => return Err(Error::NotFound(path.clone())),
+    }
+    Ok(())
+}
+
+fn remove<'alloc>(doc: &mut HeapNode<'alloc>, path: &Pointer) -> Result<HeapNode<'alloc>, Error> {
+    let not_found = || Error::NotFound(path.clone());
+
+    let Some((last, parents)) = path.0.split_last() else {
+        return Ok(std::mem::replace(doc, HeapNode::Null));
+    };
+
+    match (query_mut(doc, parents), last) {
+        (Some(HeapNode::Object(fields)), token) => object_property(token)
+            .and_then(|property| fields.remove_property(&property))
+            .map(|field| field.value)
+            .ok_or_else(not_found),
+        (Some(HeapNode::Array(items)), Token::Index(index)) if *index < items.len() => {
+            Ok(items.remove(*index))
+        }
+        _ => Err(not_found()),
+    }
+}
+
+// Query a mutable, existing location of the document.
+fn query_mut<'n, 'alloc>(
+    mut node: &'n mut HeapNode<'alloc>,
+    tokens: &[Token],
+) -> Option<&'n mut HeapNode<'alloc>> {
+    for token in tokens {
+        node = match (node, token) {
+            (HeapNode::Object(fields), token) => {
+                let property = object_property(token)?;
+                let index = fields
+                    .binary_search_by(|field| field.property.as_str().cmp(&*property))
+                    .ok()?;
+                &mut fields[index].value
+            }
+            (HeapNode::Array(items), Token::Index(index)) => items.get_mut(*index)?,
+            _ => return None,
+        };
+    }
+    Some(node)
+}
+
+// Map a Token into the object property it references.
+fn object_property(token: &Token) -> Option<Cow<'_, str>> {
+    match token {
+        Token::Index(index) => Some(Cow::Owned(index.to_string())),
+        Token::Property(property) => Some(Cow::Borrowed(property)),
+        Token::NextIndex => Some(Cow::Borrowed("-")),
+        Token::NextProperty => None,
+    }
+}
+
+fn to_value<N: AsNode>(node: &N) -> Value {
+    serde_json::to_value(SerPolicy::noop().on(node)).unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_json_patch_round_trips() {
+        let cases = [
+            (
+                json!({"a": 1, "b": [1, 2], "c": {"d": "e"}}),
+                json!({"a": 1, "b": [1, 2], "c": {"d": "e"}}),
+            ),
+            (json!({"a": 1, "b": 2}), json!({"b": 3, "c": 4})),
+            (json!([1, {"a": 1}, 3, 4]), json!([1, {"a": 2}])),
+            (json!([1]), json!([1, [2], {"three": 3}])),
+            (
+                json!({"nested": {"1": true, "a/b": null}}),
+                json!({"nested": {"1": false, "c~d": null}}),
+            ),
+            (json!({"type": "changed"}), json!(["wholesale"])),
+            (json!(1), json!(1.0)),
+            (json!("a"), json!(null)),
+        ];
+
+        for (from, to) in cases {
+            let patch = json_patch(&from, &to);
+
+            // Applying the patch produces the expected document.
+            let alloc = HeapNode::new_allocator();
+            let mut doc = HeapNode::from_node(&from, &alloc);
+            apply_json_patch(&mut doc, &patch, &alloc).unwrap();
+            assert!(super::super::compare(&doc, &to).is_eq(), "{from} => {to}");
+
+            // The serialized patch is understood by another implementation.
+            let patch: json_patch::Patch =
+                serde_json::from_value(serde_json::to_value(&patch).unwrap()).unwrap();
+            let mut other = from.clone();
+            json_patch::patch(&mut other, &patch).unwrap();
+            assert!(super::super::compare(&other, &to).is_eq(), "{from} => {to}");
+        }
+    }
+
+    #[test]
+    fn test_json_patch_is_minimal() {
+        let patch = json_patch(
+            &json!({"keep": [1, 2, 3], "remove": true, "nested": {"a": 1, "b": 2}}),
+            &json!({"keep": [1, 5], "add": "yes", "nested": {"a": 1, "b": 3}}),
+        );
+
+        assert_eq!(
+            serde_json::to_value(&patch).unwrap(),
+            json!([
+                {"op": "add", "path": "/add", "value": "yes"},
+                {"op": "replace", "path": "/keep/1", "value": 5},
+                {"op": "remove", "path": "/keep/2"},
+                {"op": "replace", "path": "/nested/b", "value": 3},
+                {"op": "remove", "path": "/remove"},
+            ])
+        );
+    }
+
+    #[test]
+    fn test_apply_json_patch_operations() {
+        let patch: Vec<Operation> = serde_json::from_value(json!([
+            {"op": "test", "path": "/a", "value": [1, 2]},
+            {"op": "add", "path": "/a/1", "value": "inserted"},
+            {"op": "add", "path": "/a/-", "value": "appended"},
+            {"op": "copy", "from": "/a", "path": "/b"},
+            {"op": "move", "from": "/a/0", "path": "/c"},
+            {"op": "replace", "path": "/b/3", "value": "replaced"},
+            {"op": "remove", "path": "/d"},
+        ]))
+        .unwrap();
+
+        let alloc = HeapNode::new_allocator();
+        let mut doc = HeapNode::from_node(&json!({"a": [1, 2], "d": true}), &alloc);
+        apply_json_patch(&mut doc, &patch, &alloc).unwrap();
+
+        assert_eq!(
+            to_value(&doc),
+            json!({
+                "a": ["inserted", 2, "appended"],
+                "b": [1, "inserted", 2, "replaced"],
+                "c": 1,
+            })
+        );
+
+        // Failed patches leave the document unmodified.
+        for (patch, expect) in [
+            (
+                json!([{"op": "remove", "path": "/a/0"}, {"op": "test", "path": "/c", "value": 2}]),
+                "patch test of location '/c' failed",
+            ),
+            (
+                json!([{"op": "remove", "path": "/a/0"}, {"op": "remove", "path": "/a/9"}]),
+                "patch location '/a/9' does not exist",
+            ),
+            (
+                json!([{"op": "move", "from": "/a", "path": "/a/0"}]),
+                "cannot move '/a' into its own child location '/a/0'",
+            ),
+        ] {
+            let patch: Vec<Operation> = serde_json::from_value(patch).unwrap();
+            let err = apply_json_patch(&mut doc, &patch, &alloc).unwrap_err();
+            assert_eq!(err.to_string(), expect);
+            assert_eq!(to_value(&doc)["a"], json!(["inserted", 2, "appended"]));
+        }
+    }
+
+    #[test]
+    fn test_merge_patch_round_trips() {
+        let cases = [
+            (
+                json!({"a": 1, "b": {"c": 2, "d": 3}}),
+                json!({"a": 1, "b": {"c": 2, "d": 3}}),
+                json!({}),
+            ),
+            (
+                json!({"a": 1, "b": {"c": 2, "d": 3}, "e": [1, 2]}),
+                json!({"b": {"c": 4, "d": 3, "f": {"g": true}}, "e": [2]}),
+                json!({"a": null, "b": {"c": 4, "f": {"g": true}}, "e": [2]}),
+            ),
+            (
+                json!({"a": {"b": 1}}),
+                json!({"a": "scalar"}),
+                json!({"a": "scalar"}),
+            ),
+            (json!([1, 2]), json!({"a": 1}), json!({"a": 1})),
+            (json!({"a": 1}), json!(true), json!(true)),
+        ];
+
+        for (from, to, expect) in cases {
+            let patch = merge_patch(&from, &to);
+            assert_eq!(patch, expect, "{from} => {to}");
+
+            let alloc = HeapNode::new_allocator();
+            let mut doc = HeapNode::from_node(&from, &alloc);
+            apply_merge_patch(&mut doc, &patch, &alloc);
+            assert_eq!(to_value(&doc), to, "{from} => {to}");
+
+            // Another implementation agrees on the patch outcome.
+            let mut other = from.clone();
+            json_patch::merge(&mut other, &patch);
+            assert_eq!(other, to, "{from} => {to}");
+        }
+    }
+
+    #[test]
+    fn test_apply_merge_patch_rfc_examples() {
+        // Examples from RFC 7396 Appendix A.
+        let cases = [
+            (json!({"a": "b"}), json!({"a": "c"}), json!({"a": "c"})),
+            (
+                json!({"a": "b"}),
+                json!({"b": "c"}),
+                json!({"a": "b", "b": "c"}),
+            ),
+            (json!({"a": "b"}), json!({"a": null}), json!({})),
+            (
+                json!({"a": "b", "b": "c"}),
+                json!({"a": null}),
+                json!({"b": "c"}),
+            ),
+            (json!({"a": ["b"]}), json!({"a": "c"}), json!({"a": "c"})),
+            (json!({"a": "c"}), json!({"a": ["b"]}), json!({"a": ["b"]})),
+            (
+                json!({"a": {"b": "c"}}),
+                json!({"a": {"b": "d", "c": null}}),
+                json!({"a": {"b": "d"}}),
+            ),
+            (
+                json!({"a": [{"b": "c"}]}),
+                json!({"a": [1]}),
+                json!({"a": [1]}),
+            ),
+            (json!(["a", "b"]), json!(["c", "d"]), json!(["c", "d"])),
+            (json!({"a": "b"}), json!(["c"]), json!(["c"])),
+            (json!({"a": "foo"}), json!(null), json!(null)),
+            (json!({"a": "foo"}), json!("bar"), json!("bar")),
+            (
+                json!({"e": null}),
+                json!({"a": 1}),
+                json!({"a": 1, "e": null}),
+            ),
+            (
+                json!([1, 2]),
+                json!({"a": "b", "c": null}),
+                json!({"a": "b"}),
+            ),
+            (
+                json!({}),
+                json!({"a": {"bb": {"ccc": null}}}),
+                json!({"a": {"bb": {}}}),
+            ),
+        ];
+
+        for (target, patch, expect) in cases {
+            let alloc = HeapNode::new_allocator();
+            let mut doc = HeapNode::from_node(&target, &alloc);
+            apply_merge_patch(&mut doc, &patch, &alloc);
+            assert_eq!(to_value(&doc), expect, "{target} + {patch}");
+        }
+    }
+}
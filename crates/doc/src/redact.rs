@@ -0,0 +1,190 @@
+use crate::{shape::Shape, BumpStr, HeapNode};
+use sha2::Digest;
+
+/// Strategy for redacting a sensitive document location,
+/// such as one holding personally-identifiable information.
+/// Strategies are ordered by increasing strength: where multiple strategies
+/// apply at a location, the strongest of them is used.
+#[derive(
+    serde::Serialize, serde::Deserialize, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord,
+)]
+#[serde(tag = "strategy", deny_unknown_fields, rename_all = "camelCase")]
+pub enum Strategy {
+    /// Replace each character of a string with '*', preserving its length.
+    Mask,
+    /// Replace a string with the hex-encoded SHA-256 digest of its value.
+    /// Equal values have equal digests, so redacted values may still be
+    /// joined or grouped upon.
+    Sha256,
+    /// Remove the location from the document entirely.
+    /// Blocked array items are replaced with null, to preserve the
+    /// indices of subsequent items.
+    Block,
+}
+
+impl std::convert::TryFrom<&serde_json::Value> for Strategy {
+    type Error = serde_json::Error;
+
+    fn try_from(v: &serde_json::Value) -> std::result::Result<Self, Self::Error> {
+        <Strategy as serde::Deserialize>::deserialize(v)
+    }
+}
+
+impl Shape {
+    /// Returns true if this Shape, or any of its children, has a redaction strategy.
+    pub fn has_redactions(&self) -> bool {
+        self.redact.is_some()
+            || self.array.tuple.iter().any(Shape::has_redactions)
+            || self
+                .array
+                .additional_items
+                .as_ref()
+                .map_or(false, |s| s.has_redactions())
+            || self
+                .object
+                .properties
+                .iter()
+                .any(|p| p.shape.has_redactions())
+            || self
+                .object
+                .pattern_properties
+                .iter()
+                .any(|p| p.shape.has_redactions())
+            || self
+                .object
+                .additional_properties
+                .as_ref()
+                .map_or(false, |s| s.has_redactions())
+    }
+
+    /// Redact the HeapNode document in place, applying the redaction strategies
+    /// of this Shape to their corresponding document locations.
+    /// Locations having a string-only redaction strategy (`mask` or `sha256`)
+    /// are left as-is if they're not strings.
+    pub fn redact<'alloc>(&self, doc: &mut HeapNode<'alloc>, alloc: &'alloc bumpalo::Bump) {
+        if self.redact_inner(doc, alloc) {
+            *doc = HeapNode::Null; // The document root itself is blocked.
+        }
+    }
+
+    // Returns true if the node is blocked and must be removed by its parent.
+    fn redact_inner<'alloc>(
+        &self,
+        node: &mut HeapNode<'alloc>,
+        alloc: &'alloc bumpalo::Bump,
+    ) -> bool {
+        match (self.redact, &mut *node) {
+            (Some(Strategy::Block), _) => return true,
+            (Some(Strategy::Mask), HeapNode::String(s)) => {
+                let masked = "*".repeat(s.chars().count());
+                *s = BumpStr::from_str(&masked, alloc);
+                return false;
+            }
+            (Some(Strategy::Sha256), HeapNode::String(s)) => {
+                let digest = sha2::Sha256::digest(s.as_bytes());
+                *s = BumpStr::from_str(&format!("{digest:x}"), alloc);
+                return false;
+            }
+            _ => (),
+        }
+
+        match node {
+            HeapNode::Array(items) => {
+                for (index, item) in items.iter_mut().enumerate() {
+                    let child = match self.array.tuple.get(index) {
+                        Some(child) => child,
+                        None => match &self.array.additional_items {
+                            Some(child) => child.as_ref(),
+                            None => break,
+                        },
+                    };
+                    if child.redact_inner(item, alloc) {
+                        *item = HeapNode::Null;
+                    }
+                }
+            }
+            HeapNode::Object(fields) => {
+                let mut index = 0;
+                while index != fields.len() {
+                    let field = &mut fields[index];
+                    let (child, _) = self.obj_property_location(&field.property);
+
+                    if child.redact_inner(&mut field.value, alloc) {
+                        fields.remove(index);
+                    } else {
+                        index += 1;
+                    }
+                }
+            }
+            _ => (),
+        }
+
+        false
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{shape::shape_from, SerPolicy};
+    use serde_json::json;
+
+    #[test]
+    fn test_redaction() {
+        let shape = shape_from(
+            r#"
+        type: object
+        properties:
+            name: {type: string, redact: {strategy: mask}}
+            email: {type: string, redact: {strategy: sha256}}
+            ssn: {type: string, redact: {strategy: block}}
+            phones:
+                type: array
+                items: {type: [string, "null"], redact: {strategy: sha256}}
+            notes:
+                type: array
+                items: [true, {redact: {strategy: block}}]
+        patternProperties:
+            ^secret-: {redact: {strategy: block}}
+        additionalProperties:
+            type: object
+            properties:
+                token: {type: string, redact: {strategy: mask}}
+        "#,
+        );
+        assert!(shape.has_redactions());
+
+        let fixture = json!({
+            "name": "Jane Doe",
+            "email": "jane@example.com",
+            "ssn": "123-45-6789",
+            "phones": ["555-1234", null],
+            "notes": ["one", "two", "three"],
+            "secret-key": {"nested": true},
+            "other": {"token": "abc", "keep": "me"},
+            "untouched": 42,
+        });
+
+        let alloc = HeapNode::new_allocator();
+        let mut doc = HeapNode::from_node(&fixture, &alloc);
+        shape.redact(&mut doc, &alloc);
+
+        let sha256 = |s: &str| format!("{:x}", sha2::Sha256::digest(s.as_bytes()));
+
+        assert_eq!(
+            serde_json::to_value(SerPolicy::noop().on(&doc)).unwrap(),
+            json!({
+                "name": "********",
+                "email": sha256("jane@example.com"),
+                "phones": [sha256("555-1234"), null],
+                "notes": ["one", null, "three"],
+                "other": {"token": "***", "keep": "me"},
+                "untouched": 42,
+            })
+        );
+
+        // A Shape without any redactions is a no-op.
+        let shape = shape_from("{type: object, properties: {name: {type: string}}}");
+        assert!(!shape.has_redactions());
+    }
+}
@@ -179,6 +179,7 @@ impl Shape {
                     // but explicitly mentioned so that a compiler error will force us to check
                     // here as new annotations are added.
                     Annotation::Secret(b) => shape.secret = Some(*b),
+                    Annotation::Redact(r) => shape.redact = Some(*r),
                     Annotation::Multiline(_) => {}
                     Annotation::Advanced(_) => {}
                     Annotation::Order(_) => {}
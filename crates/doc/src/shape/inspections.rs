@@ -1,6 +1,6 @@
 /// This module implements various inspections which can be performed over Shapes.
 use super::*;
-use crate::reduce::Strategy;
+use crate::{redact, reduce::Strategy};
 use json::{schema::formats::Format, LocatedProperty, Location};
 
 #[derive(thiserror::Error, Debug, Eq, PartialEq)]
@@ -23,6 +23,14 @@ pub enum Error {
     SetInvalidProperty(String),
     #[error("{0} default value is invalid: {1}")]
     InvalidDefaultValue(String, crate::FailedValidation),
+    #[error("{0} has '{1:?}' redaction strategy, restricted to strings, but has types {2:?}")]
+    RedactNotString(String, redact::Strategy, types::Set),
+    #[error("{0} has 'block' redaction strategy, but is required to exist")]
+    RedactBlockMustExist(String),
+    #[error("{0} is an array item with 'block' redaction strategy, which replaces it with null, but has types {1:?}")]
+    RedactBlockItemNotNull(String, types::Set),
+    #[error("{0} has '{1:?}' redaction strategy, but its redacted values cannot satisfy its '{2}' constraint")]
+    RedactConstrained(String, redact::Strategy, &'static str),
 }
 
 impl Shape {
@@ -114,6 +122,40 @@ impl Shape {
             }
         }
 
+        // Blocked array items are replaced with null rather than removed.
+        let is_item = matches!(loc, Location::Item(_) | Location::EndOfArray(_));
+
+        match self.redact {
+            Some(redact::Strategy::Block) if is_item && !self.type_.overlaps(types::NULL) => {
+                out.push(Error::RedactBlockItemNotNull(
+                    loc.pointer_str().to_string(),
+                    self.type_,
+                ));
+            }
+            Some(redact::Strategy::Block) if must_exist && !is_item => {
+                out.push(Error::RedactBlockMustExist(loc.pointer_str().to_string()));
+            }
+            Some(strategy @ (redact::Strategy::Mask | redact::Strategy::Sha256))
+                if self.type_ - (types::STRING | types::NULL) != types::INVALID =>
+            {
+                out.push(Error::RedactNotString(
+                    loc.pointer_str().to_string(),
+                    strategy,
+                    self.type_,
+                ));
+            }
+            Some(strategy @ (redact::Strategy::Mask | redact::Strategy::Sha256)) => {
+                if let Some(keyword) = self.redact_constraint(strategy) {
+                    out.push(Error::RedactConstrained(
+                        loc.pointer_str().to_string(),
+                        strategy,
+                        keyword,
+                    ));
+                }
+            }
+            _ => (),
+        }
+
         for (loc, child_must_exist, child) in items
             .chain(addl_items)
             .chain(props)
@@ -131,11 +173,33 @@ impl Shape {
             child.inspect_inner(loc, must_exist && child_must_exist, out);
         }
     }
+
+    // Documents are redacted before they're validated, so a redacted value
+    // must still satisfy the string constraints of its location.
+    // Returns the first constraint which it cannot satisfy.
+    fn redact_constraint(&self, strategy: redact::Strategy) -> Option<&'static str> {
+        const SHA256_HEX_LEN: u32 = 64;
+
+        if self.enum_.is_some() {
+            Some("enum")
+        } else if self.string.format.is_some() {
+            Some("format")
+        } else if strategy != redact::Strategy::Sha256 {
+            None // Masking preserves the length of its value.
+        } else if matches!(self.string.max_length, Some(max) if max < SHA256_HEX_LEN) {
+            Some("maxLength")
+        } else if self.string.min_length > SHA256_HEX_LEN {
+            Some("minLength")
+        } else {
+            None
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::{shape_from, Error};
+    use crate::redact::Strategy;
     use json::schema::types;
     use pretty_assertions::assert_eq;
 
@@ -219,4 +283,65 @@ mod test {
             ]
         );
     }
+
+    #[test]
+    fn test_redaction_errors() {
+        let obj = shape_from(
+            r#"
+        type: object
+        properties:
+            masked-string:
+                type: [string, "null"]
+                redact: {strategy: mask}
+            hashed-integer:
+                type: integer
+                redact: {strategy: sha256}
+            blocked-optional:
+                redact: {strategy: block}
+            blocked-required:
+                type: object
+                redact: {strategy: block}
+            email-format:
+                type: string
+                format: email
+                redact: {strategy: sha256}
+            hashed-short:
+                type: string
+                maxLength: 32
+                redact: {strategy: sha256}
+            masked-short:
+                type: string
+                maxLength: 32
+                redact: {strategy: mask}
+            blocked-items:
+                type: array
+                items:
+                    - {type: [string, "null"], redact: {strategy: block}}
+                    - {type: string, redact: {strategy: block}}
+                additionalItems:
+                    type: integer
+                    redact: {strategy: block}
+        required: [blocked-required]
+        additionalProperties:
+            redact: {strategy: mask}
+        "#,
+        );
+
+        assert_eq!(
+            obj.inspect(),
+            vec![
+                Error::RedactBlockItemNotNull("/blocked-items/1".to_owned(), types::STRING),
+                Error::RedactBlockItemNotNull("/blocked-items/-".to_owned(), types::INTEGER),
+                Error::RedactBlockMustExist("/blocked-required".to_owned()),
+                Error::RedactConstrained("/email-format".to_owned(), Strategy::Sha256, "format"),
+                Error::RedactNotString(
+                    "/hashed-integer".to_owned(),
+                    Strategy::Sha256,
+                    types::INTEGER
+                ),
+                Error::RedactConstrained("/hashed-short".to_owned(), Strategy::Sha256, "maxLength"),
+                Error::RedactNotString("/*".to_owned(), Strategy::Mask, types::ANY),
+            ]
+        );
+    }
 }
@@ -237,6 +237,7 @@ impl Shape {
         let provenance = lhs.provenance.intersect(rhs.provenance);
         let default = lhs.default.or(rhs.default);
         let secret = lhs.secret.or(rhs.secret);
+        let redact = lhs.redact.max(rhs.redact);
 
         let mut annotations = rhs.annotations;
         annotations.extend(lhs.annotations.into_iter());
@@ -279,6 +280,7 @@ impl Shape {
             provenance,
             default,
             secret,
+            redact,
             annotations,
             string,
             array,
@@ -147,7 +147,7 @@ impl Shape {
         }
     }
 
-    fn obj_property_location(&self, prop: &str) -> (&Shape, Exists) {
+    pub(crate) fn obj_property_location(&self, prop: &str) -> (&Shape, Exists) {
         if let Some(property) = self.object.properties.iter().find(|p| *p.name == *prop) {
             let exists = if self.type_ == types::OBJECT && property.is_required {
                 // A property must exist iff this location can _only_ be an object,
@@ -35,6 +35,9 @@ pub struct Shape {
     pub default: Option<Box<(Value, Option<super::FailedValidation>)>>,
     /// Is this location sensitive? For example, a password or credential.
     pub secret: Option<bool>,
+    /// Location's `redact` strategy, if it holds sensitive data
+    /// (such as PII) which must be redacted from captured documents.
+    pub redact: Option<crate::redact::Strategy>,
     /// Annotations are any keywords starting with `X-` or `x-`.
     /// Their keys and values are collected here, without performing any
     /// normalization of prefix case. Technically both `x-foo` and `X-foo` may be
@@ -174,6 +177,7 @@ impl Shape {
             provenance: Provenance::Unset,
             default: None,
             secret: None,
+            redact: None,
             annotations: BTreeMap::new(),
             array: ArrayShape::new(),
             numeric: NumericShape::new(),
@@ -194,6 +198,7 @@ impl Shape {
             provenance: Provenance::Inline,
             default: None,
             secret: None,
+            redact: None,
             annotations: BTreeMap::new(),
             array: ArrayShape::new(),
             numeric: NumericShape::new(),
@@ -252,7 +257,7 @@ fn impute_property_shape(
 
 #[cfg(test)]
 // Map a JSON schema, in YAML form, into a Shape.
-fn shape_from(schema_yaml: &str) -> Shape {
+pub(crate) fn shape_from(schema_yaml: &str) -> Shape {
     let url = url::Url::parse("http://example/schema").unwrap();
     let schema: Value = serde_yaml::from_str(schema_yaml).unwrap();
     let schema =
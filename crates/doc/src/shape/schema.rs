@@ -23,6 +23,7 @@ fn to_sub_schema(shape: Shape) -> Schema {
         provenance: _, // Not mapped to a schema.
         default,
         secret,
+        redact,
         annotations,
         array,
         numeric,
@@ -167,6 +168,10 @@ fn to_sub_schema(shape: Shape) -> Schema {
             out.extensions
                 .insert("secret".to_string(), serde_json::json!(true));
         }
+        if let Some(redact) = redact {
+            out.extensions
+                .insert("redact".to_string(), serde_json::json!(redact));
+        }
 
         match reduction {
             Reduction::Unset => {}
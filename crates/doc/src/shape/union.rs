@@ -254,6 +254,9 @@ impl Shape {
         let provenance = lhs.provenance.union(rhs.provenance);
         let default = union_option(lhs.default, rhs.default);
         let secret = union_option(lhs.secret, rhs.secret);
+        // Unlike other annotations, a redaction of either side is preserved
+        // so that sensitive values are never inadvertently revealed.
+        let redact = lhs.redact.max(rhs.redact);
 
         // Union of annotations is actually an _intersection_, which yields only
         // the annotations that are guaranteed to apply at a given location.
@@ -302,6 +305,7 @@ impl Shape {
             provenance,
             default,
             secret,
+            redact,
             annotations,
             string,
             array,
@@ -193,11 +193,16 @@ impl<'schema, 'doc, 'tmp, N: AsNode> Validation<'schema, 'doc, 'tmp, N> {
         assert!(full_validator.invalid());
         assert_eq!(span, full_span);
 
+        // TODO: It might be a good idea to add a field on `FailedValidation` to indicate
+        // whether the document serialized here has been truncated.
+        let document = serde_json::to_value(SerPolicy::debug().on(document)).unwrap();
+        let explanation =
+            json::validator::explain(full_validator.outcomes(), &document).unwrap_or_default();
+
         Err(FailedValidation {
-            // TODO: It might be a good idea to add a field on `FailedValidation` to indicate
-            // whether the document serialized here has been truncated.
-            document: serde_json::to_value(SerPolicy::debug().on(document)).unwrap(),
             basic_output: json::validator::build_basic_output(full_validator.outcomes()),
+            document,
+            explanation,
         })
     }
 }
@@ -206,11 +211,22 @@ impl<'schema, 'doc, 'tmp, N: AsNode> Validation<'schema, 'doc, 'tmp, N> {
 pub struct FailedValidation {
     pub basic_output: serde_json::Value,
     pub document: serde_json::Value,
+    /// Short, human-friendly explanation of the most relevant validation error.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub explanation: String,
 }
 
 impl std::fmt::Display for FailedValidation {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        serde_json::to_string_pretty(self).unwrap().fmt(f)
+        // Lead with the explanation, followed by full details of the failure.
+        if !self.explanation.is_empty() {
+            writeln!(f, "{}", self.explanation)?;
+        }
+        let details = serde_json::json!({
+            "basic_output": self.basic_output,
+            "document": self.document,
+        });
+        serde_json::to_string_pretty(&details).unwrap().fmt(f)
     }
 }
 impl std::error::Error for FailedValidation {}
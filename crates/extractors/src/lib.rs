@@ -10,6 +10,8 @@ pub enum Error {
     InferenceNotFound,
     #[error("failed to parse inferred projection default value")]
     ParseDefault(#[source] serde_json::Error),
+    #[error("failed to parse computed projection expression")]
+    ParseExpression(#[source] serde_json::Error),
 }
 type Result<T> = std::result::Result<T, Error>;
 
@@ -86,6 +88,13 @@ pub fn for_projection(
         return Err(Error::InferenceNotFound);
     };
 
+    // Computed projections evaluate an expression, rather than extracting a location.
+    if !projection.expression_json.is_empty() {
+        let expression: doc::Expression =
+            serde_json::from_str(&projection.expression_json).map_err(Error::ParseExpression)?;
+        return Ok(doc::Extractor::for_expression(expression, policy));
+    }
+
     // Special-case for date-time extracted from the clock component of a UUID.
     // Compare to assemble::inference_uuid_v1_date_time().
     if matches!(inf,
@@ -241,4 +250,40 @@ mod test {
         ]
         "###);
     }
+
+    #[test]
+    fn test_computed_projection() {
+        let projections: Vec<flow::Projection> = serde_json::from_value(json!([
+            {"field": "full_name", "ptr": "/_meta/computed/full_name", "explicit": true, "inference": {},
+                "expression": {"concat": {"of": ["/first", "/last"], "separator": " "}}},
+            {"field": "lower", "ptr": "/_meta/computed/lower", "explicit": true, "inference": {},
+                "expression": {"lower": "/last"}},
+        ]))
+        .unwrap();
+
+        let extractors = for_fields(
+            &["full_name", "lower"],
+            &projections,
+            &doc::SerPolicy::noop(),
+        )
+        .unwrap();
+        let doc = json!({"first": "Jane", "last": "Doe"});
+
+        let extracted: Vec<_> = extractors
+            .iter()
+            .map(|ex| match ex.query(&doc) {
+                Ok(node) => node.clone(),
+                Err(value) => value.into_owned(),
+            })
+            .collect();
+
+        assert_eq!(extracted, vec![json!("Jane Doe"), json!("doe")]);
+
+        let mut invalid = projections[1].clone();
+        invalid.expression_json = r#"{"lower": 42}"#.to_string();
+        assert!(matches!(
+            for_projection(&invalid, &doc::SerPolicy::noop()),
+            Err(Error::ParseExpression(_))
+        ));
+    }
 }
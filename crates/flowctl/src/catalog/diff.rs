@@ -0,0 +1,550 @@
+use crate::catalog::{collect_specs, fetch_live_specs, List, LiveSpecRow, NameSelector};
+use crate::output::{to_table_row, CliOutput, JsonCell};
+use crate::{local_specs, CliContext};
+use doc::patch::Operation;
+use itertools::{EitherOrBoth, Itertools};
+use models::CatalogType;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+#[derive(Debug, clap::Args)]
+pub struct Diff {
+    /// Path or URL to a Flow specification file to compare with live specifications.
+    #[clap(long)]
+    source: String,
+    /// Also compare with all live specifications under the given prefix.
+    ///
+    /// Live specifications under the prefix which are not in the source
+    /// are reported as removed. May be provided multiple times.
+    #[clap(long)]
+    prefix: Vec<String>,
+}
+
+/// Difference of a single catalog specification.
+#[derive(Serialize)]
+pub struct SpecDiff {
+    pub catalog_name: String,
+    pub spec_type: CatalogType,
+    pub change: Change,
+    /// Changed locations of the specification, if `change` is Changed.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub changes: Vec<LocationChange>,
+}
+
+#[derive(Serialize, Debug, Copy, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Change {
+    Added,
+    Removed,
+    Changed,
+}
+
+/// Change at a location of a specification, such as a binding,
+/// a schema keyword, or an endpoint configuration property.
+#[derive(Serialize)]
+pub struct LocationChange {
+    pub location: String,
+    pub change: Change,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<Value>,
+    /// Whether the location is sops-encrypted in either model.
+    /// Encrypted values are masked, and cannot be compared with their plain-text form.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub encrypted: bool,
+}
+
+impl CliOutput for SpecDiff {
+    type TableAlt = ();
+    type CellValue = JsonCell;
+
+    fn table_headers(_alt: Self::TableAlt) -> Vec<&'static str> {
+        vec!["Name", "Type", "Change", "Details"]
+    }
+
+    fn into_table_row(self, _alt: Self::TableAlt) -> Vec<Self::CellValue> {
+        let details = self
+            .changes
+            .iter()
+            .map(
+                |LocationChange {
+                     location,
+                     change,
+                     before,
+                     after,
+                     encrypted,
+                 }| {
+                    let compact =
+                        |v: &Option<Value>| v.as_ref().map(Value::to_string).unwrap_or_default();
+                    match change {
+                        Change::Added => format!("+ {location}: {}", compact(after)),
+                        Change::Removed => format!("- {location}: {}", compact(before)),
+                        Change::Changed if *encrypted => {
+                            format!("~ {location}: changed (encrypted)")
+                        }
+                        Change::Changed => {
+                            format!("~ {location}: {} => {}", compact(before), compact(after))
+                        }
+                    }
+                },
+            )
+            .join("\n");
+
+        let mut row = to_table_row(&self, &["/catalog_name", "/spec_type", "/change"]);
+        row.push(JsonCell(Some(Value::String(details))));
+        row
+    }
+}
+
+pub async fn do_diff(ctx: &mut CliContext, args: &Diff) -> anyhow::Result<()> {
+    let source = build::arg_source_to_url(&args.source, false)?;
+    let draft = local_specs::surface_errors(local_specs::load(&source).await.into_result())?;
+    let local = models_by_name(local_specs::into_catalog(draft));
+
+    let columns = vec![
+        "catalog_name",
+        "id",
+        "updated_at",
+        "last_pub_id",
+        "spec_type",
+        "spec",
+    ];
+    let mut live_specs = fetch_live_specs::<LiveSpecRow>(
+        &ctx.client,
+        &List {
+            flows: false,
+            name_selector: NameSelector {
+                name: local.keys().cloned().collect(),
+                prefix: Vec::new(),
+            },
+            type_selector: Default::default(),
+        },
+        columns.clone(),
+    )
+    .await?;
+
+    if !args.prefix.is_empty() {
+        live_specs.extend(
+            fetch_live_specs::<LiveSpecRow>(
+                &ctx.client,
+                &List {
+                    flows: false,
+                    name_selector: NameSelector {
+                        name: Vec::new(),
+                        prefix: args.prefix.clone(),
+                    },
+                    type_selector: Default::default(),
+                },
+                columns,
+            )
+            .await?,
+        );
+    }
+    tracing::debug!(count = live_specs.len(), "successfully fetched live specs");
+
+    // Specs may have been fetched by both name and prefix.
+    let live_specs = live_specs
+        .into_iter()
+        .unique_by(|row| row.catalog_name.clone());
+    let live = models_by_name(local_specs::into_catalog(collect_specs(live_specs)?));
+
+    let diffs = diff_catalogs(live, local);
+    ctx.write_all(diffs, ())
+}
+
+// Map each specification of the Catalog to its type and JSON model.
+pub(super) fn models_by_name(catalog: models::Catalog) -> BTreeMap<String, (CatalogType, Value)> {
+    let models::Catalog {
+        captures,
+        collections,
+        materializations,
+        tests,
+        ..
+    } = catalog;
+
+    captures
+        .into_iter()
+        .map(|(name, model)| (name.to_string(), (CatalogType::Capture, to_value(model))))
+        .chain(
+            collections.into_iter().map(|(name, model)| {
+                (name.to_string(), (CatalogType::Collection, to_value(model)))
+            }),
+        )
+        .chain(materializations.into_iter().map(|(name, model)| {
+            (
+                name.to_string(),
+                (CatalogType::Materialization, to_value(model)),
+            )
+        }))
+        .chain(
+            tests
+                .into_iter()
+                .map(|(name, model)| (name.to_string(), (CatalogType::Test, to_value(model)))),
+        )
+        .collect()
+}
+
+fn to_value(model: impl Serialize) -> Value {
+    let mut value = serde_json::to_value(model).expect("models always serialize");
+
+    // Live models carry their last publication ID as an expectation, which isn't a difference.
+    if let Value::Object(fields) = &mut value {
+        fields.remove("expectPubId");
+    }
+    value
+}
+
+pub(super) fn diff_catalogs(
+    live: BTreeMap<String, (CatalogType, Value)>,
+    local: BTreeMap<String, (CatalogType, Value)>,
+) -> Vec<SpecDiff> {
+    let mut out = Vec::new();
+
+    for eob in live.into_iter().merge_join_by(local, |l, r| l.0.cmp(&r.0)) {
+        let (catalog_name, spec_type, change, changes) = match eob {
+            EitherOrBoth::Left((name, (spec_type, _live))) => {
+                (name, spec_type, Change::Removed, Vec::new())
+            }
+            EitherOrBoth::Right((name, (spec_type, _local))) => {
+                (name, spec_type, Change::Added, Vec::new())
+            }
+            EitherOrBoth::Both((name, (spec_type, live)), (_, (_, local))) => {
+                let changes = diff_model(spec_type, live, local);
+                if changes.is_empty() {
+                    continue;
+                }
+                (name, spec_type, Change::Changed, changes)
+            }
+        };
+        out.push(SpecDiff {
+            catalog_name,
+            spec_type,
+            change,
+            changes,
+        });
+    }
+    out
+}
+
+// Semantically diff the live and local models of a specification.
+// Bindings (or derivation transforms) are matched with one another by their
+// collection (or name), and other differences are reported by location.
+fn diff_model(spec_type: CatalogType, mut live: Value, mut local: Value) -> Vec<LocationChange> {
+    strip_sops(&mut live);
+    strip_sops(&mut local);
+
+    let mut out = Vec::new();
+
+    let (parent, property, key): (&str, &str, fn(&Value) -> Option<&str>) = match spec_type {
+        CatalogType::Capture => ("", "bindings", capture_binding_key),
+        CatalogType::Materialization => ("", "bindings", materialization_binding_key),
+        CatalogType::Collection => ("/derive", "transforms", transform_key),
+        CatalogType::Test => {
+            diff_locations("", &live, &local, &mut out);
+            return out;
+        }
+    };
+    let take = |model: &mut Value| match model
+        .pointer_mut(parent)
+        .and_then(Value::as_object_mut)
+        .and_then(|fields| fields.remove(property))
+    {
+        Some(Value::Array(items)) => keyed(items, key),
+        _ => BTreeMap::new(),
+    };
+    let (live_items, local_items) = (take(&mut live), take(&mut local));
+
+    diff_locations("", &live, &local, &mut out);
+
+    for eob in live_items
+        .into_iter()
+        .merge_join_by(local_items, |l, r| l.0.cmp(&r.0))
+    {
+        match eob {
+            EitherOrBoth::Left((key, before)) => out.push(location_change(
+                format!("{property}[{key}]"),
+                Change::Removed,
+                Some(before),
+                None,
+            )),
+            EitherOrBoth::Right((key, after)) => out.push(location_change(
+                format!("{property}[{key}]"),
+                Change::Added,
+                None,
+                Some(after),
+            )),
+            EitherOrBoth::Both((key, before), (_, after)) => {
+                diff_locations(&format!("{property}[{key}]"), &before, &after, &mut out)
+            }
+        }
+    }
+
+    out
+}
+
+fn capture_binding_key(binding: &Value) -> Option<&str> {
+    binding.get("target")?.as_str()
+}
+
+fn materialization_binding_key(binding: &Value) -> Option<&str> {
+    match binding.get("source")? {
+        Value::String(source) => Some(source),
+        source => source.get("name")?.as_str(),
+    }
+}
+
+fn transform_key(transform: &Value) -> Option<&str> {
+    transform.get("name")?.as_str()
+}
+
+// Key array items by their extracted key, falling back to their index.
+// Repeated keys are disambiguated by their occurrence.
+fn keyed(items: Vec<Value>, key: fn(&Value) -> Option<&str>) -> BTreeMap<String, Value> {
+    let mut out = BTreeMap::new();
+
+    for (index, item) in items.into_iter().enumerate() {
+        let mut k = key(&item)
+            .map(str::to_string)
+            .unwrap_or_else(|| index.to_string());
+
+        let base = k.clone();
+        let mut occurrence = 1;
+        while out.contains_key(&k) {
+            occurrence += 1;
+            k = format!("{base}#{occurrence}");
+        }
+        out.insert(k, item);
+    }
+    out
+}
+
+// Report each differing location of `before` and `after`.
+fn diff_locations(prefix: &str, before: &Value, after: &Value, out: &mut Vec<LocationChange>) {
+    for op in doc::patch::json_patch(before, after) {
+        let (path, change, value) = match op {
+            Operation::Add { path, value } => (path, Change::Added, Some(value)),
+            Operation::Remove { path } => (path, Change::Removed, None),
+            Operation::Replace { path, value } => (path, Change::Changed, Some(value)),
+            _ => unreachable!("json_patch produces only add, remove, and replace"),
+        };
+        out.push(location_change(
+            format!("{prefix}{path}"),
+            change,
+            path.query(before).cloned(),
+            value,
+        ));
+    }
+}
+
+// Build a LocationChange, masking its sops-encrypted values.
+// A sops encryption can't be compared with its plain-text value, nor with
+// another encryption of it, so a changed location which is encrypted in either
+// model is reported as changed without revealing either value.
+fn location_change(
+    location: String,
+    change: Change,
+    mut before: Option<Value>,
+    mut after: Option<Value>,
+) -> LocationChange {
+    let mut encrypted = false;
+    for value in before.iter_mut().chain(after.iter_mut()) {
+        encrypted |= mask_sops(value);
+    }
+    if encrypted && change == Change::Changed {
+        before = Some(Value::String(MASKED.to_string()));
+        after = Some(Value::String(MASKED.to_string()));
+    }
+
+    LocationChange {
+        location,
+        change,
+        before,
+        after,
+        encrypted,
+    }
+}
+
+const MASKED: &str = "<encrypted>";
+
+// Remove sops metadata, which changes with every encryption. Where sops used
+// an encrypted suffix, it's stripped from property names to match their
+// decrypted form. Encrypted values are left as-is, so that unchanged
+// encryptions compare as equal.
+fn strip_sops(value: &mut Value) {
+    match value {
+        Value::Array(items) => items.iter_mut().for_each(strip_sops),
+        Value::Object(fields) => {
+            let suffix = match fields.get("sops") {
+                Some(Value::Object(sops)) if sops.contains_key("mac") => Some(
+                    sops.get("encrypted_suffix")
+                        .and_then(Value::as_str)
+                        .map(str::to_string),
+                ),
+                _ => None,
+            };
+            if let Some(suffix) = suffix {
+                fields.remove("sops");
+
+                if let Some(suffix) = suffix {
+                    strip_encrypted_suffix(value, &suffix);
+                }
+                return strip_sops(value);
+            }
+            fields.values_mut().for_each(strip_sops);
+        }
+        _ => (),
+    }
+}
+
+fn strip_encrypted_suffix(value: &mut Value, suffix: &str) {
+    match value {
+        Value::Array(items) => items
+            .iter_mut()
+            .for_each(|v| strip_encrypted_suffix(v, suffix)),
+        Value::Object(fields) => {
+            *fields = std::mem::take(fields)
+                .into_iter()
+                .map(|(mut property, mut value)| {
+                    strip_encrypted_suffix(&mut value, suffix);
+                    if let Some(stripped) = property.strip_suffix(suffix) {
+                        property = stripped.to_string();
+                    }
+                    (property, value)
+                })
+                .collect();
+        }
+        _ => (),
+    }
+}
+
+// Mask sops-encrypted values, returning whether any were masked.
+fn mask_sops(value: &mut Value) -> bool {
+    match value {
+        Value::Array(items) => items.iter_mut().fold(false, |m, v| mask_sops(v) | m),
+        Value::Object(fields) => fields.values_mut().fold(false, |m, v| mask_sops(v) | m),
+        Value::String(s) if s.starts_with("ENC[") => {
+            *s = MASKED.to_string();
+            true
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_strip_sops() {
+        let mut value = json!({
+            "host": "example.com",
+            "password_sops": "ENC[AES256_GCM,data:abc,type:str]",
+            "nested": {"token_sops": "ENC[AES256_GCM,data:def,type:str]", "plain": 1},
+            "sops": {"mac": "ENC[AES256_GCM,data:mac,type:str]", "encrypted_suffix": "_sops"},
+        });
+        strip_sops(&mut value);
+
+        assert_eq!(
+            value,
+            json!({
+                "host": "example.com",
+                "password": "ENC[AES256_GCM,data:abc,type:str]",
+                "nested": {"token": "ENC[AES256_GCM,data:def,type:str]", "plain": 1},
+            })
+        );
+
+        // Objects without sops metadata are unchanged.
+        let mut value = json!({"password_sops": "hunter2", "sops": {"other": true}});
+        let expect = value.clone();
+        strip_sops(&mut value);
+        assert_eq!(value, expect);
+    }
+
+    #[test]
+    fn test_mask_sops() {
+        let mut value = json!({
+            "host": "example.com",
+            "password": "ENC[AES256_GCM,data:abc,type:str]",
+            "keys": ["ENC[AES256_GCM,data:def,type:str]", "plain"],
+        });
+        assert!(mask_sops(&mut value));
+        assert_eq!(
+            value,
+            json!({
+                "host": "example.com",
+                "password": MASKED,
+                "keys": [MASKED, "plain"],
+            })
+        );
+
+        let mut value = json!({"host": "example.com"});
+        assert!(!mask_sops(&mut value));
+    }
+
+    #[test]
+    fn test_binding_keys() {
+        assert_eq!(
+            capture_binding_key(&json!({"target": "acmeCo/foo"})),
+            Some("acmeCo/foo")
+        );
+        assert_eq!(
+            materialization_binding_key(&json!({"source": "acmeCo/foo"})),
+            Some("acmeCo/foo")
+        );
+        assert_eq!(
+            materialization_binding_key(&json!({"source": {"name": "acmeCo/bar"}})),
+            Some("acmeCo/bar")
+        );
+        assert_eq!(transform_key(&json!({"name": "fromFoo"})), Some("fromFoo"));
+        assert_eq!(capture_binding_key(&json!({"resource": {}})), None);
+
+        let items = vec![
+            json!({"target": "acmeCo/foo", "n": 1}),
+            json!({"resource": {}}),
+            json!({"target": "acmeCo/foo", "n": 2}),
+        ];
+        assert_eq!(
+            keyed(items, capture_binding_key)
+                .into_keys()
+                .collect::<Vec<_>>(),
+            vec!["1", "acmeCo/foo", "acmeCo/foo#2"],
+        );
+    }
+
+    #[test]
+    fn test_encrypted_changes() {
+        let live = json!({
+            "endpoint": {"connector": {"config": {
+                "host": "example.com",
+                "password_sops": "ENC[AES256_GCM,data:abc,type:str]",
+                "sops": {"mac": "ENC[AES256_GCM,data:mac,type:str]", "encrypted_suffix": "_sops"},
+            }}},
+        });
+
+        // An identical encryption is unchanged.
+        assert!(diff_model(CatalogType::Test, live.clone(), live.clone()).is_empty());
+
+        // A plain-text value is reported as changed, without revealing either value.
+        let local = json!({
+            "endpoint": {"connector": {"config": {
+                "host": "example.com",
+                "password": "hunter2",
+            }}},
+        });
+        let changes = diff_model(CatalogType::Test, live, local);
+
+        assert_eq!(
+            serde_json::to_value(&changes).unwrap(),
+            json!([{
+                "location": "/endpoint/connector/config/password",
+                "change": "changed",
+                "before": MASKED,
+                "after": MASKED,
+                "encrypted": true,
+            }])
+        );
+    }
+}
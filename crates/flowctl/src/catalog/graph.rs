@@ -0,0 +1,474 @@
+use crate::catalog::{fetch_live_specs, List, LiveSpecRow, NameSelector, SpecTypeSelector};
+use crate::CliContext;
+use anyhow::Context;
+use models::{AnySpec, ModelDef};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+#[derive(Debug, clap::Args)]
+pub struct Graph {
+    #[clap(flatten)]
+    pub name_selector: NameSelector,
+    /// Format of the rendered graph.
+    #[clap(long, value_enum, default_value_t = GraphFormat::Dot)]
+    pub format: GraphFormat,
+    /// Render only the part of the graph which is connected to this node,
+    /// in the direction given by --direction.
+    #[clap(long)]
+    pub from: Option<String>,
+    /// Direction of the traversal from the --from node.
+    #[clap(long, value_enum, default_value_t = Direction::Both, requires = "from")]
+    pub direction: Direction,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum GraphFormat {
+    /// Graphviz DOT.
+    Dot,
+    /// Mermaid flowchart.
+    Mermaid,
+    /// JSON object of nodes and edges.
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum Direction {
+    /// Nodes which the --from node depends upon.
+    Upstream,
+    /// Nodes which depend upon the --from node.
+    Downstream,
+    /// Both upstream and downstream nodes.
+    Both,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum NodeType {
+    Capture,
+    Collection,
+    Derivation,
+    Materialization,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum EdgeType {
+    // A capture binding writes into a collection.
+    Capture,
+    // A derivation transform reads from a collection.
+    Derive,
+    // A materialization binding reads from a collection.
+    Materialize,
+    // A materialization adds bindings for the collections of a capture.
+    SourceCapture,
+}
+
+#[derive(Debug, Serialize)]
+struct Node {
+    name: String,
+    // None if the node is referenced, but wasn't itself selected.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    node_type: Option<NodeType>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    disabled: bool,
+}
+
+#[derive(Debug, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+struct Edge {
+    from: String,
+    to: String,
+    #[serde(rename = "type")]
+    edge_type: EdgeType,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    disabled: bool,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct DependencyGraph {
+    nodes: Vec<Node>,
+    edges: Vec<Edge>,
+}
+
+pub async fn do_graph(ctx: &mut CliContext, args: &Graph) -> anyhow::Result<()> {
+    let list = List {
+        flows: false,
+        name_selector: args.name_selector.clone(),
+        type_selector: SpecTypeSelector {
+            tests: Some(false),
+            ..Default::default()
+        },
+    };
+    let live = fetch_live_specs::<LiveSpecRow>(
+        &ctx.client,
+        &list,
+        vec![
+            "id",
+            "catalog_name",
+            "spec_type",
+            "updated_at",
+            "last_pub_id",
+            "spec",
+        ],
+    )
+    .await
+    .context("fetching live specs")?;
+
+    let mut specs = Vec::with_capacity(live.len());
+    for row in live {
+        let Some(spec) = &row.spec else {
+            continue;
+        };
+        let spec = AnySpec::deserialize(row.spec_type, spec.get())
+            .with_context(|| format!("parsing the model of {}", row.catalog_name))?;
+        specs.push((row.catalog_name, spec));
+    }
+
+    let mut graph = build_graph(specs);
+
+    if let Some(from) = &args.from {
+        graph = graph.traverse(from, args.direction)?;
+    }
+
+    let rendered = match args.format {
+        GraphFormat::Dot => graph.to_dot(),
+        GraphFormat::Mermaid => graph.to_mermaid(),
+        GraphFormat::Json => serde_json::to_string_pretty(&graph)?,
+    };
+    println!("{rendered}");
+    Ok(())
+}
+
+// Build the graph of producer-to-collection-to-consumer edges of the specs,
+// including edges of disabled bindings.
+fn build_graph(specs: Vec<(String, AnySpec)>) -> DependencyGraph {
+    let mut nodes: BTreeMap<String, Node> = BTreeMap::new();
+    let mut edges: BTreeSet<Edge> = BTreeSet::new();
+
+    let mut add_edge = |from: &str, to: &str, edge_type, disabled| {
+        edges.insert(Edge {
+            from: from.to_string(),
+            to: to.to_string(),
+            edge_type,
+            disabled,
+        });
+    };
+
+    for (name, spec) in &specs {
+        let node_type = match spec {
+            AnySpec::Capture(capture) => {
+                for binding in &capture.bindings {
+                    add_edge(name, &binding.target, EdgeType::Capture, binding.disable);
+                }
+                NodeType::Capture
+            }
+            AnySpec::Collection(collection) => match &collection.derive {
+                Some(derive) => {
+                    for transform in &derive.transforms {
+                        let source = transform.source.collection();
+                        add_edge(source, name, EdgeType::Derive, transform.disable);
+                    }
+                    NodeType::Derivation
+                }
+                None => NodeType::Collection,
+            },
+            AnySpec::Materialization(materialization) => {
+                for binding in &materialization.bindings {
+                    let source = binding.source.collection();
+                    add_edge(source, name, EdgeType::Materialize, binding.disable);
+                }
+                if let Some(capture) = materialization.materialization_source_capture_name() {
+                    add_edge(capture, name, EdgeType::SourceCapture, false);
+                }
+                NodeType::Materialization
+            }
+            AnySpec::Test(_) => continue,
+        };
+
+        nodes.insert(
+            name.clone(),
+            Node {
+                name: name.clone(),
+                node_type: Some(node_type),
+                disabled: !spec.is_enabled(),
+            },
+        );
+    }
+
+    // Add nodes which are referenced by edges but weren't selected.
+    for edge in &edges {
+        for name in [&edge.from, &edge.to] {
+            nodes.entry(name.clone()).or_insert_with(|| Node {
+                name: name.clone(),
+                node_type: None,
+                disabled: false,
+            });
+        }
+    }
+
+    DependencyGraph {
+        nodes: nodes.into_values().collect(),
+        edges: edges.into_iter().collect(),
+    }
+}
+
+impl DependencyGraph {
+    // Retain only nodes and edges which are reachable from `from` in the given direction.
+    fn traverse(self, from: &str, direction: Direction) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            self.nodes.iter().any(|node| node.name == from),
+            "{from} is not a node of the selected graph"
+        );
+
+        let mut reached: BTreeSet<&str> = BTreeSet::new();
+        let mut retained: BTreeSet<usize> = BTreeSet::new();
+
+        if matches!(direction, Direction::Upstream | Direction::Both) {
+            self.walk(from, false, &mut reached, &mut retained);
+        }
+        if matches!(direction, Direction::Downstream | Direction::Both) {
+            self.walk(from, true, &mut reached, &mut retained);
+        }
+        let reached: BTreeSet<String> = reached.into_iter().map(str::to_string).collect();
+
+        let Self { nodes, edges } = self;
+
+        Ok(Self {
+            nodes: nodes
+                .into_iter()
+                .filter(|node| reached.contains(&node.name))
+                .collect(),
+            edges: edges
+                .into_iter()
+                .enumerate()
+                .filter(|(index, _edge)| retained.contains(index))
+                .map(|(_index, edge)| edge)
+                .collect(),
+        })
+    }
+
+    // Walk edges from `from`, following them forward (downstream) or in reverse (upstream).
+    fn walk<'s>(
+        &'s self,
+        from: &'s str,
+        downstream: bool,
+        reached: &mut BTreeSet<&'s str>,
+        retained: &mut BTreeSet<usize>,
+    ) {
+        let mut queue = VecDeque::from([from]);
+        let mut visited = BTreeSet::from([from]);
+        reached.insert(from);
+
+        while let Some(name) = queue.pop_front() {
+            for (index, edge) in self.edges.iter().enumerate() {
+                let (near, far) = if downstream {
+                    (&edge.from, &edge.to)
+                } else {
+                    (&edge.to, &edge.from)
+                };
+                if near != name {
+                    continue;
+                }
+                retained.insert(index);
+                reached.insert(far);
+
+                if visited.insert(far) {
+                    queue.push_back(far);
+                }
+            }
+        }
+    }
+
+    fn to_dot(&self) -> String {
+        let mut out = String::from("digraph catalog {\n    rankdir=LR;\n");
+
+        for node in &self.nodes {
+            let shape = match node.node_type {
+                Some(NodeType::Capture) => "cds",
+                Some(NodeType::Collection) => "box",
+                Some(NodeType::Derivation) => "box3d",
+                Some(NodeType::Materialization) => "cylinder",
+                None => "box",
+            };
+            let mut attrs = vec![format!("shape={shape}")];
+            if node.node_type.is_none() {
+                attrs.push("color=gray".to_string());
+            }
+            if node.disabled {
+                attrs.push("style=dashed".to_string());
+                attrs.push(format!("label={:?}", format!("{} (disabled)", node.name)));
+            }
+            out.push_str(&format!("    {:?} [{}];\n", node.name, attrs.join(", ")));
+        }
+        for edge in &self.edges {
+            let mut attrs = Vec::new();
+            if edge.edge_type == EdgeType::SourceCapture {
+                attrs.push("label=\"sourceCapture\"".to_string());
+                attrs.push("style=dotted".to_string());
+            } else if edge.disabled {
+                attrs.push("label=\"disabled\"".to_string());
+                attrs.push("style=dashed".to_string());
+            }
+            out.push_str(&format!("    {:?} -> {:?}", edge.from, edge.to));
+            if !attrs.is_empty() {
+                out.push_str(&format!(" [{}]", attrs.join(", ")));
+            }
+            out.push_str(";\n");
+        }
+        out.push('}');
+        out
+    }
+
+    fn to_mermaid(&self) -> String {
+        // Mermaid node IDs are restricted, so nodes are identified by index and labeled by name.
+        let ids: BTreeMap<&str, String> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| (node.name.as_str(), format!("n{index}")))
+            .collect();
+
+        let mut out = String::from("flowchart LR\n");
+
+        for node in &self.nodes {
+            let mut label = node.name.replace('"', "#quot;");
+            if node.disabled {
+                label.push_str(" (disabled)");
+            }
+            let id = &ids[node.name.as_str()];
+            let shape = match node.node_type {
+                Some(NodeType::Capture) => format!("{id}>\"{label}\"]"),
+                Some(NodeType::Materialization) => format!("{id}[(\"{label}\")]"),
+                Some(NodeType::Derivation) => format!("{id}[[\"{label}\"]]"),
+                Some(NodeType::Collection) | None => format!("{id}[\"{label}\"]"),
+            };
+            out.push_str(&format!("    {shape}\n"));
+        }
+        for edge in &self.edges {
+            let (from, to) = (&ids[edge.from.as_str()], &ids[edge.to.as_str()]);
+
+            let arrow = if edge.edge_type == EdgeType::SourceCapture {
+                "-. sourceCapture .->".to_string()
+            } else if edge.disabled {
+                "-. disabled .->".to_string()
+            } else {
+                "-->".to_string()
+            };
+            out.push_str(&format!("    {from} {arrow} {to}\n"));
+        }
+        out.pop(); // Trailing newline.
+        out
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use models::CatalogType;
+
+    // Build the graph of a fixture catalog, which has each type of node and edge.
+    // acmeCo/archive is referenced but not selected, and acmeCo/unrelated
+    // is not connected to any other node.
+    fn fixture_graph() -> DependencyGraph {
+        let fixture: Vec<(String, BTreeMap<String, serde_json::Value>)> = serde_yaml::from_str(
+            r#"
+- - acmeCo/hello
+  - capture:
+      endpoint: { connector: { image: source/hello, config: {} } }
+      bindings:
+        - { resource: {}, target: acmeCo/greetings }
+        - { resource: {}, target: acmeCo/ignored, disable: true }
+- - acmeCo/greetings
+  - collection: { schema: { type: object }, key: [/id] }
+- - acmeCo/reversed
+  - collection:
+      schema: { type: object }
+      key: [/id]
+      derive:
+        using: { sqlite: {} }
+        transforms:
+          - { name: fromGreetings, source: acmeCo/greetings, lambda: select 1;, shuffle: any }
+          - { name: fromArchive, source: acmeCo/archive, lambda: select 1;, shuffle: any, disable: true }
+- - acmeCo/sink
+  - materialization:
+      sourceCapture: acmeCo/hello
+      endpoint: { connector: { image: materialize/sink, config: {} } }
+      bindings:
+        - { resource: {}, source: acmeCo/reversed }
+      shards: { disable: true }
+- - acmeCo/unrelated
+  - collection: { schema: { type: object }, key: [/id] }
+"#,
+        )
+        .unwrap();
+
+        let specs = fixture
+            .into_iter()
+            .flat_map(|(name, specs)| {
+                specs.into_iter().map(move |(catalog_type, spec)| {
+                    let catalog_type = match catalog_type.as_str() {
+                        "capture" => CatalogType::Capture,
+                        "collection" => CatalogType::Collection,
+                        "materialization" => CatalogType::Materialization,
+                        other => panic!("unexpected catalog type {other}"),
+                    };
+                    let spec = AnySpec::deserialize(catalog_type, &spec.to_string()).unwrap();
+                    (name.clone(), spec)
+                })
+            })
+            .collect();
+
+        build_graph(specs)
+    }
+
+    #[test]
+    fn test_build_graph() {
+        insta::assert_json_snapshot!(fixture_graph());
+    }
+
+    #[test]
+    fn test_to_dot() {
+        insta::assert_snapshot!(fixture_graph().to_dot());
+    }
+
+    #[test]
+    fn test_to_mermaid() {
+        insta::assert_snapshot!(fixture_graph().to_mermaid());
+    }
+
+    #[test]
+    fn test_traverse() {
+        let downstream = fixture_graph()
+            .traverse("acmeCo/reversed", Direction::Downstream)
+            .unwrap();
+        insta::assert_snapshot!("traverse_downstream_dot", downstream.to_dot());
+        insta::assert_snapshot!("traverse_downstream_mermaid", downstream.to_mermaid());
+
+        let upstream = fixture_graph()
+            .traverse("acmeCo/reversed", Direction::Upstream)
+            .unwrap();
+        insta::assert_json_snapshot!("traverse_upstream", upstream);
+
+        // Traversal in both directions doesn't reach unconnected nodes.
+        let both = fixture_graph()
+            .traverse("acmeCo/greetings", Direction::Both)
+            .unwrap();
+        let names: Vec<&str> = both.nodes.iter().map(|node| node.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "acmeCo/greetings",
+                "acmeCo/hello",
+                "acmeCo/reversed",
+                "acmeCo/sink"
+            ]
+        );
+
+        let err = fixture_graph()
+            .traverse("acmeCo/missing", Direction::Both)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "acmeCo/missing is not a node of the selected graph"
+        );
+    }
+}
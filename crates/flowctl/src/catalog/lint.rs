@@ -0,0 +1,596 @@
+use crate::local_specs;
+use crate::output::{to_table_row, CliOutput, JsonCell};
+use anyhow::Context;
+use doc::shape::{limits, location::Exists, Reduction};
+use json::schema::{formats::Format, types};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, clap::Args)]
+pub struct Lint {
+    /// Path or URL to a Flow specification file to lint.
+    #[clap(long)]
+    source: String,
+    /// Path to a YAML or JSON file which enables or disables lint rules.
+    ///
+    /// For example, `rules: {missing-description: false}` disables the
+    /// missing-description rule. Rules are enabled by default.
+    #[clap(long)]
+    config: Option<std::path::PathBuf>,
+    /// Fail if any lint warnings are reported.
+    #[clap(long)]
+    deny_warnings: bool,
+}
+
+/// A best-practice rule which is checked by `flowctl catalog lint`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Rule {
+    /// Collection key locations should be required to exist.
+    KeyNotRequired,
+    /// Numeric locations of collections having reductions should be reduced.
+    NumericWithoutReduce,
+    /// Stateful SQLite derivations shouldn't use `shuffle: any`.
+    ShuffleAnyStateful,
+    /// Collection schemas and tests should have descriptions.
+    MissingDescription,
+    /// Collection schemas shouldn't exceed the complexity limits of schema inference.
+    WideSchema,
+    /// Logical partitions shouldn't be of high-cardinality fields.
+    HighCardinalityPartition,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LintConfig {
+    /// Rules which are enabled (true) or disabled (false).
+    #[serde(default)]
+    rules: BTreeMap<Rule, bool>,
+}
+
+impl LintConfig {
+    fn enabled(&self, rule: Rule) -> bool {
+        self.rules.get(&rule).copied().unwrap_or(true)
+    }
+}
+
+/// A lint warning of a catalog specification.
+#[derive(Debug, Serialize)]
+pub struct Warning {
+    pub rule: Rule,
+    pub catalog_name: String,
+    pub message: String,
+    pub scope: url::Url,
+}
+
+impl CliOutput for Warning {
+    type TableAlt = ();
+    type CellValue = JsonCell;
+
+    fn table_headers(_alt: Self::TableAlt) -> Vec<&'static str> {
+        vec!["Rule", "Name", "Message", "Scope"]
+    }
+
+    fn into_table_row(self, _alt: Self::TableAlt) -> Vec<Self::CellValue> {
+        to_table_row(self, &["/rule", "/catalog_name", "/message", "/scope"])
+    }
+}
+
+pub async fn do_lint(ctx: &mut crate::CliContext, args: &Lint) -> anyhow::Result<()> {
+    let config = match &args.config {
+        Some(path) => {
+            let content = std::fs::read(path)
+                .with_context(|| format!("reading lint config {}", path.display()))?;
+            serde_yaml::from_slice(&content)
+                .with_context(|| format!("parsing lint config {}", path.display()))?
+        }
+        None => LintConfig::default(),
+    };
+
+    let source = build::arg_source_to_url(&args.source, false)?;
+    let mut draft = local_specs::surface_errors(local_specs::load(&source).await.into_result())?;
+    ::sources::inline_draft_catalog(&mut draft);
+
+    let warnings = lint_catalog(&draft, &config);
+
+    let count = warnings.len();
+    if count == 0 {
+        println!("No lint warnings.");
+        return Ok(());
+    }
+    ctx.write_all(warnings, ())?;
+
+    if args.deny_warnings {
+        anyhow::bail!("{count} lint warning(s)");
+    }
+    Ok(())
+}
+
+/// Run all enabled lint rules over the draft catalog.
+fn lint_catalog(draft: &tables::DraftCatalog, config: &LintConfig) -> Vec<Warning> {
+    let mut out = Vec::new();
+
+    for row in draft.collections.iter() {
+        let Some(model) = &row.model else {
+            continue; // Deletion.
+        };
+        lint_collection(&row.collection, &row.scope, model, &mut out);
+    }
+
+    for row in draft.tests.iter() {
+        let Some(model) = &row.model else {
+            continue;
+        };
+        if model.description.is_empty() {
+            out.push(Warning {
+                rule: Rule::MissingDescription,
+                catalog_name: row.test.to_string(),
+                message: "test has no description".to_string(),
+                scope: row.scope.clone(),
+            });
+        }
+    }
+
+    out.retain(|warning| config.enabled(warning.rule));
+    out
+}
+
+fn lint_collection(
+    name: &models::Collection,
+    scope: &url::Url,
+    model: &models::CollectionDef,
+    out: &mut Vec<Warning>,
+) {
+    let mut warn = |rule, message: String| {
+        out.push(Warning {
+            rule,
+            catalog_name: name.to_string(),
+            message,
+            scope: scope.clone(),
+        })
+    };
+
+    if let Some(derive) = &model.derive {
+        let stateful = matches!(&derive.using, models::DeriveUsing::Sqlite(sqlite) if !sqlite.migrations.is_empty());
+
+        for transform in derive.transforms.iter().filter(|t| !t.disable) {
+            if stateful && matches!(transform.shuffle, models::Shuffle::Any) {
+                warn(
+                    Rule::ShuffleAnyStateful,
+                    format!(
+                        "transform {} uses `shuffle: any`, but the SQLite derivation has stateful migrations; documents of a key may be processed by different shards, each having its own state",
+                        transform.name
+                    ),
+                );
+            }
+        }
+    }
+
+    // The write schema is linted, as read schemas commonly reference an inferred schema.
+    let Some(schema) = model.schema.as_ref().or(model.write_schema.as_ref()) else {
+        return;
+    };
+    let Ok(shape) = build_shape(schema) else {
+        return; // Invalid schemas are reported by validation.
+    };
+    let locations = shape.locations();
+
+    if shape.description.is_none() {
+        warn(
+            Rule::MissingDescription,
+            "collection schema has no description".to_string(),
+        );
+    }
+
+    for ptr in model.key.iter() {
+        let (_shape, exists) = shape.locate(&doc::Pointer::from_str(ptr));
+        if exists != Exists::Must {
+            warn(
+                Rule::KeyNotRequired,
+                format!("key {ptr} is not required to exist, and documents without it are keyed on its default"),
+            );
+        }
+    }
+
+    // Only collections which use reductions are checked for unreduced numeric locations.
+    let reduces = locations
+        .iter()
+        .any(|(_ptr, _pattern, shape, _exists)| !matches!(shape.reduction, Reduction::Unset));
+
+    for (ptr, pattern, loc_shape, _exists) in &locations {
+        let ptr_str = ptr.to_string();
+
+        if reduces
+            && !pattern
+            && loc_shape.type_.overlaps(types::INT_OR_FRAC)
+            && matches!(loc_shape.reduction, Reduction::Unset)
+            && !model.key.iter().any(|key| key.as_str() == ptr_str)
+        {
+            warn(
+                Rule::NumericWithoutReduce,
+                format!("numeric location {ptr_str} has no `reduce` annotation, and keeps only its last-written value"),
+            );
+        }
+    }
+
+    // Count locations and nesting depth as is done by schema inference limits.
+    let (mut count, mut depth) = (0, 0);
+    for (ptr, _pattern, _shape, _exists) in &locations {
+        if matches!(
+            ptr.0.last(),
+            None | Some(doc::ptr::Token::NextIndex) | Some(doc::ptr::Token::NextProperty)
+        ) {
+            continue;
+        }
+        count += 1;
+        depth = depth.max(ptr.0.len());
+    }
+    if count >= limits::DEFAULT_SCHEMA_COMPLEXITY_LIMIT
+        || depth > limits::DEFAULT_SCHEMA_DEPTH_LIMIT
+    {
+        warn(
+            Rule::WideSchema,
+            format!(
+                "collection schema has {count} locations with a maximum depth of {depth}, exceeding the limits of {} locations and a depth of {}",
+                limits::DEFAULT_SCHEMA_COMPLEXITY_LIMIT,
+                limits::DEFAULT_SCHEMA_DEPTH_LIMIT,
+            ),
+        );
+    }
+
+    // Reason why values of a location are likely to have high cardinality.
+    let location_reason = |location: &models::JsonPointer| {
+        let (loc_shape, _exists) = shape.locate(&doc::Pointer::from_str(location));
+
+        if model.key.iter().any(|key| key == location) {
+            Some("is a component of the collection key".to_string())
+        } else if loc_shape.type_.overlaps(types::FRACTIONAL) {
+            Some("is a fractional number".to_string())
+        } else {
+            match &loc_shape.string.format {
+                Some(
+                    format @ (Format::DateTime
+                    | Format::Time
+                    | Format::Uuid
+                    | Format::Ulid
+                    | Format::Email
+                    | Format::Uri
+                    | Format::Iri),
+                ) => Some(format!("has string format {}", serde_json::json!(format))),
+                _ => None,
+            }
+        }
+    };
+
+    // Reason of the first high-cardinality location a computed projection reads.
+    let computed_reason = |locations: &[models::JsonPointer]| {
+        locations.iter().find_map(|location| {
+            location_reason(location)
+                .map(|reason| format!("is computed from {location}, which {reason}"))
+        })
+    };
+
+    for (field, projection) in &model.projections {
+        use models::ProjectionExpression as Expr;
+
+        let reason = match projection {
+            models::Projection::Extended {
+                location,
+                partition: true,
+            } => location_reason(location),
+            models::Projection::Computed {
+                expression,
+                partition: true,
+            } => match expression {
+                Expr::Hash(_) => Some("is a hash".to_string()),
+                Expr::DateTrunc {
+                    unit: unit @ (models::DateTruncUnit::Second | models::DateTruncUnit::Minute),
+                    ..
+                } => Some(format!(
+                    "truncates a date-time to the {}",
+                    serde_json::json!(unit)
+                )),
+                Expr::DateTrunc { .. } => None,
+                Expr::Lower(location) | Expr::Upper(location) => {
+                    computed_reason(std::slice::from_ref(location))
+                }
+                Expr::Concat { of, .. } | Expr::Coalesce(of) => computed_reason(of),
+            },
+            _ => continue,
+        };
+
+        if let Some(reason) = reason {
+            warn(
+                Rule::HighCardinalityPartition,
+                format!(
+                    "partition field {field} {reason}, and is likely to have many distinct values"
+                ),
+            );
+        }
+    }
+}
+
+fn build_shape(schema: &models::Schema) -> anyhow::Result<doc::Shape> {
+    let schema = doc::validation::build_bundle(schema.get())?;
+
+    let mut index = doc::SchemaIndexBuilder::new();
+    index.add(&schema)?;
+    let index = index.into_index();
+
+    Ok(doc::Shape::infer(&schema, &index))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Lint a fixture catalog having the given YAML `collections` and `tests`,
+    // returning the rule, catalog name, and message of each warning.
+    fn lint(fixture: &str, config: &LintConfig) -> Vec<(Rule, String, String)> {
+        let fixture: serde_json::Value = serde_yaml::from_str(fixture).unwrap();
+        let fixture = serde_json::json!({"test://example/catalog.yaml": fixture});
+
+        let mut draft = sources::scenarios::evaluate_fixtures(Default::default(), &fixture);
+        assert!(draft.errors.is_empty(), "{:?}", draft.errors);
+        sources::inline_draft_catalog(&mut draft);
+
+        lint_catalog(&draft, config)
+            .into_iter()
+            .map(|w| (w.rule, w.catalog_name, w.message))
+            .collect()
+    }
+
+    // Lint a single acmeCo/thing collection having the given YAML definition.
+    fn lint_collection_fixture(collection: &str) -> Vec<(Rule, String, String)> {
+        let collection: serde_json::Value = serde_yaml::from_str(collection).unwrap();
+        let fixture = serde_json::json!({"collections": {"acmeCo/thing": collection}});
+        lint(&fixture.to_string(), &LintConfig::default())
+    }
+
+    fn rules(warnings: &[(Rule, String, String)]) -> Vec<Rule> {
+        warnings.iter().map(|(rule, _, _)| *rule).collect()
+    }
+
+    #[test]
+    fn test_key_not_required() {
+        let pass = lint_collection_fixture(
+            r#"
+schema:
+  description: A thing.
+  type: object
+  properties: { id: { type: string } }
+  required: [id]
+key: [/id]
+"#,
+        );
+        assert_eq!(pass, Vec::new());
+
+        let fail = lint_collection_fixture(
+            r#"
+schema:
+  description: A thing.
+  type: object
+  properties: { id: { type: string } }
+key: [/id]
+"#,
+        );
+        assert_eq!(rules(&fail), vec![Rule::KeyNotRequired]);
+        assert_eq!(fail[0].1, "acmeCo/thing");
+    }
+
+    #[test]
+    fn test_numeric_without_reduce() {
+        let pass = lint_collection_fixture(
+            r#"
+schema:
+  description: A thing.
+  type: object
+  reduce: { strategy: merge }
+  properties:
+    id: { type: string }
+    n: { type: integer, reduce: { strategy: sum } }
+  required: [id]
+key: [/id]
+"#,
+        );
+        assert_eq!(pass, Vec::new());
+
+        let fail = lint_collection_fixture(
+            r#"
+schema:
+  description: A thing.
+  type: object
+  reduce: { strategy: merge }
+  properties:
+    id: { type: string }
+    n: { type: integer }
+  required: [id]
+key: [/id]
+"#,
+        );
+        assert_eq!(rules(&fail), vec![Rule::NumericWithoutReduce]);
+        assert!(fail[0].2.contains("/n"), "{}", fail[0].2);
+    }
+
+    #[test]
+    fn test_shuffle_any_stateful() {
+        let fixture = |shuffle: &str| {
+            format!(
+                r#"
+schema:
+  description: A thing.
+  type: object
+  properties: {{ id: {{ type: string }} }}
+  required: [id]
+key: [/id]
+derive:
+  using:
+    sqlite:
+      migrations:
+        - create table seen (id text primary key);
+  transforms:
+    - name: fromThing
+      source: acmeCo/thing
+      lambda: select 1;
+      shuffle: {shuffle}
+"#
+            )
+        };
+
+        let pass = lint_collection_fixture(&fixture("{ key: [/id] }"));
+        assert_eq!(pass, Vec::new());
+
+        let fail = lint_collection_fixture(&fixture("any"));
+        assert_eq!(rules(&fail), vec![Rule::ShuffleAnyStateful]);
+        assert!(fail[0].2.contains("fromThing"), "{}", fail[0].2);
+    }
+
+    #[test]
+    fn test_missing_description() {
+        let fixture = |description: &str| {
+            format!(
+                r#"
+collections:
+  acmeCo/thing:
+    schema:
+      {description}
+      type: object
+      properties: {{ id: {{ type: string }} }}
+      required: [id]
+    key: [/id]
+tests:
+  acmeCo/test:
+    {description}
+    steps:
+      - ingest:
+          collection: acmeCo/thing
+          documents: [{{ id: one }}]
+"#
+            )
+        };
+
+        let pass = lint(&fixture("description: Described."), &LintConfig::default());
+        assert_eq!(pass, Vec::new());
+
+        let fail = lint(&fixture(""), &LintConfig::default());
+        assert_eq!(
+            fail,
+            vec![
+                (
+                    Rule::MissingDescription,
+                    "acmeCo/thing".to_string(),
+                    "collection schema has no description".to_string()
+                ),
+                (
+                    Rule::MissingDescription,
+                    "acmeCo/test".to_string(),
+                    "test has no description".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_wide_schema() {
+        // Build a schema nesting property `a` to the given depth.
+        let fixture = |depth: usize| {
+            let mut schema = serde_json::json!({"type": "string"});
+            for _ in 0..depth {
+                schema = serde_json::json!({
+                    "type": "object",
+                    "properties": {"a": schema},
+                });
+            }
+            schema["description"] = "A thing.".into();
+            schema["properties"]["id"] = serde_json::json!({"type": "string"});
+            schema["required"] = serde_json::json!(["id"]);
+
+            serde_json::json!({"schema": schema, "key": ["/id"]}).to_string()
+        };
+
+        let pass = lint_collection_fixture(&fixture(limits::DEFAULT_SCHEMA_DEPTH_LIMIT));
+        assert_eq!(pass, Vec::new());
+
+        let fail = lint_collection_fixture(&fixture(limits::DEFAULT_SCHEMA_DEPTH_LIMIT + 1));
+        assert_eq!(rules(&fail), vec![Rule::WideSchema]);
+    }
+
+    #[test]
+    fn test_high_cardinality_partition() {
+        let fixture = |projections: &str| {
+            format!(
+                r#"
+schema:
+  description: A thing.
+  type: object
+  properties:
+    id: {{ type: string }}
+    region: {{ type: string }}
+    ts: {{ type: string, format: date-time }}
+  required: [id, region, ts]
+key: [/id]
+projections: {projections}
+"#
+            )
+        };
+
+        let pass = lint_collection_fixture(&fixture(
+            r#"{
+  region: { location: /region, partition: true },
+  day: { expression: { dateTrunc: { location: /ts, unit: day } }, partition: true },
+  regionLower: { expression: { lower: /region }, partition: true },
+  ts: /ts,
+  idHash: { expression: { hash: [/id] } },
+}"#,
+        ));
+        assert_eq!(pass, Vec::new());
+
+        let fail = lint_collection_fixture(&fixture(
+            r#"{
+  ts: { location: /ts, partition: true },
+  idHash: { expression: { hash: [/region] }, partition: true },
+  minute: { expression: { dateTrunc: { location: /ts, unit: minute } }, partition: true },
+  idLower: { expression: { lower: /id }, partition: true },
+  both: { expression: { concat: { of: [/region, /ts] } }, partition: true },
+}"#,
+        ));
+        let messages: Vec<_> = fail
+            .iter()
+            .map(|(_, _, message)| message.as_str())
+            .collect();
+
+        assert_eq!(rules(&fail), vec![Rule::HighCardinalityPartition; 5]);
+        assert_eq!(
+            messages,
+            vec![
+                "partition field both is computed from /ts, which has string format \"date-time\", and is likely to have many distinct values",
+                "partition field idHash is a hash, and is likely to have many distinct values",
+                "partition field idLower is computed from /id, which is a component of the collection key, and is likely to have many distinct values",
+                "partition field minute truncates a date-time to the \"minute\", and is likely to have many distinct values",
+                "partition field ts has string format \"date-time\", and is likely to have many distinct values",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_disabled_rule() {
+        let fixture = r#"
+collections:
+  acmeCo/thing:
+    schema:
+      type: object
+      properties: { id: { type: string } }
+    key: [/id]
+"#;
+        let all = lint(fixture, &LintConfig::default());
+        assert_eq!(
+            rules(&all),
+            vec![Rule::MissingDescription, Rule::KeyNotRequired]
+        );
+
+        let config: LintConfig =
+            serde_yaml::from_str("rules: {missing-description: false}").unwrap();
+        let filtered = lint(fixture, &config);
+        assert_eq!(rules(&filtered), vec![Rule::KeyNotRequired]);
+    }
+}
@@ -0,0 +1,498 @@
+use anyhow::Context;
+use futures::TryStreamExt;
+use proto_flow::{derive, flow};
+use proto_gazette::broker;
+use std::collections::BTreeMap;
+
+// Maximum number of rounds of derivation processing which may follow
+// an ingestion, before we conclude the derivations will never settle.
+const MAX_DERIVATION_ROUNDS: usize = 100;
+
+/// Run catalog tests of the `source` locally, without use of the control plane.
+/// Derivations are run through the runtime harness and documents are
+/// held in memory. Returns an error if any test fails.
+pub async fn run_tests(source: &str) -> anyhow::Result<()> {
+    let validations = crate::local_specs::load_and_validate_local(source).await?;
+
+    let collections: BTreeMap<&str, &flow::CollectionSpec> = validations
+        .built_collections
+        .iter()
+        .filter_map(|row| row.spec.as_ref())
+        .map(|spec| (spec.name.as_str(), spec))
+        .collect();
+
+    let tests: Vec<&flow::TestSpec> = validations
+        .built_tests
+        .iter()
+        .filter_map(|row| row.spec.as_ref())
+        .collect();
+
+    if tests.is_empty() {
+        println!("No tests to run.");
+        return Ok(());
+    }
+
+    let runtime = runtime::Runtime::new(
+        true,          // Allow local.
+        String::new(), // Default network.
+        ops::tracing_log_handler,
+        None,
+        "test".to_string(),
+    );
+
+    let (mut passed, mut failed) = (0, 0);
+
+    for test in tests {
+        match run_test(&runtime, &collections, test).await? {
+            None => {
+                println!("PASS {}", test.name);
+                passed += 1;
+            }
+            Some(failure) => {
+                println!("FAIL {}\n{failure}", test.name);
+                failed += 1;
+            }
+        }
+    }
+    println!("\n{passed} passed, {failed} failed");
+
+    if failed != 0 {
+        anyhow::bail!("{failed} test(s) failed");
+    }
+    Ok(())
+}
+
+// Run a single test, returning a description of its first failed step,
+// or None if all steps passed.
+async fn run_test<L: runtime::LogHandler>(
+    runtime: &runtime::Runtime<L>,
+    collections: &BTreeMap<&str, &flow::CollectionSpec>,
+    test: &flow::TestSpec,
+) -> anyhow::Result<Option<String>> {
+    // Each test begins with empty collections and fresh derivation states.
+    let mut derivations = collections
+        .values()
+        .filter(|spec| spec.derivation.is_some())
+        .map(|spec| Derivation::new(spec))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    // Ordered log of all documents ingested or derived by the test.
+    let mut log: Vec<(models::Collection, serde_json::Value)> = Vec::new();
+
+    for step in &test.steps {
+        let flow::test_spec::Step {
+            step_type,
+            step_index,
+            description,
+            step_scope,
+            collection,
+            docs_json_vec,
+            partitions,
+        } = step;
+
+        let docs = docs_json_vec
+            .iter()
+            .map(|doc_json| serde_json::from_str(doc_json))
+            .collect::<Result<Vec<serde_json::Value>, _>>()
+            .context("failed to parse test step documents")?;
+
+        let spec = collections
+            .get(collection.as_str())
+            .with_context(|| format!("test step collection {collection} was not built"))?;
+
+        // Derivations are run to completion prior to a verification,
+        // as well as after an ingestion so that failures are surfaced early.
+        if *step_type == flow::test_spec::step::Type::Ingest as i32 {
+            // Ingested documents must be valid against the collection's write schema.
+            let mut validator =
+                doc::Validator::new(doc::validation::build_bundle(&spec.write_schema_json)?)?;
+
+            for (index, doc) in docs.iter().enumerate() {
+                if let Err(err) = validator.validate(None, doc)?.ok() {
+                    return Ok(Some(format!(
+                        "  step {step_index} ({description}) at {step_scope}\n  ingest of collection {collection} document {index} failed validation:\n{err}",
+                    )));
+                }
+            }
+
+            log.extend(
+                docs.into_iter()
+                    .map(|doc| (models::Collection::new(collection), doc)),
+            );
+            run_derivations(runtime, &mut derivations, &mut log).await?;
+            continue;
+        }
+        run_derivations(runtime, &mut derivations, &mut log).await?;
+
+        let actual = combine_collection(spec, partitions.as_ref(), &log)?;
+        let expect = serde_json::Value::Array(docs);
+        let actual = serde_json::Value::Array(actual);
+
+        let diffs = doc::diff(Some(&actual), Some(&expect));
+        if !diffs.is_empty() {
+            return Ok(Some(format!(
+                "  step {step_index} ({description}) at {step_scope}\n  verify of collection {collection} failed with differences:\n{}",
+                serde_json::to_string_pretty(&diffs).unwrap(),
+            )));
+        }
+    }
+
+    Ok(None)
+}
+
+// Derivation is a derived collection under test.
+struct Derivation<'s> {
+    spec: &'s flow::CollectionSpec,
+    // Collections which are read by the derivation's transforms.
+    sources: Vec<&'s str>,
+    // Offset of the next document of the test log which the derivation has not read.
+    offset: usize,
+    // Transactions of source documents read by the derivation, as a harness fixture.
+    // The harness resumes from the checkpoint of `state_dir`,
+    // so that each run processes only its newest transaction.
+    fixture: runtime::harness::fixture::Fixture,
+    // Directory of the derivation's RocksDB state, which persists across runs.
+    state_dir: tempfile::TempDir,
+}
+
+impl<'s> Derivation<'s> {
+    fn new(spec: &'s flow::CollectionSpec) -> anyhow::Result<Self> {
+        let sources = spec
+            .derivation
+            .iter()
+            .flat_map(|derivation| derivation.transforms.iter())
+            .filter_map(|transform| transform.collection.as_ref())
+            .map(|collection| collection.name.as_str())
+            .collect();
+
+        Ok(Self {
+            spec,
+            sources,
+            offset: 0,
+            fixture: Vec::new(),
+            state_dir: tempfile::tempdir().context("creating derivation state directory")?,
+        })
+    }
+
+    // Run the derivation over its fixture, returning its published documents.
+    async fn run<L: runtime::LogHandler>(
+        &self,
+        runtime: &runtime::Runtime<L>,
+    ) -> anyhow::Result<Vec<serde_json::Value>> {
+        let responses_rx = runtime::harness::run_derive(
+            runtime::harness::fixture::Reader(self.fixture.clone()),
+            runtime.clone(),
+            vec![usize::MAX], // A single session having unlimited transactions.
+            self.spec,
+            models::RawValue::from_str("{}").unwrap(),
+            self.state_dir.path(),
+            std::time::Duration::MAX,
+        );
+        tokio::pin!(responses_rx);
+
+        let mut published = Vec::new();
+        while let Some(response) = responses_rx.try_next().await? {
+            if let Some(derive::response::Published { doc_json }) = response.published {
+                published.push(
+                    serde_json::from_str(&doc_json).context("failed to parse derived document")?,
+                );
+            }
+        }
+        Ok(published)
+    }
+}
+
+// Run derivations over unread documents of the log, appending their derived
+// documents to the log, until no derivation has further documents to read.
+async fn run_derivations<L: runtime::LogHandler>(
+    runtime: &runtime::Runtime<L>,
+    derivations: &mut [Derivation<'_>],
+    log: &mut Vec<(models::Collection, serde_json::Value)>,
+) -> anyhow::Result<()> {
+    for _round in 0..MAX_DERIVATION_ROUNDS {
+        let mut idle = true;
+
+        for derivation in derivations.iter_mut() {
+            let txn: Vec<_> = log[derivation.offset..]
+                .iter()
+                .filter(|(collection, _doc)| derivation.sources.contains(&collection.as_str()))
+                .cloned()
+                .collect();
+            derivation.offset = log.len();
+
+            if txn.is_empty() {
+                continue;
+            }
+            idle = false;
+            derivation.fixture.push(txn);
+
+            let published = derivation
+                .run(runtime)
+                .await
+                .with_context(|| format!("failed to run derivation {}", derivation.spec.name))?;
+
+            log.extend(
+                published
+                    .into_iter()
+                    .map(|doc| (models::Collection::new(&derivation.spec.name), doc)),
+            );
+        }
+
+        if idle {
+            return Ok(());
+        }
+    }
+
+    anyhow::bail!(
+        "derivations did not settle after {MAX_DERIVATION_ROUNDS} rounds of processing (do they form a cycle?)"
+    )
+}
+
+// Combine logged documents of the collection which match the partition selector,
+// reducing them by key into their fully-reduced and key-ordered form.
+fn combine_collection(
+    spec: &flow::CollectionSpec,
+    selector: Option<&broker::LabelSelector>,
+    log: &[(models::Collection, serde_json::Value)],
+) -> anyhow::Result<Vec<serde_json::Value>> {
+    let ser_policy = doc::SerPolicy::noop();
+    let key = extractors::for_key(&spec.key, &spec.projections, &ser_policy)?;
+    let partitions =
+        extractors::for_fields(&spec.partition_fields, &spec.projections, &ser_policy)?;
+
+    let schema = if spec.read_schema_json.is_empty() {
+        &spec.write_schema_json
+    } else {
+        &spec.read_schema_json
+    };
+    let validator = doc::Validator::new(doc::validation::build_bundle(schema)?)?;
+
+    let memtable = doc::combine::MemTable::new(doc::combine::Spec::with_one_binding(
+        true, // Full reduction.
+        key,
+        format!("verified collection {}", spec.name),
+        None,
+        validator,
+    ));
+
+    for (collection, doc) in log {
+        if collection.as_str() != spec.name {
+            continue;
+        }
+        let set = labels::partition::encode_field_range(
+            Default::default(),
+            0,
+            u32::MAX,
+            &spec.partition_fields,
+            &partitions,
+            doc,
+        )?;
+        if !selector_matches(&set, selector) {
+            continue;
+        }
+        memtable.add(0, doc::HeapNode::from_node(doc, memtable.alloc()), false)?;
+    }
+
+    let mut out = Vec::new();
+    for drained in memtable.try_into_drainer()? {
+        let doc::combine::DrainedDoc { meta, root } = drained?;
+
+        if meta.deleted() {
+            continue;
+        }
+        out.push(serde_json::to_value(ser_policy.on_owned(&root))?);
+    }
+    Ok(out)
+}
+
+// Evaluate whether the LabelSet of a logical partition matches an optional selector.
+// Every label name of `include` must be matched by one of its values,
+// and no label of `exclude` may be matched. Empty selector values match any value.
+fn selector_matches(set: &broker::LabelSet, selector: Option<&broker::LabelSelector>) -> bool {
+    let Some(broker::LabelSelector { include, exclude }) = selector else {
+        return true;
+    };
+    let matches = |sel: &broker::Label| {
+        set.labels.iter().any(|label| {
+            label.name == sel.name
+                && (sel.value.is_empty()
+                    || label.value == sel.value
+                    || (sel.prefix && label.value.starts_with(&sel.value)))
+        })
+    };
+
+    let include = include
+        .as_ref()
+        .map(|s| s.labels.as_slice())
+        .unwrap_or_default();
+    let exclude = exclude
+        .as_ref()
+        .map(|s| s.labels.as_slice())
+        .unwrap_or_default();
+
+    let mut names: Vec<&str> = include.iter().map(|l| l.name.as_str()).collect();
+    names.dedup(); // LabelSets are ordered on name.
+
+    names.into_iter().all(|name| {
+        include
+            .iter()
+            .filter(|sel| sel.name == name)
+            .any(|sel| matches(sel))
+    }) && !exclude.iter().any(|sel| matches(sel))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    fn selector(include: &[(&str, &str)], exclude: &[(&str, &str)]) -> broker::LabelSelector {
+        broker::LabelSelector {
+            include: Some(labels::build_set(include.iter().copied())),
+            exclude: Some(labels::build_set(exclude.iter().copied())),
+        }
+    }
+
+    #[test]
+    fn test_selector_matches() {
+        let set = labels::build_set([
+            ("estuary.dev/field/region", "us-east"),
+            ("estuary.dev/field/tier", "gold"),
+        ]);
+
+        // An absent or empty selector matches everything.
+        assert!(selector_matches(&set, None));
+        assert!(selector_matches(&set, Some(&selector(&[], &[]))));
+
+        // Included labels must be matched, by any of their values.
+        assert!(selector_matches(
+            &set,
+            Some(&selector(&[("estuary.dev/field/region", "us-east")], &[]))
+        ));
+        assert!(selector_matches(
+            &set,
+            Some(&selector(
+                &[
+                    ("estuary.dev/field/region", "eu-west"),
+                    ("estuary.dev/field/region", "us-east"),
+                    ("estuary.dev/field/tier", "gold"),
+                ],
+                &[]
+            ))
+        ));
+        assert!(!selector_matches(
+            &set,
+            Some(&selector(
+                &[
+                    ("estuary.dev/field/region", "us-east"),
+                    ("estuary.dev/field/tier", "silver"),
+                ],
+                &[]
+            ))
+        ));
+        assert!(!selector_matches(
+            &set,
+            Some(&selector(&[("estuary.dev/field/other", "")], &[]))
+        ));
+
+        // An empty value matches any value of the label.
+        assert!(selector_matches(
+            &set,
+            Some(&selector(&[("estuary.dev/field/tier", "")], &[]))
+        ));
+
+        // Excluded labels must not be matched.
+        assert!(!selector_matches(
+            &set,
+            Some(&selector(&[], &[("estuary.dev/field/tier", "gold")]))
+        ));
+        assert!(selector_matches(
+            &set,
+            Some(&selector(&[], &[("estuary.dev/field/tier", "silver")]))
+        ));
+
+        // Prefix selectors match values having the prefix.
+        assert!(!selector_matches(
+            &set,
+            Some(&selector(&[("estuary.dev/field/region", "us-")], &[]))
+        ));
+        assert!(selector_matches(
+            &set,
+            Some(&selector(
+                &[("estuary.dev/field/region:prefix", "us-")],
+                &[]
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_combine_collection() {
+        let projection = |field: &str, ptr: &str, is_partition_key| flow::Projection {
+            field: field.to_string(),
+            ptr: ptr.to_string(),
+            is_partition_key,
+            inference: Some(Default::default()),
+            ..Default::default()
+        };
+        let spec = flow::CollectionSpec {
+            name: "acmeCo/things".to_string(),
+            key: vec!["/id".to_string()],
+            partition_fields: vec!["region".to_string()],
+            projections: vec![
+                projection("id", "/id", false),
+                projection("region", "/region", true),
+            ],
+            write_schema_json: json!({
+                "type": "object",
+                "properties": {
+                    "id": {"type": "string"},
+                    "n": {"type": "integer", "reduce": {"strategy": "sum"}},
+                    "region": {"type": "string"},
+                },
+                "required": ["id", "region"],
+                "reduce": {"strategy": "merge"},
+            })
+            .to_string(),
+            ..Default::default()
+        };
+
+        let log = vec![
+            (
+                models::Collection::new("acmeCo/things"),
+                json!({"id": "b", "region": "us", "n": 1}),
+            ),
+            (
+                models::Collection::new("acmeCo/other"),
+                json!({"id": "a", "region": "us", "n": 100}),
+            ),
+            (
+                models::Collection::new("acmeCo/things"),
+                json!({"id": "a", "region": "eu", "n": 2}),
+            ),
+            (
+                models::Collection::new("acmeCo/things"),
+                json!({"id": "b", "region": "us", "n": 3}),
+            ),
+        ];
+
+        // Documents are reduced by key, and returned in key order.
+        assert_eq!(
+            combine_collection(&spec, None, &log).unwrap(),
+            vec![
+                json!({"id": "a", "region": "eu", "n": 2}),
+                json!({"id": "b", "region": "us", "n": 4}),
+            ]
+        );
+
+        // Only documents of selected partitions are combined.
+        assert_eq!(
+            combine_collection(
+                &spec,
+                Some(&selector(&[("estuary.dev/field/region", "us")], &[])),
+                &log
+            )
+            .unwrap(),
+            vec![json!({"id": "b", "region": "us", "n": 4})]
+        );
+    }
+}
@@ -1,7 +1,15 @@
 mod delete;
+mod diff;
+mod graph;
+mod lint;
+mod local_test;
 mod publish;
 mod pull_specs;
+mod rollback;
 mod test;
+mod toggle;
+
+pub(crate) use publish::{prompt_to_continue, publish_specs};
 
 use crate::{
     api_exec, api_exec_paginated,
@@ -50,8 +58,17 @@ pub enum Command {
     /// Test catalog specifications
     ///
     /// Runs catalog tests based on specifications in a
-    /// local directory or a remote URL. This
+    /// local directory or a remote URL. Pass `--local` to run tests
+    /// on this machine without use of the control plane.
     Test(test::TestArgs),
+    /// Diff local catalog specifications with live specifications.
+    ///
+    /// Compares specifications of a local directory or remote URL with the
+    /// corresponding live specifications, and prints added, removed, and changed
+    /// specifications. Changes are detailed by binding and document location,
+    /// such as schema keywords and endpoint configuration properties.
+    /// Encrypted endpoint configuration values are masked.
+    Diff(diff::Diff),
     /// History of a catalog specification.
     ///
     /// Print all historical publications of catalog specifications.
@@ -75,6 +92,40 @@ pub enum Command {
     /// Once in your draft, use `draft develop` and `draft author` to
     /// develop and author updates to the specification.
     Draft(Draft),
+    /// Roll back catalog specifications to a prior publication.
+    ///
+    /// Restores every specification under a prefix to its latest publication
+    /// at or before a given publication ID or timestamp. Specifications created
+    /// after that point are left as-is, unless --delete-new is given.
+    /// Changes are shown for confirmation before they're published, and the
+    /// publication fails if any specification is concurrently updated.
+    Rollback(rollback::Rollback),
+    /// Disable the tasks, or task bindings, of catalog specifications.
+    ///
+    /// Disables the shards of every selected capture, derivation, and materialization,
+    /// or only their bindings named by --binding, through a single publication.
+    /// Changes are shown for confirmation before they're published, and the
+    /// publication fails if any specification is concurrently updated.
+    Disable(toggle::Toggle),
+    /// Enable the tasks, or task bindings, of catalog specifications.
+    ///
+    /// The inverse of `flowctl catalog disable`, which accepts the same arguments.
+    Enable(toggle::Toggle),
+    /// Render the dependency graph of catalog specifications.
+    ///
+    /// Emits a Graphviz DOT, Mermaid, or JSON graph having edges from captures
+    /// to the collections they write, and from collections to the derivations and
+    /// materializations which read them. Source-capture links and disabled
+    /// bindings are included. Collections which are referenced by selected specs
+    /// but aren't themselves selected are included as nodes without a type.
+    /// Use --from to render only the nodes upstream or downstream of a node.
+    Graph(graph::Graph),
+    /// Lint catalog specifications for best practices.
+    ///
+    /// Runs best-practice rules over specifications of a local directory or
+    /// remote URL, and reports warnings having a rule ID and the scope of the
+    /// offending specification. Rules may be enabled or disabled with a --config file.
+    Lint(lint::Lint),
 }
 
 /// Common selection criteria based on the spec name.
@@ -214,12 +265,32 @@ impl Catalog {
             Command::PullSpecs(pull) => pull_specs::do_pull_specs(ctx, pull).await,
             Command::Publish(publish) => publish::do_publish(ctx, publish).await,
             Command::Test(source) => test::do_test(ctx, source).await,
+            Command::Diff(diff) => diff::do_diff(ctx, diff).await,
             Command::History(history) => do_history(ctx, history).await,
             Command::Draft(draft) => do_draft(ctx, draft).await,
+            Command::Rollback(rollback) => rollback::do_rollback(ctx, rollback).await,
+            Command::Disable(toggle) => toggle::do_toggle(ctx, toggle, true).await,
+            Command::Enable(toggle) => toggle::do_toggle(ctx, toggle, false).await,
+            Command::Graph(graph) => graph::do_graph(ctx, graph).await,
+            Command::Lint(lint) => lint::do_lint(ctx, lint).await,
         }
     }
 }
 
+/// Returns a LIKE pattern which matches catalog names beginning with `prefix`.
+/// The LIKE wildcards `%` and `_` are escaped, as `_` is common within names.
+pub fn like_prefix(prefix: &str) -> String {
+    let mut pattern = String::with_capacity(prefix.len() + 1);
+    for c in prefix.chars() {
+        if matches!(c, '\\' | '%' | '_') {
+            pattern.push('\\');
+        }
+        pattern.push(c);
+    }
+    pattern.push('%');
+    pattern
+}
+
 /// Fetches `LiveSpecRow`s from the `live_specs_ext` view.
 /// This may make multiple requests as necessary.
 ///
@@ -277,7 +348,13 @@ where
             .map(|batch| async {
                 let conditions = batch
                     .iter()
-                    .map(|prefix| format!("catalog_name.like.\"{prefix}%\""))
+                    // Backslashes are themselves escaped within quoted values.
+                    .map(|prefix| {
+                        format!(
+                            "catalog_name.like.\"{}\"",
+                            like_prefix(prefix).replace('\\', "\\\\")
+                        )
+                    })
                     .join(",");
                 // We need to paginate the results, since prefixes can match many rows.
                 api_exec_paginated::<T>(builder.clone().or(conditions)).await
@@ -372,6 +449,31 @@ impl SpecRow for LiveSpecRow {
     }
 }
 
+/// A spec as it's to be drafted, or as it's currently live,
+/// which expects a specific last publication of its live spec.
+#[derive(Clone)]
+pub struct DraftRow {
+    pub catalog_name: String,
+    pub spec_type: CatalogType,
+    pub spec: Option<RawValue>,
+    pub expect_pub_id: models::Id,
+}
+
+impl SpecRow for DraftRow {
+    fn catalog_name(&self) -> &str {
+        &self.catalog_name
+    }
+    fn spec_type(&self) -> CatalogType {
+        self.spec_type
+    }
+    fn spec(&self) -> Option<&RawValue> {
+        self.spec.as_ref()
+    }
+    fn expect_pub_id(&self) -> Option<models::Id> {
+        Some(self.expect_pub_id)
+    }
+}
+
 /// Collects an iterator of `SpecRow`s into a `tables::DraftCatalog`.
 pub fn collect_specs(
     rows: impl IntoIterator<Item = impl SpecRow>,
@@ -500,7 +602,7 @@ async fn do_history(ctx: &mut crate::CliContext, History { name }: &History) ->
     let rows: Vec<Row> = api_exec_paginated(
         ctx.client
             .from("publication_specs_ext")
-            .like("catalog_name", format!("{name}%"))
+            .like("catalog_name", like_prefix(&name))
             .select(
                 vec![
                     "catalog_name",
@@ -74,7 +74,7 @@ pub async fn do_publish(ctx: &mut CliContext, args: &Publish) -> anyhow::Result<
     Ok(())
 }
 
-async fn prompt_to_continue() -> bool {
+pub(crate) async fn prompt_to_continue() -> bool {
     use tokio::io::AsyncReadExt;
 
     println!("\nEnter Y to publish these specs, or anything else to abort: ");
@@ -88,7 +88,33 @@ async fn prompt_to_continue() -> bool {
     }
 }
 
-async fn try_delete_draft(client: &crate::Client, draft_id: models::Id) {
+/// Publish `specs` through a newly-created draft, which is deleted if the
+/// publication fails. Specs which are created are placed in `default_data_plane`.
+pub(crate) async fn publish_specs(
+    client: &crate::Client,
+    default_data_plane: &str,
+    specs: &tables::DraftCatalog,
+) -> anyhow::Result<()> {
+    let draft = draft::create_draft(client).await?;
+    println!("Created draft: {}", &draft.id);
+    tracing::info!(draft_id = %draft.id, "created draft");
+
+    if let Err(err) = draft::upsert_draft_specs(client, draft.id, specs).await {
+        try_delete_draft(client, draft.id).await;
+        return Err(err);
+    }
+    println!("Proceeding to publish...");
+
+    let publish_result = draft::publish(client, default_data_plane, draft.id, false).await;
+    // The draft will have been deleted automatically if the publish was successful.
+    if let Err(err) = publish_result.as_ref() {
+        tracing::error!(draft_id = %draft.id, error = %err, "publication error");
+        try_delete_draft(client, draft.id).await;
+    }
+    publish_result
+}
+
+pub(super) async fn try_delete_draft(client: &crate::Client, draft_id: models::Id) {
     if let Err(del_err) = draft::delete_draft(client, draft_id).await {
         tracing::error!(draft_id = %draft_id, error = %del_err, "failed to delete draft");
     }
@@ -0,0 +1,265 @@
+use crate::catalog::{collect_specs, diff, publish, DraftRow};
+use crate::{api_exec_paginated, local_specs, CliContext};
+use anyhow::Context;
+use itertools::Itertools;
+use models::{CatalogType, RawValue};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+#[derive(Debug, clap::Args)]
+pub struct Rollback {
+    /// Prefix of catalog specifications to roll back.
+    #[clap(long)]
+    prefix: String,
+    /// Publication ID or RFC 3339 timestamp to roll back to.
+    ///
+    /// Each specification under the prefix is restored to its latest
+    /// publication at or before this point.
+    #[clap(long)]
+    to: String,
+    /// Delete specifications which were created after the rollback point.
+    ///
+    /// By default, these specifications are left as they are.
+    #[clap(long)]
+    delete_new: bool,
+    /// Proceed with the rollback without prompting for confirmation.
+    ///
+    /// This flag is required if running flowctl non-interactively, such as in a shell script.
+    #[clap(long)]
+    auto_approve: bool,
+    /// Data-plane into which re-created specifications will be placed.
+    #[clap(long, default_value = "ops/dp/public/gcp-us-central1-c1")]
+    default_data_plane: String,
+}
+
+// Point in the publication history to which specifications are rolled back.
+enum Point {
+    Publication(models::Id),
+    Time(time::OffsetDateTime),
+}
+
+impl Point {
+    fn parse(to: &str) -> anyhow::Result<Self> {
+        if let Ok(pub_id) = to.parse::<models::Id>() {
+            return Ok(Self::Publication(pub_id));
+        }
+        let ts = time::OffsetDateTime::parse(to, &time::format_description::well_known::Rfc3339)
+            .with_context(|| {
+                format!("{to:?} is neither a publication ID nor an RFC 3339 timestamp")
+            })?;
+        Ok(Self::Time(ts))
+    }
+
+    fn includes(&self, row: &PublicationRow) -> bool {
+        match self {
+            Self::Publication(pub_id) => row.pub_id <= *pub_id,
+            Self::Time(ts) => row.published_at.0 <= *ts,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct PublicationRow {
+    catalog_name: String,
+    pub_id: models::Id,
+    published_at: crate::Timestamp,
+    // None if the publication deleted the spec.
+    spec_type: Option<CatalogType>,
+}
+
+#[derive(Deserialize)]
+struct LiveRow {
+    catalog_name: String,
+    last_pub_id: models::Id,
+    // None if the spec is currently deleted.
+    spec_type: Option<CatalogType>,
+    spec: Option<RawValue>,
+}
+
+pub async fn do_rollback(ctx: &mut CliContext, args: &Rollback) -> anyhow::Result<()> {
+    use crossterm::tty::IsTty;
+
+    anyhow::ensure!(args.auto_approve || std::io::stdin().is_tty(), "The rollback command must be run interactively unless the `--auto-approve` flag is provided");
+
+    let point = Point::parse(&args.to)?;
+    let like = super::like_prefix(&args.prefix);
+
+    let publications: Vec<PublicationRow> = api_exec_paginated(
+        ctx.client
+            .from("publication_specs_ext")
+            .like("catalog_name", &like)
+            .select("catalog_name,pub_id,published_at,spec_type"),
+    )
+    .await
+    .context("fetching publication history")?;
+
+    let live: Vec<LiveRow> = api_exec_paginated(
+        ctx.client
+            .from("live_specs_ext")
+            .like("catalog_name", &like)
+            .select("catalog_name,last_pub_id,spec_type,spec"),
+    )
+    .await
+    .context("fetching live specs")?;
+
+    // Identify the latest publication of each spec as of the rollback point.
+    let mut targets: BTreeMap<&str, &PublicationRow> = BTreeMap::new();
+    for row in publications.iter().filter(|row| point.includes(row)) {
+        match targets.get(row.catalog_name.as_str()) {
+            Some(prior) if prior.pub_id >= row.pub_id => (),
+            _ => {
+                targets.insert(&row.catalog_name, row);
+            }
+        }
+    }
+
+    let mut restore = Vec::new(); // Specs to restore from a publication.
+    let mut drafted = Vec::new(); // DraftRows of the rollback.
+    let mut current = Vec::new(); // Live DraftRows which are affected by the rollback.
+    let mut left_as_is = Vec::new(); // Specs created after the point, which are left as-is.
+
+    for live in &live {
+        let target = targets.get(live.catalog_name.as_str());
+
+        let Some(spec_type) = target
+            .and_then(|target| target.spec_type)
+            .or(live.spec_type)
+        else {
+            continue; // Deleted both at the rollback point and now.
+        };
+
+        match target {
+            // Unchanged since the rollback point.
+            Some(target) if target.pub_id == live.last_pub_id => continue,
+            // The spec existed at the rollback point, and is restored.
+            Some(PublicationRow {
+                spec_type: Some(_),
+                pub_id,
+                ..
+            }) => restore.push((*pub_id, live)),
+            // The spec was deleted as of the rollback point, and is deleted again.
+            Some(PublicationRow {
+                spec_type: None, ..
+            }) => drafted.push(DraftRow {
+                catalog_name: live.catalog_name.clone(),
+                spec_type,
+                spec: None,
+                expect_pub_id: live.last_pub_id,
+            }),
+            // Created after the rollback point, and since deleted.
+            None if live.spec_type.is_none() => continue,
+            // Created after the rollback point.
+            None if args.delete_new => drafted.push(DraftRow {
+                catalog_name: live.catalog_name.clone(),
+                spec_type,
+                spec: None,
+                expect_pub_id: live.last_pub_id,
+            }),
+            None => {
+                left_as_is.push(live.catalog_name.as_str());
+                continue;
+            }
+        }
+
+        if let Some(spec_type) = live.spec_type {
+            current.push(DraftRow {
+                catalog_name: live.catalog_name.clone(),
+                spec_type,
+                spec: live.spec.clone(),
+                expect_pub_id: live.last_pub_id,
+            });
+        }
+    }
+
+    // Fetch models of restored specs as of their publications.
+    drafted.extend(fetch_published_specs(&ctx.client, restore).await?);
+
+    if !left_as_is.is_empty() {
+        println!("The following specs were created after the rollback point, and will be left as-is (use --delete-new to delete them):");
+        for name in left_as_is {
+            println!("{name}");
+        }
+        println!(); // blank line to give a bit of spacing
+    }
+
+    if drafted.is_empty() {
+        println!("No specs would be changed by this rollback, nothing to publish.");
+        return Ok(());
+    }
+
+    let diffs = diff::diff_catalogs(
+        diff::models_by_name(local_specs::into_catalog(collect_specs(current)?)),
+        diff::models_by_name(local_specs::into_catalog(collect_specs(
+            drafted.iter().cloned(),
+        )?)),
+    );
+    println!("Will roll back the following {} specs", drafted.len());
+    ctx.write_all(diffs, ())?;
+
+    if !(args.auto_approve || publish::prompt_to_continue().await) {
+        println!("\nCancelling");
+        anyhow::bail!("rollback cancelled");
+    }
+
+    publish::publish_specs(
+        &ctx.client,
+        &args.default_data_plane,
+        &collect_specs(drafted)?,
+    )
+    .await
+    .context("Rollback failed")?;
+    println!("\nRollback successful");
+    Ok(())
+}
+
+// Fetch models of specs as of the given publications, as DraftRows which
+// expect the current last publication of their live spec.
+async fn fetch_published_specs(
+    client: &crate::Client,
+    restore: Vec<(models::Id, &LiveRow)>,
+) -> anyhow::Result<Vec<DraftRow>> {
+    // Break requested names into chunks, to avoid overflowing the URL length limit in postgREST.
+    const BATCH_SIZE: usize = 25;
+
+    #[derive(Deserialize)]
+    struct Row {
+        catalog_name: String,
+        spec_type: CatalogType,
+        spec: Option<RawValue>,
+    }
+    let mut out = Vec::with_capacity(restore.len());
+
+    for (pub_id, lives) in restore.into_iter().into_group_map() {
+        for batch in lives.chunks(BATCH_SIZE) {
+            let rows: Vec<Row> = api_exec_paginated(
+                client
+                    .from("publication_specs_ext")
+                    .eq("pub_id", pub_id.to_string())
+                    .in_("catalog_name", batch.iter().map(|live| &live.catalog_name))
+                    .select("catalog_name,spec_type,spec"),
+            )
+            .await
+            .with_context(|| format!("fetching specs of publication {pub_id}"))?;
+
+            for Row {
+                catalog_name,
+                spec_type,
+                spec,
+            } in rows
+            {
+                let live = batch
+                    .iter()
+                    .find(|live| live.catalog_name == catalog_name)
+                    .context("publication_specs_ext returned an unexpected spec")?;
+
+                out.push(DraftRow {
+                    catalog_name,
+                    spec_type,
+                    spec,
+                    expect_pub_id: live.last_pub_id,
+                });
+            }
+        }
+    }
+    Ok(out)
+}
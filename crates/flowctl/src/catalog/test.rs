@@ -1,3 +1,4 @@
+use super::local_test;
 use crate::{draft, local_specs, CliContext};
 use anyhow::Context;
 
@@ -9,6 +10,12 @@ pub struct TestArgs {
     /// Data-plane into which created specifications will be placed.
     #[clap(long, default_value = "ops/dp/public/gcp-us-central1-c1")]
     default_data_plane: String,
+    /// Run tests locally, without use of the control plane.
+    ///
+    /// Derivations are run on this machine, and the process exits
+    /// with a non-zero status if any test fails.
+    #[clap(long)]
+    local: bool,
 }
 
 /// Test is really just a publish with the `dry-run` flag set to true, but we have a separate subcommand
@@ -16,6 +23,10 @@ pub struct TestArgs {
 /// and discoverable to users. There's also no need for any confirmation steps, since we're not
 /// actually modifying the published specs.
 pub async fn do_test(ctx: &mut CliContext, args: &TestArgs) -> anyhow::Result<()> {
+    if args.local {
+        return local_test::run_tests(&args.source).await;
+    }
+
     let (draft_catalog, _validations) =
         local_specs::load_and_validate(&ctx.client, &args.source).await?;
 
@@ -0,0 +1,269 @@
+use crate::catalog::{
+    collect_specs, delete, diff, fetch_live_specs, publish, DraftRow, LiveSpecRow, SpecTypeSelector,
+};
+use crate::{local_specs, CliContext};
+use anyhow::Context;
+use models::{CatalogType, RawValue};
+use std::collections::BTreeSet;
+
+#[derive(Debug, clap::Args)]
+pub struct Toggle {
+    #[clap(flatten)]
+    pub name_selector: delete::NameSelector,
+    /// Select only tasks of this type.
+    #[clap(long = "type", value_enum)]
+    pub task_type: Option<TaskType>,
+    /// Toggle the binding of selected tasks having this name, rather than their shards.
+    /// May be provided multiple times.
+    ///
+    /// Capture bindings are named by their target collection, materialization
+    /// bindings by their source collection, and derivation transforms by their name.
+    #[clap(long)]
+    pub binding: Vec<String>,
+    /// Proceed without prompting for confirmation.
+    ///
+    /// This flag is required if running flowctl non-interactively, such as in a shell script.
+    #[clap(long)]
+    pub auto_approve: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum TaskType {
+    Capture,
+    Derivation,
+    Materialization,
+}
+
+/// Disable (or enable) the shards or bindings of all selected tasks,
+/// through a single publication which expects each task is unchanged.
+pub async fn do_toggle(ctx: &mut CliContext, args: &Toggle, disable: bool) -> anyhow::Result<()> {
+    use crossterm::tty::IsTty;
+
+    let verb = if disable { "disable" } else { "enable" };
+
+    anyhow::ensure!(
+        args.auto_approve || std::io::stdin().is_tty(),
+        "The {verb} command must be run interactively unless the `--auto-approve` flag is provided"
+    );
+
+    let include = |task_type| Some(args.task_type.map_or(true, |t| t == task_type));
+    let list = crate::catalog::List {
+        flows: false,
+        name_selector: args.name_selector.clone().into(),
+        type_selector: SpecTypeSelector {
+            captures: include(TaskType::Capture),
+            // Derivations are collections, and non-derived collections are filtered below.
+            collections: include(TaskType::Derivation),
+            materializations: include(TaskType::Materialization),
+            tests: Some(false),
+        },
+    };
+
+    let live = fetch_live_specs::<LiveSpecRow>(
+        &ctx.client,
+        &list,
+        vec![
+            "id",
+            "catalog_name",
+            "spec_type",
+            "updated_at",
+            "last_pub_id",
+            "spec",
+        ],
+    )
+    .await
+    .context("fetching live specs")?;
+
+    let mut current = Vec::new(); // Live DraftRows of changed specs.
+    let mut drafted = Vec::new(); // DraftRows of changed specs.
+    let mut matched = BTreeSet::new(); // Bindings matched by any selected task.
+
+    for row in live {
+        let Some(spec) = &row.spec else {
+            continue;
+        };
+        let toggled = toggle_model(row.spec_type, spec, &args.binding, disable, &mut matched)
+            .with_context(|| format!("updating the model of {}", row.catalog_name))?;
+
+        let Some(toggled) = toggled else {
+            continue; // Not a task, or already in the desired state.
+        };
+
+        current.push(DraftRow {
+            catalog_name: row.catalog_name.clone(),
+            spec_type: row.spec_type,
+            spec: row.spec.clone(),
+            expect_pub_id: row.last_pub_id,
+        });
+        drafted.push(DraftRow {
+            catalog_name: row.catalog_name,
+            spec_type: row.spec_type,
+            spec: Some(toggled),
+            expect_pub_id: row.last_pub_id,
+        });
+    }
+
+    // A mistyped binding would otherwise go unnoticed.
+    let unmatched: Vec<&str> = args
+        .binding
+        .iter()
+        .filter(|binding| !matched.contains(binding.as_str()))
+        .map(String::as_str)
+        .collect();
+    if !unmatched.is_empty() {
+        anyhow::bail!(
+            "no selected task has a binding named {}",
+            unmatched.join(", ")
+        );
+    }
+
+    if drafted.is_empty() {
+        println!("No selected tasks would be changed, nothing to publish.");
+        return Ok(());
+    }
+
+    let diffs = diff::diff_catalogs(
+        diff::models_by_name(local_specs::into_catalog(collect_specs(current)?)),
+        diff::models_by_name(local_specs::into_catalog(collect_specs(
+            drafted.iter().cloned(),
+        )?)),
+    );
+    println!("Will {verb} the following {} specs", drafted.len());
+    ctx.write_all(diffs, ())?;
+
+    if !(args.auto_approve || publish::prompt_to_continue().await) {
+        println!("\nCancelling");
+        anyhow::bail!("{verb} cancelled");
+    }
+
+    // Only live specs are re-published, so none are created and
+    // a default data-plane is not required.
+    publish::publish_specs(&ctx.client, "", &collect_specs(drafted)?)
+        .await
+        .with_context(|| format!("failed to {verb} specs"))?;
+    println!("\nSuccessfully updated specs");
+    Ok(())
+}
+
+// Toggle the model of a live spec, returning its updated model
+// or None if it's not a task or would be unchanged.
+// Selected `bindings` which the task has are added to `matched`.
+fn toggle_model<'b>(
+    spec_type: CatalogType,
+    spec: &RawValue,
+    bindings: &'b [String],
+    disable: bool,
+    matched: &mut BTreeSet<&'b str>,
+) -> anyhow::Result<Option<RawValue>> {
+    // Set each `flag` to `disable`, returning true if any was changed.
+    fn set<'a>(flags: impl IntoIterator<Item = &'a mut bool>, disable: bool) -> bool {
+        let mut changed = false;
+        for flag in flags {
+            changed |= *flag != disable;
+            *flag = disable;
+        }
+        changed
+    }
+    let mut selected = |name: &str| {
+        let found = bindings.iter().find(|binding| binding.as_str() == name);
+        if let Some(binding) = found {
+            matched.insert(binding.as_str());
+        }
+        found.is_some()
+    };
+
+    let (changed, value) = match spec_type {
+        CatalogType::Capture => {
+            let mut model: models::CaptureDef = serde_json::from_str(spec.get())?;
+            let changed = if bindings.is_empty() {
+                set([&mut model.shards.disable], disable)
+            } else {
+                set(
+                    model
+                        .bindings
+                        .iter_mut()
+                        .filter(|b| selected(b.target.as_str()))
+                        .map(|b| &mut b.disable),
+                    disable,
+                )
+            };
+            (changed, serde_json::to_value(model)?)
+        }
+        CatalogType::Materialization => {
+            let mut model: models::MaterializationDef = serde_json::from_str(spec.get())?;
+            let changed = if bindings.is_empty() {
+                set([&mut model.shards.disable], disable)
+            } else {
+                set(
+                    model
+                        .bindings
+                        .iter_mut()
+                        .filter(|b| selected(b.source.collection().as_str()))
+                        .map(|b| &mut b.disable),
+                    disable,
+                )
+            };
+            (changed, serde_json::to_value(model)?)
+        }
+        CatalogType::Collection => {
+            let mut model: models::CollectionDef = serde_json::from_str(spec.get())?;
+            let Some(derive) = &mut model.derive else {
+                return Ok(None); // Not a derivation.
+            };
+            let changed = if bindings.is_empty() {
+                set([&mut derive.shards.disable], disable)
+            } else {
+                set(
+                    derive
+                        .transforms
+                        .iter_mut()
+                        .filter(|t| selected(t.name.as_str()))
+                        .map(|t| &mut t.disable),
+                    disable,
+                )
+            };
+            (changed, serde_json::to_value(model)?)
+        }
+        CatalogType::Test => return Ok(None),
+    };
+
+    Ok(changed.then(|| RawValue::from_value(&value)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_toggle_model_bindings() {
+        let spec = RawValue::from_value(&json!({
+            "endpoint": {"connector": {"image": "an/image", "config": {}}},
+            "bindings": [
+                {"resource": {}, "target": "acmeCo/one"},
+                {"resource": {}, "target": "acmeCo/two", "disable": true},
+            ],
+        }));
+        let bindings = vec!["acmeCo/one".to_string(), "acmeCo/missing".to_string()];
+        let mut matched = BTreeSet::new();
+
+        let toggled = toggle_model(CatalogType::Capture, &spec, &bindings, true, &mut matched)
+            .unwrap()
+            .unwrap();
+        let toggled: models::CaptureDef = serde_json::from_str(toggled.get()).unwrap();
+
+        assert!(toggled.bindings.iter().all(|b| b.disable));
+        assert!(!toggled.shards.disable);
+        assert_eq!(matched, BTreeSet::from(["acmeCo/one"]));
+
+        // A matched binding which is already in the desired state is unchanged.
+        let mut matched = BTreeSet::new();
+        let bindings = vec!["acmeCo/two".to_string()];
+        assert!(
+            toggle_model(CatalogType::Capture, &spec, &bindings, true, &mut matched)
+                .unwrap()
+                .is_none()
+        );
+        assert_eq!(matched, BTreeSet::from(["acmeCo/two"]));
+    }
+}
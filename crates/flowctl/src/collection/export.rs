@@ -0,0 +1,728 @@
+use crate::collection::CollectionJournalSelector;
+use anyhow::Context;
+use futures::StreamExt;
+use gazette::journal::ReadJsonLine;
+use proto_flow::flow;
+use proto_gazette::broker;
+use std::sync::Arc;
+
+#[derive(clap::Args, Debug)]
+pub struct ExportArgs {
+    #[clap(flatten)]
+    pub selector: CollectionJournalSelector,
+    /// Path of the file to write.
+    #[clap(long)]
+    pub path: std::path::PathBuf,
+    /// Format of the exported file.
+    /// If not provided, the format is inferred from the extension of `--path`.
+    #[clap(long, value_enum)]
+    pub format: Option<ExportFormat>,
+    /// Export only documents which were written at or after this RFC 3339 timestamp.
+    /// For example, `--since 2024-01-01T00:00:00Z`.
+    #[clap(long)]
+    pub since: Option<humantime::Timestamp>,
+    /// Export only documents which were written before this RFC 3339 timestamp.
+    #[clap(long)]
+    pub until: Option<humantime::Timestamp>,
+    /// Comma-separated projected fields of the collection to export, in order.
+    /// By default, all projected fields other than the root document are exported.
+    #[clap(long, value_delimiter = ',')]
+    pub fields: Vec<String>,
+}
+
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq)]
+pub enum ExportFormat {
+    Parquet,
+    Csv,
+    Avro,
+}
+
+// Number of rows written in each Parquet row group.
+const PARQUET_ROW_GROUP_SIZE: usize = 10_000;
+
+/// Export documents of a collection into a Parquet, CSV or Avro file.
+/// Documents written within the time range are reduced by the collection key
+/// and exported in key order, with a column for each selected projection.
+pub async fn export_collection(
+    ctx: &mut crate::CliContext,
+    ExportArgs {
+        selector,
+        path,
+        format,
+        since,
+        until,
+        fields,
+    }: &ExportArgs,
+) -> anyhow::Result<()> {
+    let format = match format {
+        Some(format) => *format,
+        None => match path.extension().and_then(|ext| ext.to_str()) {
+            Some("parquet") => ExportFormat::Parquet,
+            Some("csv") => ExportFormat::Csv,
+            Some("avro") => ExportFormat::Avro,
+            _ => anyhow::bail!(
+                "cannot infer the export format of {} (provide `--format`)",
+                path.display()
+            ),
+        },
+    };
+    let since = since.as_ref().map(|since| **since);
+    let until = until.as_ref().map(|until| **until);
+
+    let collection = &selector.collection;
+    let spec = super::fetch_built_spec(&ctx.client, collection).await?;
+
+    let columns = Column::for_fields(&spec, fields)?;
+    let ser_policy = doc::SerPolicy::noop();
+    let extractors = extractors::for_fields(
+        &columns.iter().map(|c| &c.field).collect::<Vec<_>>(),
+        &spec.projections,
+        &ser_policy,
+    )?;
+
+    let schema = if spec.read_schema_json.is_empty() {
+        &spec.write_schema_json
+    } else {
+        &spec.read_schema_json
+    };
+    let validator = doc::Validator::new(doc::validation::build_bundle(schema)?)?;
+
+    let mut accumulator = doc::combine::Accumulator::new(
+        doc::combine::Spec::with_one_binding(
+            true, // Full reduction.
+            extractors::for_key(&spec.key, &spec.projections, &ser_policy)?,
+            format!("exported collection {collection}"),
+            None,
+            validator,
+        ),
+        tempfile::tempfile().context("opening temporary spill file")?,
+    )?;
+
+    let (journal_name_prefix, journal_client) =
+        flow_client::fetch_collection_authorization(&ctx.client, collection).await?;
+
+    let list_resp = journal_client
+        .list(broker::ListRequest {
+            selector: Some(selector.build_label_selector(journal_name_prefix)),
+            ..Default::default()
+        })
+        .await
+        .context("listing journals for collection export")?;
+
+    // Fragments which were last modified before `since` cannot hold documents of the range.
+    let begin_mod_time = since
+        .and_then(|since| since.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|since| since.as_secs() as i64)
+        .unwrap_or_default();
+    let uuid_ptr = doc::Pointer::from_str(&spec.uuid_ptr);
+    let mut read = 0;
+
+    for journal in list_resp.journals.into_iter().filter_map(|j| j.spec) {
+        let journal = journal.name;
+        tracing::debug!(%journal, "starting read of journal");
+
+        let mut lines = journal_client
+            .clone()
+            .read_committed(
+                broker::ReadRequest {
+                    journal: journal.clone(),
+                    offset: 0,
+                    block: false,
+                    begin_mod_time,
+                    ..Default::default()
+                },
+                1,
+            )
+            .take_while(|line| {
+                futures::future::ready(!matches!(
+                    line,
+                    // Graceful EOF of non-blocking read.
+                    Err(gazette::Error::BrokerStatus(
+                        broker::Status::OffsetNotYetAvailable
+                    ))
+                ))
+            })
+            .boxed_local();
+
+        while let Some(line) = lines.next().await {
+            let (root, next_offset) = match line {
+                Ok(ReadJsonLine::Meta(_)) => continue,
+                Ok(ReadJsonLine::Doc { root, next_offset }) => (root, next_offset),
+                Err(err) if err.is_transient() => {
+                    tracing::warn!(%err, %journal, "error reading collection (will retry)");
+                    tokio::time::sleep(super::read::RETRY_BACKOFF).await;
+                    continue;
+                }
+                Err(err) => return Err(err).with_context(|| format!("reading journal {journal}")),
+            };
+
+            let clock = super::read::parse_clock(&uuid_ptr, root.get()).with_context(|| {
+                format!("document of journal {journal} ending at offset {next_offset} is missing a valid UUID")
+            })?;
+            let written_at = clock.to_time();
+
+            if matches!(since, Some(since) if written_at < since)
+                || matches!(until, Some(until) if written_at >= until)
+            {
+                continue;
+            }
+
+            let memtable = accumulator.memtable()?;
+            memtable.add(
+                0,
+                doc::HeapNode::from_node(root.get(), memtable.alloc()),
+                false,
+            )?;
+            read += 1;
+        }
+    }
+
+    let rows = accumulator.into_drainer()?.filter_map(|drained| {
+        let doc::combine::DrainedDoc { meta, root } = match drained {
+            Ok(drained) => drained,
+            Err(err) => return Some(Err(anyhow::Error::from(err))),
+        };
+        if meta.deleted() {
+            return None;
+        }
+        Some(match &root {
+            doc::OwnedNode::Heap(root) => extract_row(&columns, &extractors, root.get()),
+            doc::OwnedNode::Archived(root) => extract_row(&columns, &extractors, root.get()),
+        })
+    });
+
+    let file = std::fs::File::create(path)
+        .with_context(|| format!("creating export file {}", path.display()))?;
+    let file = std::io::BufWriter::new(file);
+
+    let written = match format {
+        ExportFormat::Parquet => write_parquet(&columns, rows, file),
+        ExportFormat::Csv => write_csv(&columns, rows, file),
+        ExportFormat::Avro => write_avro(&columns, rows, file),
+    }
+    .with_context(|| format!("writing export file {}", path.display()))?;
+
+    println!(
+        "Exported {written} reduced documents (from {read} documents read) to {}.",
+        path.display()
+    );
+    Ok(())
+}
+
+// Type of an exported column, as inferred from its projection.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ColumnType {
+    Boolean,
+    Integer,
+    Number,
+    // Strings, and also values of other or multiple types which are exported as JSON.
+    String,
+}
+
+#[derive(Debug)]
+struct Column {
+    field: String,
+    type_: ColumnType,
+    nullable: bool,
+}
+
+// A value of an exported row.
+#[derive(Debug)]
+enum Cell {
+    Null,
+    Boolean(bool),
+    Integer(i64),
+    Number(f64),
+    String(String),
+}
+
+type Row = Vec<Cell>;
+
+impl Column {
+    // Build Columns for the selected `fields` of the collection,
+    // or for all non-root projections if `fields` is empty.
+    fn for_fields(spec: &flow::CollectionSpec, fields: &[String]) -> anyhow::Result<Vec<Self>> {
+        let projections: Vec<&flow::Projection> = if fields.is_empty() {
+            spec.projections
+                .iter()
+                .filter(|projection| !projection.ptr.is_empty())
+                .collect()
+        } else {
+            fields
+                .iter()
+                .map(|field| {
+                    spec.projections
+                        .iter()
+                        .find(|projection| projection.field == *field)
+                        .with_context(|| {
+                            format!("collection {} has no projected field {field:?}", spec.name)
+                        })
+                })
+                .collect::<anyhow::Result<_>>()?
+        };
+        anyhow::ensure!(!projections.is_empty(), "there are no fields to export");
+
+        Ok(projections.into_iter().map(Self::from_projection).collect())
+    }
+
+    fn from_projection(projection: &flow::Projection) -> Self {
+        let (types, must_exist, numeric) = match &projection.inference {
+            Some(inference) => (
+                inference.types.as_slice(),
+                inference.exists == flow::inference::Exists::Must as i32,
+                inference.numeric.as_ref(),
+            ),
+            None => (&[] as &[String], false, None),
+        };
+        // Integers which are inferred to exceed the range of i64 are exported as numbers.
+        let exceeds_i64 = numeric.is_some_and(|numeric| {
+            (numeric.has_minimum && numeric.minimum < i64::MIN as f64)
+                || (numeric.has_maximum && numeric.maximum > i64::MAX as f64)
+        });
+
+        let non_null: Vec<&str> = types
+            .iter()
+            .map(String::as_str)
+            .filter(|type_| *type_ != "null")
+            .collect();
+
+        let type_ = match non_null.as_slice() {
+            ["boolean"] => ColumnType::Boolean,
+            ["integer"] if !exceeds_i64 => ColumnType::Integer,
+            ["integer"] | ["number"] | ["integer", "number"] => ColumnType::Number,
+            _ => ColumnType::String,
+        };
+
+        Self {
+            field: projection.field.clone(),
+            type_,
+            nullable: !must_exist || non_null.len() != types.len(),
+        }
+    }
+
+    fn cell(&self, value: serde_json::Value) -> anyhow::Result<Cell> {
+        use serde_json::Value;
+
+        let cell = match (self.type_, value) {
+            (_, Value::Null) => Cell::Null,
+            (ColumnType::Boolean, Value::Bool(b)) => Cell::Boolean(b),
+            (ColumnType::Integer, Value::Number(n)) => match n.as_i64() {
+                Some(i) => Cell::Integer(i),
+                // Fall back to a number if the value is beyond the range of i64.
+                None => Cell::Number(n.as_f64().unwrap()),
+            },
+            (ColumnType::Number, Value::Number(n)) => Cell::Number(n.as_f64().unwrap()),
+            (ColumnType::String, Value::String(s)) => Cell::String(s),
+            (ColumnType::String, value) => Cell::String(value.to_string()),
+            (type_, value) => anyhow::bail!(
+                "field {:?} value {value} doesn't match its inferred {type_:?} type",
+                self.field
+            ),
+        };
+
+        if matches!(cell, Cell::Null) && !self.nullable {
+            anyhow::bail!("field {:?} is required but has no value", self.field);
+        }
+        Ok(cell)
+    }
+}
+
+fn extract_row<N: doc::AsNode>(
+    columns: &[Column],
+    extractors: &[doc::Extractor],
+    doc: &N,
+) -> anyhow::Result<Row> {
+    let ser_policy = doc::SerPolicy::noop();
+
+    columns
+        .iter()
+        .zip(extractors.iter())
+        .map(|(column, extractor)| {
+            let value = match extractor.query(doc) {
+                Ok(node) => serde_json::to_value(ser_policy.on(node))?,
+                Err(value) => value.into_owned(),
+            };
+            column.cell(value)
+        })
+        .collect()
+}
+
+fn write_csv<W: std::io::Write>(
+    columns: &[Column],
+    rows: impl Iterator<Item = anyhow::Result<Row>>,
+    w: W,
+) -> anyhow::Result<usize> {
+    let mut writer = csv::Writer::from_writer(w);
+    writer.write_record(columns.iter().map(|column| &column.field))?;
+
+    let mut written = 0;
+    for row in rows {
+        writer.write_record(row?.into_iter().map(|cell| match cell {
+            Cell::Null => String::new(),
+            Cell::Boolean(b) => b.to_string(),
+            Cell::Integer(i) => i.to_string(),
+            Cell::Number(f) => f.to_string(),
+            Cell::String(s) => s,
+        }))?;
+        written += 1;
+    }
+    writer.flush()?;
+
+    Ok(written)
+}
+
+fn write_parquet<W: std::io::Write + Send>(
+    columns: &[Column],
+    rows: impl Iterator<Item = anyhow::Result<Row>>,
+    w: W,
+) -> anyhow::Result<usize> {
+    use parquet::basic::{LogicalType, Repetition, Type as PhysicalType};
+    use parquet::data_type::{BoolType, ByteArray, ByteArrayType, DoubleType, Int64Type};
+    use parquet::schema::types::Type;
+
+    let fields = columns
+        .iter()
+        .map(|column| {
+            let (physical, logical) = match column.type_ {
+                ColumnType::Boolean => (PhysicalType::BOOLEAN, None),
+                ColumnType::Integer => (PhysicalType::INT64, None),
+                ColumnType::Number => (PhysicalType::DOUBLE, None),
+                ColumnType::String => (PhysicalType::BYTE_ARRAY, Some(LogicalType::String)),
+            };
+            let repetition = if column.nullable {
+                Repetition::OPTIONAL
+            } else {
+                Repetition::REQUIRED
+            };
+            Ok(Arc::new(
+                Type::primitive_type_builder(&column.field, physical)
+                    .with_repetition(repetition)
+                    .with_logical_type(logical)
+                    .build()?,
+            ))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let schema = Type::group_type_builder("document")
+        .with_fields(fields)
+        .build()?;
+    let mut writer =
+        parquet::file::writer::SerializedFileWriter::new(w, Arc::new(schema), Default::default())?;
+
+    let mut written = 0;
+    let mut rows = rows.peekable();
+
+    while rows.peek().is_some() {
+        let group = rows
+            .by_ref()
+            .take(PARQUET_ROW_GROUP_SIZE)
+            .collect::<anyhow::Result<Vec<Row>>>()?;
+
+        let mut group_writer = writer.next_row_group()?;
+
+        for (index, column) in columns.iter().enumerate() {
+            let mut column_writer = group_writer
+                .next_column()?
+                .expect("schema has a column for each exported field");
+
+            let cells = group.iter().map(|row| &row[index]);
+            let def_levels: Vec<i16> = cells
+                .clone()
+                .map(|cell| if matches!(cell, Cell::Null) { 0 } else { 1 })
+                .collect();
+            let def_levels = column.nullable.then_some(def_levels.as_slice());
+
+            match column.type_ {
+                ColumnType::Boolean => {
+                    let values: Vec<bool> = cells
+                        .filter_map(|cell| match cell {
+                            Cell::Boolean(b) => Some(*b),
+                            _ => None,
+                        })
+                        .collect();
+                    column_writer
+                        .typed::<BoolType>()
+                        .write_batch(&values, def_levels, None)?;
+                }
+                ColumnType::Integer => {
+                    let values: Vec<i64> = cells
+                        .filter_map(|cell| match cell {
+                            Cell::Integer(i) => Some(Ok(*i)),
+                            Cell::Number(f) => Some(Err(integer_overflow(column, *f))),
+                            _ => None,
+                        })
+                        .collect::<anyhow::Result<_>>()?;
+                    column_writer
+                        .typed::<Int64Type>()
+                        .write_batch(&values, def_levels, None)?;
+                }
+                ColumnType::Number => {
+                    let values: Vec<f64> = cells
+                        .filter_map(|cell| match cell {
+                            Cell::Number(f) => Some(*f),
+                            _ => None,
+                        })
+                        .collect();
+                    column_writer
+                        .typed::<DoubleType>()
+                        .write_batch(&values, def_levels, None)?;
+                }
+                ColumnType::String => {
+                    let values: Vec<ByteArray> = cells
+                        .filter_map(|cell| match cell {
+                            Cell::String(s) => Some(ByteArray::from(s.as_str())),
+                            _ => None,
+                        })
+                        .collect();
+                    column_writer
+                        .typed::<ByteArrayType>()
+                        .write_batch(&values, def_levels, None)?;
+                }
+            }
+            column_writer.close()?;
+        }
+        group_writer.close()?;
+        written += group.len();
+    }
+    writer.close()?;
+
+    Ok(written)
+}
+
+fn write_avro<W: std::io::Write>(
+    columns: &[Column],
+    rows: impl Iterator<Item = anyhow::Result<Row>>,
+    w: W,
+) -> anyhow::Result<usize> {
+    use apache_avro::types::Value;
+
+    let names = avro_field_names(columns);
+
+    let fields: Vec<serde_json::Value> = columns
+        .iter()
+        .zip(names.iter())
+        .map(|(column, name)| {
+            let type_ = match column.type_ {
+                ColumnType::Boolean => "boolean",
+                ColumnType::Integer => "long",
+                ColumnType::Number => "double",
+                ColumnType::String => "string",
+            };
+            let type_ = if column.nullable {
+                serde_json::json!(["null", type_])
+            } else {
+                serde_json::json!(type_)
+            };
+            serde_json::json!({"name": name, "type": type_, "doc": column.field})
+        })
+        .collect();
+
+    let schema = apache_avro::Schema::parse(&serde_json::json!({
+        "type": "record",
+        "name": "document",
+        "fields": fields,
+    }))?;
+    let mut writer = apache_avro::Writer::new(&schema, w);
+
+    let mut written = 0;
+    for row in rows {
+        let record = row?
+            .into_iter()
+            .zip(columns.iter().zip(names.iter()))
+            .map(|(cell, (column, name))| {
+                let value = match cell {
+                    Cell::Null => Value::Null,
+                    Cell::Boolean(b) => Value::Boolean(b),
+                    Cell::Integer(i) => Value::Long(i),
+                    Cell::Number(f) if column.type_ == ColumnType::Integer => {
+                        return Err(integer_overflow(column, f));
+                    }
+                    Cell::Number(f) => Value::Double(f),
+                    Cell::String(s) => Value::String(s),
+                };
+                let value = match value {
+                    _ if !column.nullable => value,
+                    Value::Null => Value::Union(0, Box::new(Value::Null)),
+                    value => Value::Union(1, Box::new(value)),
+                };
+                Ok((name.clone(), value))
+            })
+            .collect::<anyhow::Result<_>>()?;
+
+        writer.append(Value::Record(record))?;
+        written += 1;
+    }
+    writer.into_inner()?.flush()?;
+
+    Ok(written)
+}
+
+// Parquet and Avro integer columns are 64-bit, and cannot hold a larger value
+// which fell back to a number. CSV exports have no such restriction.
+fn integer_overflow(column: &Column, value: f64) -> anyhow::Error {
+    anyhow::anyhow!(
+        "field {:?} value {value} exceeds the range of a 64-bit integer column (export as CSV, or bound the field with a `maximum`)",
+        column.field
+    )
+}
+
+// Map fields into unique names which satisfy Avro's name restrictions.
+fn avro_field_names(columns: &[Column]) -> Vec<String> {
+    let mut names: Vec<String> = Vec::with_capacity(columns.len());
+
+    for column in columns {
+        let mut base: String = column
+            .field
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        if !base.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+            base.insert(0, '_');
+        }
+
+        let mut name = base.clone();
+        for suffix in 1.. {
+            if !names.contains(&name) {
+                break;
+            }
+            name = format!("{base}_{suffix}");
+        }
+        names.push(name);
+    }
+    names
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn projection(
+        field: &str,
+        types: &[&str],
+        exists: flow::inference::Exists,
+        numeric: Option<flow::inference::Numeric>,
+    ) -> flow::Projection {
+        flow::Projection {
+            field: field.to_string(),
+            ptr: format!("/{field}"),
+            inference: Some(flow::Inference {
+                types: types.iter().map(|t| t.to_string()).collect(),
+                exists: exists as i32,
+                numeric,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn column(field: &str, type_: ColumnType, nullable: bool) -> Column {
+        Column {
+            field: field.to_string(),
+            type_,
+            nullable,
+        }
+    }
+
+    #[test]
+    fn test_column_from_projection() {
+        use flow::inference::Exists;
+
+        let huge = flow::inference::Numeric {
+            has_maximum: true,
+            maximum: 1e20,
+            ..Default::default()
+        };
+        let cases = [
+            projection("bool", &["boolean"], Exists::Must, None),
+            projection("int", &["integer"], Exists::Must, None),
+            projection("intOrNull", &["integer", "null"], Exists::Must, None),
+            projection("intMay", &["integer"], Exists::May, None),
+            projection("intHuge", &["integer"], Exists::Must, Some(huge)),
+            projection("num", &["integer", "number"], Exists::Must, None),
+            projection("str", &["string"], Exists::Must, None),
+            projection("mixed", &["integer", "string"], Exists::Must, None),
+            projection("obj", &["object"], Exists::Must, None),
+            flow::Projection {
+                field: "uninferred".to_string(),
+                ..Default::default()
+            },
+        ];
+
+        let columns: Vec<(String, ColumnType, bool)> = cases
+            .iter()
+            .map(Column::from_projection)
+            .map(|c| (c.field, c.type_, c.nullable))
+            .collect();
+
+        let expect = [
+            ("bool", ColumnType::Boolean, false),
+            ("int", ColumnType::Integer, false),
+            ("intOrNull", ColumnType::Integer, true),
+            ("intMay", ColumnType::Integer, true),
+            ("intHuge", ColumnType::Number, false),
+            ("num", ColumnType::Number, false),
+            ("str", ColumnType::String, false),
+            ("mixed", ColumnType::String, false),
+            ("obj", ColumnType::String, false),
+            ("uninferred", ColumnType::String, true),
+        ];
+        assert_eq!(
+            columns,
+            expect
+                .iter()
+                .map(|(f, t, n)| (f.to_string(), *t, *n))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_avro_field_names() {
+        let columns: Vec<Column> = ["id", "a-b", "a_b", "1st", "a-b", "_meta/op"]
+            .into_iter()
+            .map(|field| column(field, ColumnType::String, true))
+            .collect();
+
+        assert_eq!(
+            avro_field_names(&columns),
+            vec!["id", "a_b", "a_b_1", "_1st", "a_b_2", "_meta_op"],
+        );
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let columns = vec![
+            column("id", ColumnType::String, false),
+            column("flag", ColumnType::Boolean, true),
+            column("count", ColumnType::Integer, true),
+            column("ratio", ColumnType::Number, true),
+        ];
+        let rows = vec![
+            Ok(vec![
+                Cell::String("one, two".to_string()),
+                Cell::Boolean(true),
+                Cell::Integer(-42),
+                Cell::Number(1.5),
+            ]),
+            Ok(vec![
+                Cell::String("three".to_string()),
+                Cell::Null,
+                Cell::Null,
+                Cell::Null,
+            ]),
+        ];
+
+        let mut out = Vec::new();
+        let written = write_csv(&columns, rows.into_iter(), &mut out).unwrap();
+
+        assert_eq!(written, 2);
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "id,flag,count,ratio\n\"one, two\",true,-42,1.5\nthree,,,\n"
+        );
+
+        // An error of a row is returned.
+        let rows = vec![Err(anyhow::anyhow!("whoops"))];
+        let err = write_csv(&columns, rows.into_iter(), Vec::new()).unwrap_err();
+        assert_eq!(err.to_string(), "whoops");
+    }
+}
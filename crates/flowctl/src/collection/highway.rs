@@ -0,0 +1,228 @@
+// Portable implementation of the 64-bit HighwayHash, which the Flow runtime
+// uses to map packed collection keys to physical partitions.
+// See https://github.com/google/highwayhash for the reference implementation.
+
+const INIT_MUL0: [u64; 4] = [
+    0xdbe6d5d5fe4cce2f,
+    0xa4093822299f31d0,
+    0x13198a2e03707344,
+    0x243f6a8885a308d3,
+];
+const INIT_MUL1: [u64; 4] = [
+    0x3bd39e10cb0ef593,
+    0xc0acf169b5f18a8c,
+    0xbe5466cf34e90c6c,
+    0x452821e638d01377,
+];
+
+/// Compute the 64-bit HighwayHash of `data` under `key`.
+pub fn hash64(key: &[u64; 4], data: &[u8]) -> u64 {
+    let mut state = State::new(key);
+
+    let mut packets = data.chunks_exact(32);
+    for packet in &mut packets {
+        state.update_packet(packet);
+    }
+    let remainder = packets.remainder();
+    if !remainder.is_empty() {
+        state.update_remainder(remainder);
+    }
+    state.finalize64()
+}
+
+struct State {
+    v0: [u64; 4],
+    v1: [u64; 4],
+    mul0: [u64; 4],
+    mul1: [u64; 4],
+}
+
+impl State {
+    fn new(key: &[u64; 4]) -> Self {
+        let mut state = Self {
+            v0: [0; 4],
+            v1: [0; 4],
+            mul0: INIT_MUL0,
+            mul1: INIT_MUL1,
+        };
+        for i in 0..4 {
+            state.v0[i] = INIT_MUL0[i] ^ key[i];
+            state.v1[i] = INIT_MUL1[i] ^ key[i].rotate_left(32);
+        }
+        state
+    }
+
+    fn update(&mut self, lanes: [u64; 4]) {
+        for i in 0..4 {
+            self.v1[i] = self.v1[i].wrapping_add(self.mul0[i].wrapping_add(lanes[i]));
+            self.mul0[i] ^= (self.v1[i] & 0xffffffff).wrapping_mul(self.v0[i] >> 32);
+            self.v0[i] = self.v0[i].wrapping_add(self.mul1[i]);
+            self.mul1[i] ^= (self.v0[i] & 0xffffffff).wrapping_mul(self.v1[i] >> 32);
+        }
+        zipper_merge_and_add(self.v1[1], self.v1[0], &mut self.v0, 1, 0);
+        zipper_merge_and_add(self.v1[3], self.v1[2], &mut self.v0, 3, 2);
+        zipper_merge_and_add(self.v0[1], self.v0[0], &mut self.v1, 1, 0);
+        zipper_merge_and_add(self.v0[3], self.v0[2], &mut self.v1, 3, 2);
+    }
+
+    fn update_packet(&mut self, packet: &[u8]) {
+        let mut lanes = [0u64; 4];
+        for (lane, bytes) in lanes.iter_mut().zip(packet.chunks_exact(8)) {
+            *lane = u64::from_le_bytes(bytes.try_into().unwrap());
+        }
+        self.update(lanes);
+    }
+
+    fn update_remainder(&mut self, remainder: &[u8]) {
+        let size = remainder.len();
+        let size_mod4 = size & 3;
+        let tail = &remainder[size & !3..];
+
+        for v0 in self.v0.iter_mut() {
+            *v0 = v0.wrapping_add(((size as u64) << 32) + size as u64);
+        }
+        for v1 in self.v1.iter_mut() {
+            let lo = (*v1 as u32).rotate_left(size as u32);
+            let hi = ((*v1 >> 32) as u32).rotate_left(size as u32);
+            *v1 = lo as u64 | (hi as u64) << 32;
+        }
+
+        let mut packet = [0u8; 32];
+        packet[..size & !3].copy_from_slice(&remainder[..size & !3]);
+
+        if size & 16 != 0 {
+            // Copy the final four bytes, which may overlap with those already copied.
+            packet[28..].copy_from_slice(&remainder[size - 4..]);
+        } else if size_mod4 != 0 {
+            packet[16] = tail[0];
+            packet[17] = tail[size_mod4 >> 1];
+            packet[18] = tail[size_mod4 - 1];
+        }
+        self.update_packet(&packet);
+    }
+
+    fn finalize64(mut self) -> u64 {
+        for _ in 0..4 {
+            let v0 = self.v0;
+            self.update([
+                v0[2].rotate_left(32),
+                v0[3].rotate_left(32),
+                v0[0].rotate_left(32),
+                v0[1].rotate_left(32),
+            ]);
+        }
+        self.v0[0]
+            .wrapping_add(self.v1[0])
+            .wrapping_add(self.mul0[0])
+            .wrapping_add(self.mul1[0])
+    }
+}
+
+fn zipper_merge_and_add(v1: u64, v0: u64, add: &mut [u64; 4], i1: usize, i0: usize) {
+    add[i0] = add[i0].wrapping_add(
+        (((v0 & 0xff000000) | (v1 & 0xff00000000)) >> 24)
+            | (((v0 & 0xff0000000000) | (v1 & 0xff000000000000)) >> 16)
+            | (v0 & 0xff0000)
+            | ((v0 & 0xff00) << 32)
+            | ((v1 & 0xff00000000000000) >> 8)
+            | (v0 << 56),
+    );
+    add[i1] = add[i1].wrapping_add(
+        (((v1 & 0xff000000) | (v0 & 0xff00000000)) >> 24)
+            | (v1 & 0xff0000)
+            | ((v1 & 0xff0000000000) >> 16)
+            | ((v1 & 0xff00) << 24)
+            | ((v0 & 0xff000000000000) >> 8)
+            | ((v1 & 0xff) << 48)
+            | (v0 & 0xff00000000000000),
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::hash64;
+
+    #[test]
+    fn test_reference_vectors() {
+        // Key and expectations of the reference implementation's test vectors,
+        // which hash each prefix of the bytes 0..64.
+        let key = [
+            0x0706050403020100,
+            0x0f0e0d0c0b0a0908,
+            0x1716151413121110,
+            0x1f1e1d1c1b1a1918,
+        ];
+        let data: Vec<u8> = (0..64).collect();
+
+        let expect: [u64; 65] = [
+            0x907a56de22c26e53,
+            0x7eab43aac7cddd78,
+            0xb8d0569ab0b53d62,
+            0x5c6befab8a463d80,
+            0xf205a46893007eda,
+            0x2b8a1668e4a94541,
+            0xbd4ccc325befca6f,
+            0x4d02ae1738f59482,
+            0xe1205108e55f3171,
+            0x32d2644ec77a1584,
+            0xf6e10acdb103a90b,
+            0xc3bbf4615b415c15,
+            0x243cc2040063fa9c,
+            0xa89a58ce65e641ff,
+            0x24b031a348455a23,
+            0x40793f86a449f33b,
+            0xcfab3489f97eb832,
+            0x19fe67d2c8c5c0e2,
+            0x04dd90a69c565cc2,
+            0x75d9518e2371c504,
+            0x38ad9b1141d3dd16,
+            0x0264432ccd8a70e0,
+            0xa9db5a6288683390,
+            0xd7b05492003f028c,
+            0x205f615aea59e51e,
+            0xeee0c89621052884,
+            0x1bfc1a93a7284f4f,
+            0x512175b5b70da91d,
+            0xf71f8976a0a2c639,
+            0xae093fef1f84e3e7,
+            0x22ca92b01161860f,
+            0x9fc7007ccf035a68,
+            0xa0c964d9ecd580fc,
+            0x2c90f73ca03181fc,
+            0x185cf84e5691eb9e,
+            0x4fc1f5ef2752aa9b,
+            0xf5b7391a5e0a33eb,
+            0xb9b84b83b4e96c9c,
+            0x5e42fe712a5cd9b4,
+            0xa150f2f90c3f97dc,
+            0x7fa522d75e2d637d,
+            0x181ad0cc0dffd32b,
+            0x3889ed981e854028,
+            0xfb4297e8c586ee2d,
+            0x6d064a45bb28059c,
+            0x90563609b3ec860c,
+            0x7aa4fce94097c666,
+            0x1326bac06b911e08,
+            0xb926168d2b154f34,
+            0x9919848945b1948d,
+            0xa2a98fc534825ebe,
+            0xe9809095213ef0b6,
+            0x582e5483707bc0e9,
+            0x086e9414a88a6af5,
+            0xee86b98d20f6743d,
+            0xf89b7ff609b1c0a7,
+            0x4c7d9cc19e22c3e8,
+            0x9a97005024562a6f,
+            0x5dd41cf423e6ebef,
+            0xdf13609c0468e227,
+            0x6e0da4f64188155a,
+            0xb755ba4b50d7d4a1,
+            0x887a3484647479bd,
+            0xab8eebe9bf2139a0,
+            0x75542c5d4cd2a6ff,
+        ];
+        for (len, expect) in expect.into_iter().enumerate() {
+            assert_eq!(hash64(&key, &data[..len]), expect, "length {len}");
+        }
+    }
+}
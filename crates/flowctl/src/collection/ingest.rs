@@ -0,0 +1,412 @@
+use crate::collection::CollectionJournalSelector;
+use anyhow::Context;
+use proto_flow::flow;
+use proto_gazette::{broker, message_flags, uuid};
+use std::collections::BTreeMap;
+use tokio::io::AsyncBufReadExt;
+
+#[derive(clap::Args, Debug)]
+pub struct IngestArgs {
+    /// The full name of the Flow collection.
+    #[clap(long)]
+    pub collection: String,
+    /// Path to a file of newline-delimited JSON documents to ingest.
+    /// May be provided multiple times. If not provided, documents are read from stdin.
+    #[clap(long)]
+    pub file: Vec<std::path::PathBuf>,
+    /// Number of documents to append in each transaction.
+    #[clap(long, default_value = "1000")]
+    pub batch_size: usize,
+    /// Validate documents and map them to collection journals, but don't append them.
+    #[clap(long)]
+    pub dry_run: bool,
+    /// Number of invalid documents to skip before failing the ingest.
+    /// By default, any invalid document fails the ingest.
+    #[clap(long, default_value = "0")]
+    pub max_errors: usize,
+}
+
+// Fixed key of the HighwayHash which maps packed collection keys to physical partitions.
+// It must match the key used by the Flow runtime.
+const HIGHWAY_HASH_KEY: [u64; 4] = [
+    0xd4385215897e73ba,
+    0x254dad5ac367807d,
+    0x017e2288341cddec,
+    0xbad32b020c48fa1f,
+];
+
+/// Ingest newline-delimited JSON documents into a collection.
+/// Documents are validated against the collection's write schema and mapped to
+/// the journal of their logical and physical partition. Each batch is appended
+/// as a transaction of each journal, followed by an acknowledgement which
+/// commits the transaction to readers.
+pub async fn ingest_collection(
+    ctx: &mut crate::CliContext,
+    IngestArgs {
+        collection,
+        file,
+        batch_size,
+        dry_run,
+        max_errors,
+    }: &IngestArgs,
+) -> anyhow::Result<()> {
+    anyhow::ensure!(*batch_size != 0, "--batch-size must be greater than zero");
+
+    let spec = super::fetch_built_spec(&ctx.client, collection).await?;
+
+    let (journal_name_prefix, journal_client) =
+        flow_client::fetch_collection_authorization(&ctx.client, collection).await?;
+
+    let selector = CollectionJournalSelector {
+        collection: collection.clone(),
+        partitions: None,
+    };
+    let list_resp = journal_client
+        .list(broker::ListRequest {
+            selector: Some(selector.build_label_selector(journal_name_prefix)),
+            ..Default::default()
+        })
+        .await
+        .context("listing journals for collection ingest")?;
+
+    let mut mapper = Mapper::new(&spec, list_resp.journals)?;
+    let mut appenders: BTreeMap<String, gazette::journal::AppendJsonLines> = BTreeMap::new();
+
+    // Documents of an ingest are written by a unique producer,
+    // having the multicast bit set as is required of random producer IDs.
+    let mut producer = [0u8; 6];
+    producer.copy_from_slice(&::uuid::Uuid::new_v4().as_bytes()[..6]);
+    producer[0] |= 0x01;
+    let producer = uuid::Producer::from_bytes(producer);
+    let mut clock = uuid::Clock::from_time(std::time::SystemTime::now());
+
+    let uuid_ptr = doc::Pointer::from_str(&spec.uuid_ptr);
+    let ack_template: serde_json::Value =
+        serde_json::from_str(&spec.ack_template_json).context("parsing collection ACK template")?;
+
+    let mut validator =
+        doc::Validator::new(doc::validation::build_bundle(&spec.write_schema_json)?)?;
+
+    let (mut ingested, mut invalid, mut pending) = (0, 0, 0);
+
+    let mut lines = InputLines::new(file);
+    while let Some((source, line_number, line)) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let mapped = serde_json::from_str::<serde_json::Value>(&line)
+            .context("invalid JSON")
+            .and_then(|doc| {
+                validator.validate(None, &doc)?.ok()?;
+                let journal = mapper.map(&doc)?;
+                Ok((journal, doc))
+            });
+
+        let (journal, mut doc) = match mapped {
+            Ok(mapped) => mapped,
+            Err(err) => {
+                invalid += 1;
+                tracing::warn!(%source, line_number, error = ?err, "skipping invalid document");
+
+                if invalid > *max_errors {
+                    return Err(err.context(format!(
+                        "document at {source}:{line_number} is invalid ({invalid} invalid documents exceeds --max-errors {max_errors})"
+                    )));
+                }
+                continue;
+            }
+        };
+
+        clock.tick();
+        *uuid_ptr
+            .create_value(&mut doc)
+            .context("unable to place document UUID")? = serde_json::json!(uuid::build(
+            producer,
+            clock,
+            uuid::Flags(message_flags::CONTINUE_TXN as u16)
+        )
+        .as_hyphenated());
+
+        let appender = appenders.entry(journal.clone()).or_insert_with(|| {
+            journal_client
+                .clone()
+                .append_json_lines(broker::AppendRequest {
+                    journal,
+                    ..Default::default()
+                })
+        });
+        appender.push(&doc)?;
+        pending += 1;
+
+        if pending == *batch_size {
+            commit(
+                &mut appenders,
+                producer,
+                &mut clock,
+                &uuid_ptr,
+                &ack_template,
+                *dry_run,
+            )
+            .await?;
+            ingested += pending;
+            pending = 0;
+        }
+    }
+    commit(
+        &mut appenders,
+        producer,
+        &mut clock,
+        &uuid_ptr,
+        &ack_template,
+        *dry_run,
+    )
+    .await?;
+    ingested += pending;
+
+    if *dry_run {
+        println!("Validated {ingested} documents ({invalid} invalid documents skipped). This was a dry run, and nothing was appended.");
+    } else {
+        println!("Ingested {ingested} documents ({invalid} invalid documents skipped).");
+    }
+    Ok(())
+}
+
+// Commit buffered documents of each journal by appending them with a trailing ACK.
+// If `dry_run`, then buffered documents are instead discarded.
+async fn commit(
+    appenders: &mut BTreeMap<String, gazette::journal::AppendJsonLines>,
+    producer: uuid::Producer,
+    clock: &mut uuid::Clock,
+    uuid_ptr: &doc::Pointer,
+    ack_template: &serde_json::Value,
+    dry_run: bool,
+) -> anyhow::Result<()> {
+    if dry_run {
+        appenders.clear();
+        return Ok(());
+    }
+
+    for (journal, appender) in appenders.iter_mut() {
+        if appender.is_empty() {
+            continue;
+        }
+        clock.tick();
+
+        let mut ack = ack_template.clone();
+        *uuid_ptr
+            .create_value(&mut ack)
+            .context("unable to place ACK UUID")? = serde_json::json!(uuid::build(
+            producer,
+            *clock,
+            uuid::Flags(message_flags::ACK_TXN as u16)
+        )
+        .as_hyphenated());
+        appender.push(&ack)?;
+
+        let resp = appender
+            .flush()
+            .await
+            .with_context(|| format!("appending to journal {journal}"))?;
+
+        tracing::debug!(%journal, resp=?ops::DebugJson(resp), "appended transaction");
+    }
+    Ok(())
+}
+
+// Mapper maps documents to the journals of their physical partitions.
+struct Mapper {
+    key: Vec<doc::Extractor>,
+    partitions: Vec<doc::Extractor>,
+    partition_fields: Vec<String>,
+    // Journals indexed on their logical partition field labels,
+    // with their key ranges and names.
+    journals: BTreeMap<Vec<broker::Label>, Vec<(u32, u32, String)>>,
+    buf: bytes::BytesMut,
+}
+
+impl Mapper {
+    fn new(
+        spec: &flow::CollectionSpec,
+        journals: Vec<broker::list_response::Journal>,
+    ) -> anyhow::Result<Self> {
+        let ser_policy = doc::SerPolicy::noop();
+        let key = extractors::for_key(&spec.key, &spec.projections, &ser_policy)?;
+        let partitions =
+            extractors::for_fields(&spec.partition_fields, &spec.projections, &ser_policy)?;
+
+        let mut index: BTreeMap<_, Vec<_>> = BTreeMap::new();
+
+        for journal in journals {
+            let Some(broker::JournalSpec {
+                name,
+                labels: Some(set),
+                ..
+            }) = journal.spec
+            else {
+                continue;
+            };
+            let (key_begin, key_end) = labels::partition::decode_key_range(&set)?;
+
+            index
+                .entry(field_labels(set))
+                .or_default()
+                .push((key_begin, key_end, name));
+        }
+
+        Ok(Self {
+            key,
+            partitions,
+            partition_fields: spec.partition_fields.clone(),
+            journals: index,
+            buf: Default::default(),
+        })
+    }
+
+    fn map(&mut self, doc: &serde_json::Value) -> anyhow::Result<String> {
+        let set = labels::partition::encode_field_range(
+            Default::default(),
+            0,
+            u32::MAX,
+            &self.partition_fields,
+            &self.partitions,
+            doc,
+        )?;
+        let logical = field_labels(set);
+
+        let packed = doc::Extractor::extract_all(doc, &self.key, &mut self.buf);
+        let key_hash = packed_key_hash(&packed);
+
+        let Some(journals) = self.journals.get(&logical) else {
+            anyhow::bail!(
+                "document's logical partition {} has no journal (ingest cannot create new logical partitions)",
+                logical
+                    .iter()
+                    .map(|label| format!("{}={}", &label.name[labels::FIELD_PREFIX.len()..], label.value))
+                    .collect::<Vec<_>>()
+                    .join("/")
+            );
+        };
+
+        journals
+            .iter()
+            .find(|(key_begin, key_end, _name)| *key_begin <= key_hash && key_hash <= *key_end)
+            .map(|(_, _, name)| name.clone())
+            .with_context(|| {
+                format!("no physical partition covers document key hash {key_hash:08x}")
+            })
+    }
+}
+
+// Retain only the logical partition field labels of the LabelSet.
+fn field_labels(set: broker::LabelSet) -> Vec<broker::Label> {
+    set.labels
+        .into_iter()
+        .filter(|label| label.name.starts_with(labels::FIELD_PREFIX))
+        .collect()
+}
+
+// Hash a packed key into its position within the key range of physical partitions.
+fn packed_key_hash(packed: &[u8]) -> u32 {
+    (super::highway::hash64(&HIGHWAY_HASH_KEY, packed) >> 32) as u32
+}
+
+// Lines of input, tagged with their source and line number.
+struct InputLines {
+    files: std::vec::IntoIter<std::path::PathBuf>,
+    current: Option<(
+        String,
+        usize,
+        Box<dyn tokio::io::AsyncBufRead + Unpin + Send>,
+    )>,
+}
+
+impl InputLines {
+    // Build InputLines which read `files` in order, or stdin if there are no files.
+    fn new(files: &[std::path::PathBuf]) -> Self {
+        let mut lines = Self {
+            files: files.to_vec().into_iter(),
+            current: None,
+        };
+        if files.is_empty() {
+            lines.current = Some((
+                "stdin".to_string(),
+                0,
+                Box::new(tokio::io::BufReader::new(tokio::io::stdin())),
+            ));
+        }
+        lines
+    }
+
+    async fn next_line(&mut self) -> anyhow::Result<Option<(String, usize, String)>> {
+        loop {
+            let Some((source, line_number, reader)) = &mut self.current else {
+                let Some(path) = self.files.next() else {
+                    return Ok(None);
+                };
+                let file = tokio::fs::File::open(&path)
+                    .await
+                    .with_context(|| format!("opening {}", path.display()))?;
+
+                self.current = Some((
+                    path.display().to_string(),
+                    0,
+                    Box::new(tokio::io::BufReader::new(file)),
+                ));
+                continue;
+            };
+
+            let mut line = String::new();
+            if reader
+                .read_line(&mut line)
+                .await
+                .with_context(|| format!("reading {source}"))?
+                == 0
+            {
+                self.current = None;
+                continue;
+            }
+            *line_number += 1;
+
+            return Ok(Some((source.clone(), *line_number, line)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::packed_key_hash;
+    use serde_json::json;
+
+    #[test]
+    fn test_packed_key_hash_matches_runtime() {
+        // Expectations are those of the Flow runtime's mapping of packed keys.
+        let cases = [
+            (json!([true]), 0xb9f08d38),
+            (json!([false]), 0x1505e3cb),
+            (json!(["foo", "bar"]), 0x6ae719f3),
+            (json!(["foobar"]), 0x8adddd61),
+            (json!(["foobas"]), 0x7273e587),
+            (json!(["1"]), 0xf4ec4d33),
+            (json!(["2"]), 0x1e023d95),
+            (json!(["3"]), 0x38a34efe),
+            (json!(["10"]), 0x17751bae),
+            (json!(["11"]), 0x87d93806),
+            (json!([1]), 0x3c90c1d9),
+            (json!([2]), 0x97901bac),
+            (json!([3]), 0xcbc7f1e2),
+            (json!([10]), 0xd1d3f3eb),
+        ];
+        let policy = doc::SerPolicy::noop();
+        let mut buf = bytes::BytesMut::new();
+
+        for (doc, expect) in cases {
+            let key: Vec<_> = (0..doc.as_array().unwrap().len())
+                .map(|i| doc::Extractor::new(&format!("/{i}"), &policy))
+                .collect();
+            let packed = doc::Extractor::extract_all(&doc, &key, &mut buf);
+
+            assert_eq!(packed_key_hash(&packed), expect, "{doc}");
+        }
+    }
+}
@@ -1,3 +1,6 @@
+pub mod export;
+mod highway;
+pub mod ingest;
 pub mod read;
 
 use crate::Timestamp;
@@ -8,6 +11,8 @@ use time::OffsetDateTime;
 
 use crate::output::{to_table_row, CliOutput, JsonCell};
 
+use self::export::ExportArgs;
+use self::ingest::IngestArgs;
 use self::read::ReadArgs;
 
 /// Selector of collection journals, which is used for reads, journal and fragment listings, etc.
@@ -55,6 +60,18 @@ pub struct Collections {
 pub enum Command {
     /// Read data from a Flow collection and output to stdout.
     Read(ReadArgs),
+    /// Ingest newline-delimited JSON documents from stdin or files into a Flow collection.
+    ///
+    /// Documents are validated against the collection's write schema,
+    /// and are appended to the journals of their partitions in transactions
+    /// which are committed to readers. New logical partitions are not created.
+    Ingest(IngestArgs),
+    /// Export documents of a Flow collection into a Parquet, CSV or Avro file.
+    ///
+    /// Documents written within the `--since` and `--until` time range are reduced
+    /// by the collection key using its reduction annotations, and are written in key order.
+    /// Columns of the file are derived from the collection's projections and their inferred types.
+    Export(ExportArgs),
     /// List the individual journals of a flow collection
     ListJournals(CollectionJournalSelector),
     /// List the journal fragments of a flow collection
@@ -65,12 +82,37 @@ impl Collections {
     pub async fn run(&self, ctx: &mut crate::CliContext) -> Result<(), anyhow::Error> {
         match &self.cmd {
             Command::Read(args) => do_read(ctx, args).await,
+            Command::Ingest(args) => ingest::ingest_collection(ctx, args).await,
+            Command::Export(args) => export::export_collection(ctx, args).await,
             Command::ListJournals(selector) => do_list_journals(ctx, selector).await,
             Command::ListFragments(args) => do_list_fragments(ctx, args).await,
         }
     }
 }
 
+/// Fetch the built specification of a live collection.
+async fn fetch_built_spec(
+    client: &crate::Client,
+    collection: &str,
+) -> anyhow::Result<flow::CollectionSpec> {
+    #[derive(serde::Deserialize)]
+    struct Row {
+        built_spec: models::RawValue,
+    }
+    let Row { built_spec } = crate::api_exec(
+        client
+            .from("live_specs_ext")
+            .eq("catalog_name", collection)
+            .eq("spec_type", "collection")
+            .select("built_spec")
+            .single(),
+    )
+    .await
+    .with_context(|| format!("fetching built specification of collection {collection}"))?;
+
+    serde_json::from_str(built_spec.get()).context("parsing built collection specification")
+}
+
 async fn do_read(ctx: &mut crate::CliContext, args: &ReadArgs) -> Result<(), anyhow::Error> {
     tracing::debug!(?args, "executing read");
     read::read_collection(ctx, args).await?;
@@ -13,12 +13,20 @@ pub struct ReadArgs {
     #[clap(flatten)]
     pub bounds: ReadBounds,
     /// Read all journal data, including messages from transactions which were
-    /// rolled back or never committed. Due to the current limitations of the Rust
-    /// Gazette client library, this is the only mode that's currently supported,
-    /// and this flag must be provided. In the future, committed reads will become
-    /// the default.
+    /// rolled back or never committed, as well as transaction acknowledgements.
+    /// By default, only committed documents are read.
     #[clap(long)]
     pub uncommitted: bool,
+    /// Output documents of all selected journals in the order of their UUID clocks.
+    /// By default, documents are output in the order they're read, and documents
+    /// of different journals are interleaved. Ordering requires that every journal
+    /// is read to its end, and thus cannot be used with `--follow`.
+    #[clap(long, conflicts_with = "follow")]
+    pub ordered: bool,
+    /// Tag each document with the name of the journal it was read from.
+    /// Documents are then output as `{"journal": "<name>", "doc": <document>}`.
+    #[clap(long)]
+    pub tag_journal: bool,
 }
 
 /// Common definition for arguments specifying the begin and and bounds of a read command.
@@ -34,22 +42,22 @@ pub struct ReadBounds {
     pub since: Option<humantime::Duration>,
 }
 
-/// Reads collection data and prints it to stdout. This function has a number of limitations at present:
-/// - The provided `CollectionJournalSelector` must select a single journal.
-/// - Only uncommitted reads are supported
-/// - Any acknowledgements (documents with `/_meta/ack` value `true`) are also printed
-/// These limitations should all be addressed in the future when we add support for committed reads.
+// Delay before a read or listing is retried after a transient error.
+pub(super) const RETRY_BACKOFF: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Reads collection data from all journals matched by the `CollectionJournalSelector`,
+/// and prints it to stdout. When following, journals of newly-created partitions
+/// are read as they're discovered.
 pub async fn read_collection(
     ctx: &mut crate::CliContext,
     ReadArgs {
         selector,
         bounds,
         uncommitted,
+        ordered,
+        tag_journal,
     }: &ReadArgs,
 ) -> anyhow::Result<()> {
-    if !uncommitted {
-        anyhow::bail!("missing the `--uncommitted` flag. This flag is currently required, though a future release will add support for committed reads, which will be the default.");
-    }
     // output can be either None or Some(OutputType::Json), but cannot be explicitly set to
     // anything else. _Eventually_, we may want to support outputting collection data as yaml
     // or a table, but certainly not right now.
@@ -65,42 +73,201 @@ pub async fn read_collection(
     let (journal_name_prefix, journal_client) =
         flow_client::fetch_collection_authorization(&ctx.client, &selector.collection).await?;
 
+    let list_req = broker::ListRequest {
+        selector: Some(selector.build_label_selector(journal_name_prefix)),
+        ..Default::default()
+    };
+    let mut output = Output::new(*tag_journal);
+
+    if bounds.follow {
+        let listings = journal_client.clone().list_watch(broker::ListRequest {
+            watch: true,
+            ..list_req
+        });
+        return read_interleaved(journal_client, listings, bounds, *uncommitted, &mut output).await;
+    }
+
     let list_resp = journal_client
-        .list(broker::ListRequest {
-            selector: Some(selector.build_label_selector(journal_name_prefix)),
-            ..Default::default()
-        })
+        .list(list_req)
         .await
         .context("listing journals for collection read")?;
 
-    let mut journals = list_resp
-        .journals
-        .into_iter()
-        .map(|j| j.spec.unwrap())
-        .collect::<Vec<_>>();
-
-    tracing::debug!(journal_count = journals.len(), collection = %selector.collection, "listed journals");
-    let maybe_journal = journals.pop();
-    if !journals.is_empty() {
-        // TODO: implement a sequencer and allow reading from multiple journals
-        anyhow::bail!("flowctl is not yet able to read from partitioned collections (coming soon)");
-    }
+    tracing::debug!(journal_count = list_resp.journals.len(), collection = %selector.collection, "listed journals");
 
-    let journal = maybe_journal.ok_or_else(|| {
-        anyhow::anyhow!(
+    if list_resp.journals.is_empty() {
+        anyhow::bail!(
             "collection '{}' does not exist or has never been written to (it has no journals)",
             selector.collection
-        )
-    })?;
+        );
+    }
+
+    if *ordered {
+        let journals = list_resp
+            .journals
+            .into_iter()
+            .map(|j| j.spec.unwrap().name)
+            .collect();
 
-    read_collection_journal(journal_client, &journal.name, bounds).await
+        read_ordered(journal_client, journals, bounds, *uncommitted, &mut output).await
+    } else {
+        let listings = futures::stream::once(async move { Ok(list_resp) });
+        read_interleaved(journal_client, listings, bounds, *uncommitted, &mut output).await
+    }
 }
 
+/// Reads a collection journal and prints its documents to stdout.
+/// Unless `uncommitted`, only committed documents are printed and
+/// transaction acknowledgements are omitted.
 pub async fn read_collection_journal(
     journal_client: gazette::journal::Client,
     journal_name: &str,
     bounds: &ReadBounds,
+    uncommitted: bool,
+) -> anyhow::Result<()> {
+    let listings = futures::stream::once(async move {
+        Ok(broker::ListResponse {
+            journals: vec![broker::list_response::Journal {
+                spec: Some(broker::JournalSpec {
+                    name: journal_name.to_string(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }],
+            ..Default::default()
+        })
+    });
+    read_interleaved(
+        journal_client,
+        listings,
+        bounds,
+        uncommitted,
+        &mut Output::new(false),
+    )
+    .await
+}
+
+/// Read all journals of `listings` as they're discovered, and output documents
+/// across all journals in the order they're read.
+async fn read_interleaved(
+    journal_client: gazette::journal::Client,
+    listings: impl futures::Stream<Item = gazette::Result<broker::ListResponse>>,
+    bounds: &ReadBounds,
+    uncommitted: bool,
+    output: &mut Output,
+) -> anyhow::Result<()> {
+    // Back off from transient listing errors within the listings stream,
+    // so that ongoing journal reads aren't stalled while it retries.
+    let listings = listings
+        .filter_map(|listing| async move {
+            match listing {
+                Err(err) if err.is_transient() => {
+                    tracing::warn!(%err, "error listing collection journals (will retry)");
+                    tokio::time::sleep(RETRY_BACKOFF).await;
+                    None
+                }
+                listing => Some(listing),
+            }
+        })
+        .fuse();
+    tokio::pin!(listings);
+
+    let mut started = std::collections::HashSet::new();
+    let mut reads = futures::stream::SelectAll::new();
+
+    loop {
+        tokio::select! {
+            Some(listing) = listings.next() => match listing {
+                Ok(resp) => {
+                    for journal in resp.journals {
+                        let name = journal.spec.unwrap().name;
+
+                        if started.insert(name.clone()) {
+                            let lines = read_journal(journal_client.clone(), &name, bounds, uncommitted);
+                            let name = std::rc::Rc::<str>::from(name);
+                            reads.push(lines.map(move |line| (name.clone(), line)).boxed_local());
+                        }
+                    }
+                }
+                Err(err) => return Err(err).context("listing journals for collection read"),
+            },
+            Some((journal, line)) = reads.next() => match line {
+                Ok(ReadJsonLine::Meta(_)) => (),
+                Ok(ReadJsonLine::Doc {
+                    root,
+                    next_offset: _,
+                }) => output.write(&journal, root.get())?,
+                Err(err) => return Err(err).with_context(|| format!("reading journal {journal}")),
+            },
+            else => break, // All listings and reads have completed.
+        }
+    }
+
+    Ok(())
+}
+
+/// Read all `journals` to their ends, and output documents across all journals
+/// in the order of their UUID clocks.
+async fn read_ordered(
+    journal_client: gazette::journal::Client,
+    journals: Vec<String>,
+    bounds: &ReadBounds,
+    uncommitted: bool,
+    output: &mut Output,
 ) -> anyhow::Result<()> {
+    let uuid_ptr = doc::Pointer::from_str("/_meta/uuid");
+
+    // Each journal read, and its next document (if any) and its UUID clock.
+    let mut reads = journals
+        .into_iter()
+        .map(|journal| {
+            let lines = read_journal(journal_client.clone(), &journal, bounds, uncommitted);
+            (journal, lines.fuse(), None)
+        })
+        .collect::<Vec<_>>();
+
+    loop {
+        // Ensure every journal which hasn't ended has a next document.
+        for (journal, lines, head) in reads.iter_mut() {
+            while head.is_none() {
+                match lines.next().await {
+                    None => break, // Journal has been read to its end.
+                    Some(Ok(ReadJsonLine::Meta(_))) => (),
+                    Some(Ok(ReadJsonLine::Doc { root, next_offset })) => {
+                        let clock = parse_clock(&uuid_ptr, root.get()).with_context(|| {
+                            format!("document of journal {journal} ending at offset {next_offset} is missing a valid UUID")
+                        })?;
+                        *head = Some((clock, root));
+                    }
+                    Some(Err(err)) => {
+                        return Err(err).with_context(|| format!("reading journal {journal}"))
+                    }
+                }
+            }
+        }
+
+        // Output the next document having the least clock.
+        let Some((journal, _lines, head)) = reads
+            .iter_mut()
+            .filter(|(_journal, _lines, head)| head.is_some())
+            .min_by_key(|(_journal, _lines, head)| head.as_ref().unwrap().0)
+        else {
+            return Ok(()); // All journals have been read to their ends.
+        };
+        let (_clock, root) = head.take().unwrap();
+        output.write(journal, root.get())?;
+    }
+}
+
+/// Start a read of the journal, which ends upon reaching the journal's
+/// current write head unless `bounds.follow` is set.
+/// Transient errors are logged and retried after a backoff which delays
+/// only this journal's read, and aren't yielded by the returned stream.
+fn read_journal(
+    journal_client: gazette::journal::Client,
+    journal_name: &str,
+    bounds: &ReadBounds,
+    uncommitted: bool,
+) -> futures::stream::LocalBoxStream<'static, gazette::Result<ReadJsonLine>> {
     let begin_mod_time = if let Some(since) = bounds.since {
         let start_time = OffsetDateTime::now_utc() - *since;
         tracing::debug!(%since, begin_mod_time = %start_time, "resolved --since to begin_mod_time");
@@ -109,42 +276,87 @@ pub async fn read_collection_journal(
         0
     };
 
-    let mut lines = journal_client.read_json_lines(
-        broker::ReadRequest {
-            journal: journal_name.to_string(),
-            offset: 0,
-            block: bounds.follow,
-            begin_mod_time,
-            ..Default::default()
-        },
-        1,
-    );
-    tracing::debug!(%journal_name, "starting read of journal");
-
-    let policy = doc::SerPolicy::noop();
-    let mut stdout = std::io::stdout();
-
-    while let Some(line) = lines.next().await {
-        match line {
-            Ok(ReadJsonLine::Meta(_)) => (),
-            Ok(ReadJsonLine::Doc {
-                root,
-                next_offset: _,
-            }) => {
-                let mut v = serde_json::to_vec(&policy.on(root.get())).unwrap();
-                v.push(b'\n');
-                () = stdout.write_all(&v)?;
-            }
-            Err(gazette::Error::BrokerStatus(broker::Status::OffsetNotYetAvailable)) => {
-                break; // Graceful EOF of non-blocking read.
-            }
-            Err(err) if err.is_transient() => {
-                tracing::warn!(%err, "error reading collection (will retry)");
-                tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+    let req = broker::ReadRequest {
+        journal: journal_name.to_string(),
+        offset: 0,
+        block: bounds.follow,
+        begin_mod_time,
+        ..Default::default()
+    };
+    let lines = if uncommitted {
+        journal_client.read_json_lines(req, 1).boxed_local()
+    } else {
+        journal_client.read_committed(req, 1).boxed_local()
+    };
+    tracing::debug!(%journal_name, uncommitted, "starting read of journal");
+
+    let journal_name = std::rc::Rc::<str>::from(journal_name);
+
+    lines
+        .filter_map(move |line| {
+            let journal_name = journal_name.clone();
+            async move {
+                match line {
+                    Err(err) if err.is_transient() => {
+                        tracing::warn!(%err, journal = %journal_name, "error reading collection (will retry)");
+                        tokio::time::sleep(RETRY_BACKOFF).await;
+                        None
+                    }
+                    line => Some(line),
+                }
             }
-            Err(err) => anyhow::bail!(err),
+        })
+        .take_while(|line| {
+            futures::future::ready(!matches!(
+                line,
+                // Graceful EOF of non-blocking read.
+                Err(gazette::Error::BrokerStatus(
+                    broker::Status::OffsetNotYetAvailable
+                ))
+            ))
+        })
+        .boxed_local()
+}
+
+pub(super) fn parse_clock(
+    ptr: &doc::Pointer,
+    root: &doc::ArchivedNode,
+) -> Option<gazette::uuid::Clock> {
+    let doc::Node::String(uuid) = doc::AsNode::as_node(ptr.query(root)?) else {
+        return None;
+    };
+    let (_producer, clock, _flags) = gazette::uuid::parse_str(uuid).ok()?;
+    Some(clock)
+}
+
+/// Output writes documents to stdout as newline-delimited JSON.
+struct Output {
+    policy: doc::SerPolicy,
+    stdout: std::io::Stdout,
+    tag_journal: bool,
+}
+
+impl Output {
+    fn new(tag_journal: bool) -> Self {
+        Self {
+            policy: doc::SerPolicy::noop(),
+            stdout: std::io::stdout(),
+            tag_journal,
         }
     }
 
-    Ok(())
+    fn write(&mut self, journal: &str, root: &doc::ArchivedNode) -> anyhow::Result<()> {
+        let doc = self.policy.on(root);
+
+        let mut v = if self.tag_journal {
+            serde_json::to_vec(&serde_json::json!({"journal": journal, "doc": doc}))
+        } else {
+            serde_json::to_vec(&doc)
+        }
+        .unwrap();
+
+        v.push(b'\n');
+        () = self.stdout.write_all(&v)?;
+        Ok(())
+    }
 }
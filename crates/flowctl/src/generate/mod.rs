@@ -212,11 +212,14 @@ async fn generate_missing_collection_configs(
             },
             serde_json::from_str::<url::Url>(config.config.get()).ok(),
         ),
-        // TypeScript and SQLite always generate their own configs.
+        // TypeScript and SQLite always generate their own configs,
+        // WebAssembly modules are built outside of Flow,
+        // and mapping derivations have no configuration.
         // Other connectors may as well, and they'll override those generated here.
-        models::DeriveUsing::Sqlite(_) | models::DeriveUsing::Typescript(_) => {
-            return Ok(Vec::new())
-        }
+        models::DeriveUsing::Sqlite(_)
+        | models::DeriveUsing::Typescript(_)
+        | models::DeriveUsing::Wasm(_)
+        | models::DeriveUsing::Mapping(_) => return Ok(Vec::new()),
     };
     let missing_resource_urls: Vec<(url::Url, models::Collection)> = transforms
         .iter()
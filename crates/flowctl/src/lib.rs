@@ -14,6 +14,7 @@ mod output;
 mod poll;
 mod preview;
 mod raw;
+mod shards;
 
 use flow_client::client::refresh_authorizations;
 pub(crate) use flow_client::client::Client;
@@ -91,6 +92,12 @@ pub enum Command {
     Draft(draft::Draft),
     /// Read operational logs of your tasks (captures, derivations, and materializations).
     Logs(ops::Logs),
+    /// Inspect and restart the shards of your tasks.
+    ///
+    /// Each task runs as one or more shards, which are assigned to
+    /// members of the data-plane. A shard which encounters an error
+    /// is FAILED, and may be restarted once the cause is addressed.
+    Shards(shards::Shards),
     /// Advanced, low-level, and experimental commands which are less common.
     Raw(raw::Advanced),
 }
@@ -107,10 +114,19 @@ impl CliContext {
         T: output::CliOutput,
         I: IntoIterator<Item = T>,
     {
-        match self.get_output_type() {
+        let output_type = self.get_output_type();
+        let columns = &self.output.columns;
+
+        match output_type {
+            OutputType::Json | OutputType::Yaml if !columns.is_empty() => {
+                anyhow::bail!("--columns may only be used with table, csv, tsv, or jsonl output")
+            }
             OutputType::Json => output::print_json(items),
             OutputType::Yaml => output::print_yaml(items),
-            OutputType::Table => output::print_table(table_alt, items),
+            OutputType::Table => output::print_table(table_alt, items, columns),
+            OutputType::Csv => output::print_delimited(table_alt, items, columns, b','),
+            OutputType::Tsv => output::print_delimited(table_alt, items, columns, b'\t'),
+            OutputType::Jsonl => output::print_jsonl(table_alt, items, columns),
         }
     }
 
@@ -171,6 +187,7 @@ impl Cli {
             Command::Preview(preview) => preview.run(&mut context).await,
             Command::Draft(draft) => draft.run(&mut context).await,
             Command::Logs(logs) => logs.run(&mut context).await,
+            Command::Shards(shards) => shards.run(&mut context).await,
             Command::Raw(advanced) => advanced.run(&mut context).await,
         }?;
 
@@ -15,6 +15,17 @@ pub(crate) async fn load_and_validate(
     Ok((draft, surface_errors(built.into_result())?))
 }
 
+/// Load and validate sources and derivation connectors (only),
+/// without use of the control plane. Live specifications are not resolved,
+/// so sources must be self-contained.
+pub(crate) async fn load_and_validate_local(source: &str) -> anyhow::Result<tables::Validations> {
+    let source = build::arg_source_to_url(source, false)?;
+    let draft = surface_errors(load(&source).await.into_result())?;
+    let (_draft, built) =
+        validate_with(&build::NoOpCatalogResolver, true, false, true, draft, "").await;
+    surface_errors(built.into_result())
+}
+
 /// Load and validate sources and all connectors.
 pub(crate) async fn load_and_validate_full(
     client: &crate::Client,
@@ -73,15 +84,33 @@ async fn validate(
     noop_materializations: bool,
     draft: tables::DraftCatalog,
     network: &str,
+) -> (tables::DraftCatalog, tables::Validations) {
+    let resolver = Resolver {
+        client: client.clone(),
+    };
+    validate_with(
+        &resolver,
+        noop_captures,
+        noop_derivations,
+        noop_materializations,
+        draft,
+        network,
+    )
+    .await
+}
+
+async fn validate_with<R: tables::CatalogResolver>(
+    resolver: &R,
+    noop_captures: bool,
+    noop_derivations: bool,
+    noop_materializations: bool,
+    draft: tables::DraftCatalog,
+    network: &str,
 ) -> (tables::DraftCatalog, tables::Validations) {
     let source = &draft.fetches[0].resource.clone();
     let project_root = build::project_root(source);
 
-    let mut live = Resolver {
-        client: client.clone(),
-    }
-    .resolve(draft.all_catalog_names())
-    .await;
+    let mut live = resolver.resolve(draft.all_catalog_names()).await;
 
     let output = if !live.errors.is_empty() {
         // If there's a live catalog resolution error, surface it through built tables.
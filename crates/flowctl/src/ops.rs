@@ -7,6 +7,10 @@ pub struct Logs {
 
     #[clap(flatten)]
     pub bounds: ReadBounds,
+
+    /// Read all log documents, including uncommitted or rolled back transactions.
+    #[clap(long)]
+    pub uncommitted: bool,
 }
 
 /// Selects a Flow task.
@@ -24,6 +28,7 @@ impl Logs {
             &self.task.task,
             OpsCollection::Logs,
             &self.bounds,
+            self.uncommitted,
         )
         .await
     }
@@ -40,6 +45,7 @@ pub async fn read_task_ops_journal(
     task_name: &str,
     collection: OpsCollection,
     bounds: &ReadBounds,
+    uncommitted: bool,
 ) -> anyhow::Result<()> {
     let (_shard_id_prefix, ops_logs_journal, ops_stats_journal, _shard_client, journal_client) =
         flow_client::fetch_task_authorization(client, task_name).await?;
@@ -48,5 +54,11 @@ pub async fn read_task_ops_journal(
         OpsCollection::Logs => ops_logs_journal,
         OpsCollection::Stats => ops_stats_journal,
     };
-    crate::collection::read::read_collection_journal(journal_client, &journal_name, bounds).await
+    crate::collection::read::read_collection_journal(
+        journal_client,
+        &journal_name,
+        bounds,
+        uncommitted,
+    )
+    .await
 }
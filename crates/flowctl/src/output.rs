@@ -7,6 +7,11 @@ pub struct Output {
     /// How to format CLI output
     #[clap(global = true, short, long, value_enum)]
     pub output: Option<OutputType>,
+    /// Comma-separated table columns to output, in the given order.
+    /// Columns are matched to table headers without regard to case.
+    /// Applies only to table, CSV, TSV, and JSON Lines output
+    #[clap(global = true, long, value_delimiter = ',')]
+    pub columns: Vec<String>,
 }
 
 #[derive(clap::ValueEnum, Debug, Copy, Clone, PartialEq)]
@@ -17,19 +22,25 @@ pub enum OutputType {
     Yaml,
     /// Format the output as a pretty-printed table
     Table,
+    /// Format the output as comma-separated values, with a header row
+    Csv,
+    /// Format the output as tab-separated values, with a header row
+    Tsv,
+    /// Format the output as JSON Lines of table rows, preceded by a JSON schema of the rows
+    Jsonl,
 }
 
 /// A trait for things that can be output from the CLI as either JSON, YAML, or a table.
 /// The body of this trait is focused on table output, since JSON and YAML are both handled
-/// by `Serialize`.
+/// by `Serialize`. CSV, TSV, and JSON Lines output also use the table representation.
 pub trait CliOutput: Serialize {
     /// Allows threading through an alternate representation of table output. An example is
     /// the `--flows` option of `flowctl catalog list`, which adds additional columns to the table.
     /// `type TableAlt = ();` is used to opt out of having an alternative representation.
     type TableAlt: Copy;
-    /// The type output from `into_table_rows`. Common types are `String` and `JsonCell`, or
-    /// anything implementing `std::fmt::Display`.
-    type CellValue: Into<comfy_table::Cell>;
+    /// The type output from `into_table_rows`. Common types are `String` and `JsonCell`.
+    /// Cells are also converted into JSON values for CSV, TSV, and JSON Lines output.
+    type CellValue: Into<comfy_table::Cell> + Into<Value>;
 
     /// Returns the column headers of the table.
     fn table_headers(alt: Self::TableAlt) -> Vec<&'static str>;
@@ -60,14 +71,16 @@ pub fn print_json(items: impl IntoIterator<Item = impl CliOutput>) -> anyhow::Re
 pub fn print_table<T: CliOutput>(
     alt: T::TableAlt,
     items: impl IntoIterator<Item = T>,
+    columns: &[String],
 ) -> anyhow::Result<()> {
     let mut stdout = io::stdout().lock();
     let headers = T::table_headers(alt);
+    let indices = select_columns(&headers, columns)?;
 
-    let mut table = crate::new_table(headers);
+    let mut table = crate::new_table(indices.iter().map(|i| headers[*i]).collect());
 
     for item in items {
-        table.add_row(item.into_table_row(alt));
+        table.add_row(select_cells(item.into_table_row(alt), &indices));
     }
 
     for line in table.lines() {
@@ -77,6 +90,153 @@ pub fn print_table<T: CliOutput>(
     Ok(())
 }
 
+/// Prints items as delimited rows of their table representation, such as CSV
+/// or TSV, with a leading row of headers. Cells are quoted as required.
+pub fn print_delimited<T: CliOutput>(
+    alt: T::TableAlt,
+    items: impl IntoIterator<Item = T>,
+    columns: &[String],
+    delimiter: u8,
+) -> anyhow::Result<()> {
+    write_delimited(io::stdout().lock(), alt, items, columns, delimiter)
+}
+
+fn write_delimited<T: CliOutput>(
+    w: impl Write,
+    alt: T::TableAlt,
+    items: impl IntoIterator<Item = T>,
+    columns: &[String],
+    delimiter: u8,
+) -> anyhow::Result<()> {
+    let headers = T::table_headers(alt);
+    let indices = select_columns(&headers, columns)?;
+
+    let mut writer = csv::WriterBuilder::new()
+        .delimiter(delimiter)
+        .from_writer(w);
+
+    writer.write_record(indices.iter().map(|i| headers[*i]))?;
+
+    for item in items {
+        let row = select_values(item.into_table_row(alt), &indices);
+        writer.write_record(row.into_iter().map(|value| match value {
+            Value::Null => String::new(),
+            Value::String(s) => s,
+            other => other.to_string(),
+        }))?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Prints items as JSON Lines of their table representation. The first line
+/// is a JSON schema of the rows which follow, where each row is an array of
+/// cell values which are titled by their table header. Column types are those
+/// of the values of the column.
+pub fn print_jsonl<T: CliOutput>(
+    alt: T::TableAlt,
+    items: impl IntoIterator<Item = T>,
+    columns: &[String],
+) -> anyhow::Result<()> {
+    write_jsonl(io::stdout().lock(), alt, items, columns)
+}
+
+fn write_jsonl<T: CliOutput>(
+    mut w: impl Write,
+    alt: T::TableAlt,
+    items: impl IntoIterator<Item = T>,
+    columns: &[String],
+) -> anyhow::Result<()> {
+    use json::schema::types;
+
+    let headers = T::table_headers(alt);
+    let indices = select_columns(&headers, columns)?;
+
+    // Rows are gathered up-front, as the schema is derived from their values.
+    let rows: Vec<Vec<Value>> = items
+        .into_iter()
+        .map(|item| select_values(item.into_table_row(alt), &indices))
+        .collect();
+
+    let prefix_items = indices
+        .iter()
+        .enumerate()
+        .map(|(column, i)| {
+            let mut type_ = rows
+                .iter()
+                .map(|row| types::Set::for_value(&row[column]))
+                .fold(types::INVALID, |lhs, rhs| lhs | rhs);
+
+            // JSON schema has no "fractional" type, only "number".
+            if type_.overlaps(types::FRACTIONAL) {
+                type_ = type_ | types::INT_OR_FRAC;
+            }
+
+            if type_ == types::INVALID {
+                serde_json::json!({"title": headers[*i]})
+            } else {
+                serde_json::json!({"title": headers[*i], "type": type_})
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let schema = serde_json::json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "type": "array",
+        "prefixItems": prefix_items,
+        "items": false,
+    });
+    serde_json::to_writer(&mut w, &schema)?;
+    w.write_all(b"\n")?;
+
+    for row in rows {
+        serde_json::to_writer(&mut w, &row)?;
+        w.write_all(b"\n")?;
+    }
+    w.flush()?;
+    Ok(())
+}
+
+/// Returns the indices of `headers` for each of the selected `columns`,
+/// or all indices if no columns are selected.
+fn select_columns(headers: &[&str], columns: &[String]) -> anyhow::Result<Vec<usize>> {
+    if columns.is_empty() {
+        return Ok((0..headers.len()).collect());
+    }
+
+    columns
+        .iter()
+        .map(|column| {
+            headers
+                .iter()
+                .position(|header| header.eq_ignore_ascii_case(column.trim()))
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "unknown column {column:?} (available columns are: {})",
+                        headers.join(", ")
+                    )
+                })
+        })
+        .collect()
+}
+
+/// Converts a table row into cells of the selected column `indices`, in order.
+fn select_cells<C: Into<comfy_table::Cell>>(
+    row: Vec<C>,
+    indices: &[usize],
+) -> Vec<comfy_table::Cell> {
+    let row: Vec<comfy_table::Cell> = row.into_iter().map(Into::into).collect();
+
+    indices
+        .iter()
+        .map(|i| {
+            row.get(*i)
+                .cloned()
+                .unwrap_or_else(|| comfy_table::Cell::new(""))
+        })
+        .collect()
+}
+
 /// Converts an item implementing `Serialize` into a table row by extracting values
 /// using the given list of JSON `pointers`. This function is often used to implement
 /// `CliOutput::into_table_row`.
@@ -91,9 +251,25 @@ pub fn to_table_row<T: Serialize>(value: T, pointers: &[&str]) -> Vec<JsonCell>
     row
 }
 
+/// Converts a table row into JSON values of the selected column `indices`, in order.
+fn select_values<C: Into<Value>>(row: Vec<C>, indices: &[usize]) -> Vec<Value> {
+    let row: Vec<Value> = row.into_iter().map(Into::into).collect();
+
+    indices
+        .iter()
+        .map(|i| row.get(*i).cloned().unwrap_or(Value::Null))
+        .collect()
+}
+
 /// A wrapper around an `Option<Value>` to allow it to be converted into a table cell.
 pub struct JsonCell(pub Option<Value>);
 
+impl From<JsonCell> for Value {
+    fn from(cell: JsonCell) -> Value {
+        cell.0.unwrap_or(Value::Null)
+    }
+}
+
 impl Into<comfy_table::Cell> for JsonCell {
     fn into(self) -> comfy_table::Cell {
         match self.0 {
@@ -103,3 +279,138 @@ impl Into<comfy_table::Cell> for JsonCell {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[derive(Serialize)]
+    struct Row {
+        name: &'static str,
+        detail: Value,
+    }
+
+    impl CliOutput for Row {
+        type TableAlt = ();
+        type CellValue = JsonCell;
+
+        fn table_headers(_alt: Self::TableAlt) -> Vec<&'static str> {
+            vec!["Name", "Detail"]
+        }
+
+        fn into_table_row(self, _alt: Self::TableAlt) -> Vec<Self::CellValue> {
+            to_table_row(self, &["/name", "/detail"])
+        }
+    }
+
+    fn fixture() -> Vec<Row> {
+        vec![
+            Row {
+                name: "acmeCo/plain",
+                detail: json!("hello"),
+            },
+            Row {
+                name: "acmeCo/comma",
+                detail: json!("one, two"),
+            },
+            Row {
+                name: "acmeCo/quote",
+                detail: json!("say \"hi\""),
+            },
+            Row {
+                name: "acmeCo/newline",
+                detail: json!("line one\nline two"),
+            },
+            Row {
+                name: "acmeCo/object",
+                detail: json!({"a": [1, "b"]}),
+            },
+            Row {
+                name: "acmeCo/number",
+                detail: json!(1.5),
+            },
+            Row {
+                name: "acmeCo/null",
+                detail: Value::Null,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_select_columns() {
+        let headers = ["Name", "Type", "Updated"];
+
+        assert_eq!(select_columns(&headers, &[]).unwrap(), vec![0, 1, 2]);
+        assert_eq!(
+            select_columns(&headers, &["updated".to_string(), " NAME ".to_string()]).unwrap(),
+            vec![2, 0]
+        );
+        assert_eq!(
+            select_columns(&headers, &["type".to_string(), "type".to_string()]).unwrap(),
+            vec![1, 1]
+        );
+        assert_eq!(
+            select_columns(&headers, &["missing".to_string()])
+                .unwrap_err()
+                .to_string(),
+            "unknown column \"missing\" (available columns are: Name, Type, Updated)"
+        );
+    }
+
+    #[test]
+    fn test_delimited_quoting() {
+        let mut out = Vec::new();
+        write_delimited(&mut out, (), fixture(), &[], b',').unwrap();
+
+        insta::assert_snapshot!(String::from_utf8(out).unwrap(), @r###"
+        Name,Detail
+        acmeCo/plain,hello
+        acmeCo/comma,"one, two"
+        acmeCo/quote,"say ""hi"""
+        acmeCo/newline,"line one
+        line two"
+        acmeCo/object,"{""a"":[1,""b""]}"
+        acmeCo/number,1.5
+        acmeCo/null,
+        "###);
+
+        let mut out = Vec::new();
+        write_delimited(&mut out, (), fixture(), &["detail".to_string()], b'\t').unwrap();
+
+        insta::assert_snapshot!(String::from_utf8(out).unwrap(), @r###"
+        Detail
+        hello
+        one, two
+        "say ""hi"""
+        "line one
+        line two"
+        "{""a"":[1,""b""]}"
+        1.5
+        ""
+        "###);
+    }
+
+    #[test]
+    fn test_jsonl_with_schema() {
+        let mut out = Vec::new();
+        write_jsonl(
+            &mut out,
+            (),
+            fixture(),
+            &["detail".to_string(), "name".to_string()],
+        )
+        .unwrap();
+
+        insta::assert_snapshot!(String::from_utf8(out).unwrap(), @r###"
+        {"$schema":"https://json-schema.org/draft/2020-12/schema","items":false,"prefixItems":[{"title":"Detail","type":["null","number","object","string"]},{"title":"Name","type":["string"]}],"type":"array"}
+        ["hello","acmeCo/plain"]
+        ["one, two","acmeCo/comma"]
+        ["say \"hi\"","acmeCo/quote"]
+        ["line one\nline two","acmeCo/newline"]
+        [{"a":[1,"b"]},"acmeCo/object"]
+        [1.5,"acmeCo/number"]
+        [null,"acmeCo/null"]
+        "###);
+    }
+}
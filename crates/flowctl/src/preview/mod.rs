@@ -56,6 +56,14 @@ pub struct Preview {
     /// ]
     #[clap(long)]
     fixture: Option<String>,
+    /// Path of a transactions fixture to write, recording the documents of each
+    /// committed capture transaction. Recording is used only for captures.
+    /// The fixture has the same shape as --fixture, and may be used to preview
+    /// derivations and materializations of the captured collections.
+    /// It's written upon the completion of the preview, so use --sessions
+    /// or --timeout to bound the capture.
+    #[clap(long, conflicts_with = "fixture")]
+    record_fixture: Option<std::path::PathBuf>,
     /// Docker network to run connector images.
     #[clap(long, default_value = "bridge")]
     network: String,
@@ -83,6 +91,7 @@ impl Preview {
             timeout,
             sessions,
             fixture,
+            record_fixture,
             network,
             initial_state,
             output_state,
@@ -176,10 +185,15 @@ impl Preview {
                 timeout,
                 *output_state,
                 *output_apply,
+                record_fixture.as_deref(),
             )
             .await;
         }
 
+        if record_fixture.is_some() {
+            anyhow::bail!("--record-fixture may only be used with captures");
+        }
+
         for row in validations.built_collections.iter() {
             if !matches!(name, Some(n) if n == row.collection.as_str()) && name.is_some() {
                 continue;
@@ -272,6 +286,68 @@ async fn preview_capture<L: runtime::LogHandler>(
     timeout: std::time::Duration,
     output_state: bool,
     output_apply: bool,
+    record_fixture: Option<&std::path::Path>,
+) -> anyhow::Result<()> {
+    let mut recorder = record_fixture.map(|path| FixtureRecorder {
+        path,
+        fixture: Vec::new(),
+        pending: Vec::new(),
+    });
+
+    let result = run_preview_capture(
+        delay,
+        runtime,
+        sessions,
+        spec,
+        state,
+        state_dir,
+        timeout,
+        output_state,
+        output_apply,
+        recorder.as_mut(),
+    )
+    .await;
+
+    // Write committed transactions, even if the preview failed.
+    if let Some(recorder) = recorder {
+        recorder.write()?;
+    }
+    result
+}
+
+// FixtureRecorder records committed capture transactions as a Fixture.
+struct FixtureRecorder<'p> {
+    path: &'p std::path::Path,
+    fixture: runtime::harness::fixture::Fixture,
+    // Documents of the current transaction, which are not yet committed.
+    pending: Vec<(models::Collection, serde_json::Value)>,
+}
+
+impl FixtureRecorder<'_> {
+    fn write(self) -> anyhow::Result<()> {
+        let Self { path, fixture, .. } = self;
+
+        let file = std::fs::File::create(path)
+            .with_context(|| format!("couldn't create fixture file {}", path.display()))?;
+        serde_json::to_writer_pretty(std::io::BufWriter::new(file), &fixture)
+            .with_context(|| format!("couldn't write fixture file {}", path.display()))?;
+
+        tracing::info!(path=%path.display(), transactions=fixture.len(), "wrote recorded fixture");
+        Ok(())
+    }
+}
+
+async fn run_preview_capture<L: runtime::LogHandler>(
+    delay: std::time::Duration,
+    runtime: runtime::Runtime<L>,
+    sessions: Vec<usize>,
+    spec: flow::CaptureSpec,
+    state: models::RawValue,
+    state_dir: &std::path::Path,
+    timeout: std::time::Duration,
+    output_state: bool,
+    output_apply: bool,
+    mut recorder: Option<&mut FixtureRecorder<'_>>,
 ) -> anyhow::Result<()> {
     let responses_rx = runtime::harness::run_capture(
         delay,
@@ -305,10 +381,25 @@ async fn preview_capture<L: runtime::LogHandler>(
                 .name;
 
             print!("[{collection:?},{doc_json}]\n");
+
+            if let Some(recorder) = recorder.as_mut() {
+                recorder.pending.push((
+                    models::Collection::new(collection),
+                    serde_json::from_str(&doc_json).context("failed to parse captured document")?,
+                ));
+            }
         } else if let Some(capture::response::Checkpoint { state }) = response.checkpoint {
             let proto_flow::runtime::capture_response_ext::Checkpoint { stats, .. } =
                 internal.checkpoint.unwrap_or_default();
 
+            // A checkpoint commits the documents captured since the last checkpoint.
+            if let Some(recorder) = recorder.as_mut() {
+                if !recorder.pending.is_empty() {
+                    let txn = std::mem::take(&mut recorder.pending);
+                    recorder.fixture.push(txn);
+                }
+            }
+
             let collection = "connectorState";
             let state_json = state
                 .as_ref()
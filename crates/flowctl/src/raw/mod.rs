@@ -157,8 +157,7 @@ pub struct Stats {
     pub bounds: ReadBounds,
 
     /// Read raw data from stats journals, including possibly uncommitted or rolled back transactions.
-    /// This flag is currently required, but will be made optional in the future as we add support for
-    /// committed reads, which will become the default.
+    /// By default, only committed stats are read.
     #[clap(long)]
     pub uncommitted: bool,
 }
@@ -170,6 +169,7 @@ impl Stats {
             &self.task.task,
             OpsCollection::Stats,
             &self.bounds,
+            self.uncommitted,
         )
         .await
     }
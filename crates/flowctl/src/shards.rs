@@ -0,0 +1,248 @@
+use crate::catalog::{
+    collect_specs, fetch_live_specs, prompt_to_continue, publish_specs, DraftRow, List,
+    LiveSpecRow, NameSelector,
+};
+use crate::ops::TaskSelector;
+use crate::output::{to_table_row, CliOutput, JsonCell};
+use anyhow::Context;
+use proto_gazette::{broker, consumer};
+use serde::Serialize;
+
+#[derive(clap::Args, Debug)]
+pub struct Shards {
+    #[clap(subcommand)]
+    cmd: Command,
+}
+
+#[derive(clap::Subcommand, Debug)]
+pub enum Command {
+    /// List the shards of a task, with their key and r-clock ranges,
+    /// assignment status, and primary member.
+    List(TaskSelector),
+    /// Restart failed shards of a task.
+    ///
+    /// The task's current specification is re-published without changes,
+    /// which requires `write` capability to the task. Its shards are then
+    /// re-activated by the control-plane, and failed shards are restarted.
+    Restart(Restart),
+}
+
+#[derive(clap::Args, Debug)]
+pub struct Restart {
+    #[clap(flatten)]
+    pub task: TaskSelector,
+    /// Restart shards without prompting for confirmation.
+    ///
+    /// This flag is required if running flowctl non-interactively, such as in a shell script.
+    #[clap(long)]
+    pub auto_approve: bool,
+}
+
+impl Shards {
+    pub async fn run(&self, ctx: &mut crate::CliContext) -> anyhow::Result<()> {
+        match &self.cmd {
+            Command::List(task) => do_list(ctx, task).await,
+            Command::Restart(restart) => do_restart(ctx, restart).await,
+        }
+    }
+}
+
+/// A shard of a task, as it's output by `flowctl shards`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShardRow {
+    id: String,
+    key_begin: String,
+    key_end: String,
+    r_clock_begin: String,
+    r_clock_end: String,
+    // Reduced status of the shard's replicas.
+    status: String,
+    // Member which is the shard's current primary, if any.
+    primary: Option<String>,
+    // Errors of FAILED replicas.
+    errors: Vec<String>,
+}
+
+impl CliOutput for ShardRow {
+    type TableAlt = ();
+    type CellValue = JsonCell;
+
+    fn table_headers(_alt: Self::TableAlt) -> Vec<&'static str> {
+        vec![
+            "ID",
+            "Key Begin",
+            "Key End",
+            "R-Clock Begin",
+            "R-Clock End",
+            "Status",
+            "Primary",
+            "Errors",
+        ]
+    }
+
+    fn into_table_row(self, _alt: Self::TableAlt) -> Vec<Self::CellValue> {
+        to_table_row(
+            self,
+            &[
+                "/id",
+                "/keyBegin",
+                "/keyEnd",
+                "/rClockBegin",
+                "/rClockEnd",
+                "/status",
+                "/primary",
+                "/errors",
+            ],
+        )
+    }
+}
+
+impl ShardRow {
+    fn from_listing(shard: consumer::list_response::Shard) -> anyhow::Result<Self> {
+        let consumer::list_response::Shard {
+            spec,
+            route,
+            status,
+            ..
+        } = shard;
+        let consumer::ShardSpec { id, labels, .. } =
+            spec.context("shard listing is missing its spec")?;
+
+        let range = labels::shard::decode_range_spec(&labels.unwrap_or_default())
+            .with_context(|| format!("decoding key and r-clock ranges of shard {id}"))?;
+
+        // Status is reduced by taking the maximum code of all replicas.
+        let code = status
+            .iter()
+            .filter_map(|status| consumer::replica_status::Code::try_from(status.code).ok())
+            .max()
+            .unwrap_or(consumer::replica_status::Code::Idle);
+
+        let primary = route.and_then(|route| {
+            let member = route.members.get(usize::try_from(route.primary).ok()?)?;
+            Some(format!("{}/{}", member.zone, member.suffix))
+        });
+
+        let errors = status
+            .into_iter()
+            .flat_map(|status| status.errors.into_iter())
+            .collect();
+
+        let fmt = |v: u32| format!("{v:08x}");
+
+        Ok(Self {
+            id,
+            key_begin: fmt(range.key_begin),
+            key_end: fmt(range.key_end),
+            r_clock_begin: fmt(range.r_clock_begin),
+            r_clock_end: fmt(range.r_clock_end),
+            status: code.as_str_name().to_string(),
+            primary,
+            errors,
+        })
+    }
+
+    fn is_failed(&self) -> bool {
+        self.status == consumer::replica_status::Code::Failed.as_str_name()
+    }
+}
+
+async fn do_list(ctx: &mut crate::CliContext, task: &TaskSelector) -> anyhow::Result<()> {
+    let shards = list_task_shards(&ctx.client, &task.task).await?;
+    ctx.write_all(shards, ())
+}
+
+async fn do_restart(ctx: &mut crate::CliContext, restart: &Restart) -> anyhow::Result<()> {
+    use crossterm::tty::IsTty;
+
+    anyhow::ensure!(restart.auto_approve || std::io::stdin().is_tty(), "The restart command must be run interactively unless the `--auto-approve` flag is provided");
+
+    let task = &restart.task.task;
+    let failed: Vec<ShardRow> = list_task_shards(&ctx.client, task)
+        .await?
+        .into_iter()
+        .filter(|shard| shard.is_failed())
+        .collect();
+
+    if failed.is_empty() {
+        println!("Task {task} has no failed shards to restart.");
+        return Ok(());
+    }
+
+    println!(
+        "Will restart the following {} failed shards, by re-publishing task {task}",
+        failed.len()
+    );
+    ctx.write_all(failed, ())?;
+
+    if !(restart.auto_approve || prompt_to_continue().await) {
+        println!("\nCancelling");
+        anyhow::bail!("restart cancelled");
+    }
+
+    let live = fetch_live_specs::<LiveSpecRow>(
+        &ctx.client,
+        &List {
+            flows: false,
+            name_selector: NameSelector {
+                name: vec![task.clone()],
+                prefix: Vec::new(),
+            },
+            type_selector: Default::default(),
+        },
+        vec![
+            "id",
+            "catalog_name",
+            "spec_type",
+            "updated_at",
+            "last_pub_id",
+            "spec",
+        ],
+    )
+    .await
+    .context("fetching live specs")?;
+
+    let Some(row) = live.into_iter().next() else {
+        anyhow::bail!("task {task} has no live specification");
+    };
+
+    // Re-publish the task's unchanged specification, which causes the
+    // control-plane to re-activate its shards and unassign those which have
+    // failed, so that they're re-assigned and restarted.
+    // The task is live, so a default data-plane is not required.
+    let specs = collect_specs([DraftRow {
+        catalog_name: row.catalog_name,
+        spec_type: row.spec_type,
+        spec: row.spec,
+        expect_pub_id: row.last_pub_id,
+    }])?;
+    publish_specs(&ctx.client, "", &specs)
+        .await
+        .context("failed to restart shards")?;
+
+    println!("\nRe-published task {task}, and its failed shards will be restarted");
+    Ok(())
+}
+
+// List the shards of the task.
+async fn list_task_shards(client: &crate::Client, task: &str) -> anyhow::Result<Vec<ShardRow>> {
+    let (_shard_id_prefix, _ops_logs, _ops_stats, shard_client, _journal_client) =
+        flow_client::fetch_task_authorization(client, task).await?;
+
+    let resp = shard_client
+        .list(consumer::ListRequest {
+            selector: Some(broker::LabelSelector {
+                include: Some(labels::build_set([(labels::TASK_NAME, task)])),
+                exclude: None,
+            }),
+            ..Default::default()
+        })
+        .await
+        .with_context(|| format!("listing shards of task {task}"))?;
+
+    resp.shards
+        .into_iter()
+        .map(ShardRow::from_listing)
+        .collect()
+}
@@ -0,0 +1,87 @@
+use super::{check_ok, Client};
+use crate::Error;
+use proto_gazette::broker;
+
+// Maximum size of a content chunk sent with a single AppendRequest (1MB).
+const CHUNK_SIZE: usize = 1 << 20;
+// Maximum number of attempts of an Append which is rejected because
+// the broker is not the current journal primary.
+const MAX_ATTEMPTS: usize = 10;
+
+impl Client {
+    /// Invoke the Gazette journal Append API, appending `content` to the journal
+    /// as a single atomic transaction.
+    ///
+    /// `req` is the first AppendRequest of the RPC, which names the journal and
+    /// may provide `check_registers`, `union_registers`, and `subtract_registers`.
+    /// Its `content` must be empty. Content is streamed in chunks following `req`,
+    /// and the append is committed only after all content has been sent.
+    ///
+    /// The Append is routed to the journal primary broker as indicated by the
+    /// `req.header` route, if present. If `req.do_not_proxy` is set and the
+    /// broker isn't the current primary, the append is retried using the updated
+    /// route returned by the broker.
+    pub async fn append(
+        &self,
+        mut req: broker::AppendRequest,
+        content: bytes::Bytes,
+    ) -> crate::Result<broker::AppendResponse> {
+        if !req.content.is_empty() {
+            return Err(Error::Protocol(
+                "append() requires AppendRequest.content is empty",
+            ));
+        }
+
+        for attempt in 1.. {
+            match self.append_once(&mut req, content.clone()).await {
+                Err(Error::BrokerStatus(broker::Status::NotJournalPrimaryBroker))
+                    if req.do_not_proxy && attempt != MAX_ATTEMPTS =>
+                {
+                    // Expected error which drives dynamic route discovery.
+                    // `req.header` has updated route topology and we restart the request.
+                    tracing::debug!(req.journal, attempt, "retrying append to journal primary");
+                }
+                result => return result,
+            }
+        }
+        unreachable!()
+    }
+
+    async fn append_once(
+        &self,
+        req: &mut broker::AppendRequest,
+        content: bytes::Bytes,
+    ) -> crate::Result<broker::AppendResponse> {
+        let route = req.header.as_ref().and_then(|hdr| hdr.route.as_ref());
+        let mut client = self.into_sub(self.router.route(route, true, &self.default).await?);
+
+        // The first request carries metadata, followed by content chunks,
+        // followed by an empty chunk which signals the append should commit.
+        // Chunks are produced lazily as the request stream is polled,
+        // so that only one chunk at a time is copied out of `content`.
+        let len = content.len();
+        let chunks = (0..len).step_by(CHUNK_SIZE).map(move |begin| {
+            let end = std::cmp::min(begin + CHUNK_SIZE, len);
+            broker::AppendRequest {
+                content: content[begin..end].to_vec(),
+                ..Default::default()
+            }
+        });
+        let requests = std::iter::once(req.clone())
+            .chain(chunks)
+            .chain(std::iter::once(broker::AppendRequest::default()));
+
+        let resp = client
+            .append(futures::stream::iter(requests))
+            .await
+            .map_err(Error::Grpc)?
+            .into_inner();
+
+        tracing::trace!(req.journal, resp=?ops::DebugJson(&resp), "append response");
+
+        if resp.header.is_some() {
+            req.header = resp.header.clone();
+        }
+        check_ok(resp.status(), resp)
+    }
+}
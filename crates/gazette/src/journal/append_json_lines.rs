@@ -0,0 +1,69 @@
+use super::Client;
+use proto_gazette::broker;
+
+/// AppendJsonLines buffers newline-delimited JSON documents for appending to a journal.
+///
+/// Documents are serialized into an in-memory buffer with each call to `push()`,
+/// and are appended as a single atomic transaction upon `flush()`. Callers should
+/// flush periodically (for example, as `len()` crosses a desired threshold).
+pub struct AppendJsonLines {
+    buffer: Vec<u8>,
+    client: Client,
+    req: broker::AppendRequest,
+}
+
+impl Client {
+    /// Build an AppendJsonLines which appends to the journal of `req`.
+    /// `req` is used as the first AppendRequest of each flushed append,
+    /// and may specify register checks and modifications.
+    pub fn append_json_lines(self, req: broker::AppendRequest) -> AppendJsonLines {
+        AppendJsonLines {
+            buffer: Vec::new(),
+            client: self,
+            req,
+        }
+    }
+}
+
+impl AppendJsonLines {
+    /// Serialize `doc` into the buffer, followed by a newline.
+    pub fn push<T: serde::Serialize + ?Sized>(&mut self, doc: &T) -> serde_json::Result<()> {
+        let restore = self.buffer.len();
+
+        if let Err(err) = serde_json::to_writer(&mut self.buffer, doc) {
+            self.buffer.truncate(restore); // Discard a partially-written document.
+            return Err(err);
+        }
+        self.buffer.push(b'\n');
+        Ok(())
+    }
+
+    /// Length of the buffered, un-flushed content in bytes.
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns true if there are no buffered documents.
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Append all buffered documents to the journal.
+    /// Returns None if there were no buffered documents to append.
+    /// If the append fails, documents remain buffered and `flush()` may be retried.
+    pub async fn flush(&mut self) -> crate::Result<Option<broker::AppendResponse>> {
+        if self.buffer.is_empty() {
+            return Ok(None);
+        }
+        let content = bytes::Bytes::copy_from_slice(&self.buffer);
+        let resp = self.client.append(self.req.clone(), content).await?;
+
+        // Route future appends using the most-recent broker topology.
+        if resp.header.is_some() {
+            self.req.header = resp.header.clone();
+        }
+        self.buffer.clear();
+
+        Ok(Some(resp))
+    }
+}
@@ -1,12 +1,19 @@
 use proto_gazette::broker;
 use tonic::transport::Channel;
 
+mod append;
 mod list;
 mod read;
 
+mod append_json_lines;
+pub use append_json_lines::AppendJsonLines;
+
 mod read_json_lines;
 pub use read_json_lines::{ReadJsonLine, ReadJsonLines};
 
+mod read_committed;
+pub use read_committed::ReadCommitted;
+
 // SubClient is the routed sub-client of Client.
 type SubClient = proto_grpc::broker::journal_client::JournalClient<
     tonic::service::interceptor::InterceptedService<Channel, crate::Metadata>,
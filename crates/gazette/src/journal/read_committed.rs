@@ -0,0 +1,236 @@
+use super::{Client, ReadJsonLine, ReadJsonLines};
+use futures::Stream;
+use proto_gazette::{broker, message_flags, uuid};
+use std::collections::{HashMap, VecDeque};
+
+impl Client {
+    /// Read committed documents of a journal as a Stream of ReadJsonLine.
+    ///
+    /// Each document is expected to have a Flow message UUID at `/_meta/uuid`,
+    /// and its flags determine whether the document is committed:
+    /// - OUTSIDE_TXN documents are immediately committed.
+    /// - CONTINUE_TXN documents are buffered until a subsequent ACK_TXN
+    ///   of the same producer commits them, or until they're rolled back.
+    /// - ACK_TXN documents commit preceding CONTINUE_TXN documents having
+    ///   clocks at or before the ACK, and are not themselves yielded.
+    ///
+    /// Documents which are duplicates of already-committed documents, or which
+    /// were rolled back, are discarded. Committed documents are yielded in the
+    /// order of their commit, which may differ from their journal order.
+    /// Documents without a valid UUID are logged and skipped.
+    pub fn read_committed(self, req: broker::ReadRequest, buffer: usize) -> ReadCommitted {
+        ReadCommitted {
+            inner: self.read_json_lines(req, buffer),
+            ready: VecDeque::new(),
+            sequencer: Sequencer::default(),
+            uuid_ptr: doc::Pointer::from_str(UUID_PTR),
+        }
+    }
+}
+
+// Location of the message UUID within each journal document.
+const UUID_PTR: &str = "/_meta/uuid";
+
+pin_project_lite::pin_project! {
+    pub struct ReadCommitted {
+        #[pin]
+        inner: ReadJsonLines,
+        ready: VecDeque<ReadJsonLine>,
+        sequencer: Sequencer<ReadJsonLine>,
+        uuid_ptr: doc::Pointer,
+    }
+}
+
+impl futures::Stream for ReadCommitted {
+    type Item = crate::Result<ReadJsonLine>;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        use std::task::Poll;
+        let mut me = self.project();
+
+        loop {
+            if let Some(line) = me.ready.pop_front() {
+                return Poll::Ready(Some(Ok(line)));
+            }
+
+            let (root, next_offset) = match me.inner.as_mut().poll_next(cx) {
+                Poll::Ready(Some(Ok(ReadJsonLine::Doc { root, next_offset }))) => {
+                    (root, next_offset)
+                }
+                // Meta responses and errors are passed through.
+                Poll::Ready(Some(line)) => return Poll::Ready(Some(line)),
+                // Pending CONTINUE_TXN documents are discarded at end-of-stream,
+                // as they were never committed.
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            };
+
+            let Some((producer, clock, flags)) = parse_uuid(me.uuid_ptr, root.get()) else {
+                tracing::warn!(
+                    next_offset,
+                    "skipping document which is missing a valid message UUID"
+                );
+                continue;
+            };
+
+            me.sequencer.sequence(
+                producer,
+                clock,
+                flags,
+                ReadJsonLine::Doc { root, next_offset },
+                me.ready,
+            );
+        }
+    }
+}
+
+fn parse_uuid<N: doc::AsNode>(
+    ptr: &doc::Pointer,
+    root: &N,
+) -> Option<(uuid::Producer, uuid::Clock, uuid::Flags)> {
+    let doc::Node::String(s) = ptr.query(root)?.as_node() else {
+        return None;
+    };
+    uuid::parse_str(s).ok()
+}
+
+/// Sequencer tracks the transactions of distinct producers, as identified by
+/// message UUIDs, and determines when each message is committed.
+struct Sequencer<T> {
+    producers: HashMap<uuid::Producer, ProducerState<T>>,
+}
+
+struct ProducerState<T> {
+    // Clock of the last ACK (or OUTSIDE_TXN message) of this producer.
+    // Messages at or before this clock have already been committed.
+    last_ack: uuid::Clock,
+    // Pending CONTINUE_TXN messages which await an ACK.
+    pending: Vec<(uuid::Clock, T)>,
+}
+
+impl<T> Default for Sequencer<T> {
+    fn default() -> Self {
+        Self {
+            producers: HashMap::new(),
+        }
+    }
+}
+
+impl<T> Sequencer<T> {
+    /// Sequence a message `item` having the given UUID components,
+    /// pushing any messages which are now committed onto `ready`.
+    fn sequence(
+        &mut self,
+        producer: uuid::Producer,
+        clock: uuid::Clock,
+        flags: uuid::Flags,
+        item: T,
+        ready: &mut VecDeque<T>,
+    ) {
+        let state = self
+            .producers
+            .entry(producer)
+            .or_insert_with(|| ProducerState {
+                last_ack: uuid::Clock::default(),
+                pending: Vec::new(),
+            });
+
+        if flags.is_ack() {
+            // Pending messages at or before the ACK clock are committed,
+            // and those after it were rolled back.
+            for (pending_clock, pending) in state.pending.drain(..) {
+                if pending_clock <= clock {
+                    ready.push_back(pending);
+                }
+            }
+            state.last_ack.update(clock);
+        } else if clock <= state.last_ack {
+            // This is a duplicate of an already-committed message.
+        } else if flags.0 as u64 & message_flags::CONTINUE_TXN != 0 {
+            state.pending.push((clock, item));
+        } else {
+            // OUTSIDE_TXN messages are immediately committed, and implicitly
+            // roll back any pending messages of the producer.
+            state.pending.clear();
+            state.last_ack = clock;
+            ready.push_back(item);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sequencing() {
+        let p1 = uuid::Producer::from_bytes([1, 0, 0, 0, 0, 1]);
+        let p2 = uuid::Producer::from_bytes([1, 0, 0, 0, 0, 2]);
+        let clock = |n: u64| uuid::Clock::from_unix(n, 0);
+        let continue_txn = uuid::Flags(message_flags::CONTINUE_TXN as u16);
+        let outside_txn = uuid::Flags(message_flags::OUTSIDE_TXN as u16);
+        let ack_txn = uuid::Flags(message_flags::ACK_TXN as u16);
+
+        let mut seq = Sequencer::default();
+        let mut ready = VecDeque::new();
+
+        // Interleaved transactions of two producers.
+        seq.sequence(p1, clock(1), continue_txn, "p1-a", &mut ready);
+        seq.sequence(p2, clock(2), continue_txn, "p2-a", &mut ready);
+        seq.sequence(p1, clock(3), continue_txn, "p1-b", &mut ready);
+        assert!(ready.is_empty());
+
+        seq.sequence(p2, clock(4), ack_txn, "p2-ack", &mut ready);
+        assert_eq!(ready.drain(..).collect::<Vec<_>>(), vec!["p2-a"]);
+
+        seq.sequence(p1, clock(5), ack_txn, "p1-ack", &mut ready);
+        assert_eq!(ready.drain(..).collect::<Vec<_>>(), vec!["p1-a", "p1-b"]);
+
+        // A duplicate of a committed message is discarded, as is its duplicate ACK.
+        seq.sequence(p1, clock(3), continue_txn, "p1-b-dup", &mut ready);
+        seq.sequence(p1, clock(5), ack_txn, "p1-ack-dup", &mut ready);
+        assert!(ready.is_empty());
+
+        // A rolled-back transaction: the ACK clock precedes pending messages.
+        seq.sequence(p1, clock(6), continue_txn, "p1-c", &mut ready);
+        seq.sequence(p1, clock(7), continue_txn, "p1-d", &mut ready);
+        seq.sequence(p1, clock(5), ack_txn, "p1-rollback", &mut ready);
+        assert!(ready.is_empty());
+
+        // A partial commit: only messages at or before the ACK clock commit.
+        seq.sequence(p2, clock(8), continue_txn, "p2-b", &mut ready);
+        seq.sequence(p2, clock(10), continue_txn, "p2-c", &mut ready);
+        seq.sequence(p2, clock(9), ack_txn, "p2-ack", &mut ready);
+        assert_eq!(ready.drain(..).collect::<Vec<_>>(), vec!["p2-b"]);
+
+        // Outside-transaction messages are immediately committed,
+        // unless they're duplicates.
+        seq.sequence(p2, clock(11), outside_txn, "p2-d", &mut ready);
+        seq.sequence(p2, clock(11), outside_txn, "p2-d-dup", &mut ready);
+        assert_eq!(ready.drain(..).collect::<Vec<_>>(), vec!["p2-d"]);
+
+        // Pending messages of a producer are rolled back by an OUTSIDE_TXN message.
+        seq.sequence(p1, clock(12), continue_txn, "p1-e", &mut ready);
+        seq.sequence(p1, clock(13), outside_txn, "p1-f", &mut ready);
+        seq.sequence(p1, clock(14), ack_txn, "p1-ack", &mut ready);
+        assert_eq!(ready.drain(..).collect::<Vec<_>>(), vec!["p1-f"]);
+    }
+
+    #[test]
+    fn test_parse_uuid() {
+        let ptr = doc::Pointer::from_str(UUID_PTR);
+
+        let fixture =
+            serde_json::json!({"_meta": {"uuid": "9f2952f3-c6a3-11ea-8802-080607050309"}});
+        let (producer, _clock, flags) = parse_uuid(&ptr, &fixture).unwrap();
+        assert_eq!(producer.as_bytes(), &[8, 6, 7, 5, 3, 9]);
+        assert!(flags.is_ack());
+
+        assert!(parse_uuid(&ptr, &serde_json::json!({"_meta": {"uuid": 42}})).is_none());
+        assert!(parse_uuid(&ptr, &serde_json::json!({"_meta": {"uuid": "nope"}})).is_none());
+        assert!(parse_uuid(&ptr, &serde_json::json!({})).is_none());
+    }
+}
@@ -0,0 +1,416 @@
+use futures::{stream::BoxStream, StreamExt};
+use gazette::{broker, journal, Error};
+use proto_grpc::broker::journal_server::{Journal, JournalServer};
+use std::sync::{Arc, Mutex};
+
+#[tokio::test]
+async fn test_append_chunks_and_commits() {
+    let (fake, client) = serve().await;
+
+    // Content spans multiple 1MB chunks.
+    let content: Vec<u8> = (0..(5 << 19)).map(|i| (i % 251) as u8).collect();
+
+    let resp = client
+        .append(request("a/journal"), content.clone().into())
+        .await
+        .unwrap();
+
+    assert_eq!(resp.status(), broker::Status::Ok);
+    let commit = resp.commit.unwrap();
+    assert_eq!((commit.begin, commit.end), (0, content.len() as i64));
+    assert_eq!(resp.total_chunks, 3);
+
+    let state = fake.state.lock().unwrap();
+    assert_eq!(state.content, content);
+    assert_eq!(state.appends, 1);
+}
+
+#[tokio::test]
+async fn test_append_rejects_request_content() {
+    let (fake, client) = serve().await;
+
+    let err = client
+        .append(
+            broker::AppendRequest {
+                content: b"inline".to_vec(),
+                ..request("a/journal")
+            },
+            "hello\n".into(),
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::Protocol(_)));
+    assert_eq!(fake.state.lock().unwrap().appends, 0);
+}
+
+#[tokio::test]
+async fn test_append_retries_not_journal_primary_broker() {
+    let (fake, client) = serve().await;
+
+    // Without `do_not_proxy`, the status is returned as an error.
+    fake.state.lock().unwrap().redirects = 1;
+
+    let err = client
+        .append(request("a/journal"), "hello\n".into())
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        Error::BrokerStatus(broker::Status::NotJournalPrimaryBroker)
+    ));
+
+    // With `do_not_proxy`, the append is retried using the returned route.
+    fake.state.lock().unwrap().redirects = 2;
+
+    let resp = client
+        .append(
+            broker::AppendRequest {
+                do_not_proxy: true,
+                ..request("a/journal")
+            },
+            "world\n".into(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(resp.status(), broker::Status::Ok);
+    assert_eq!(resp.header.unwrap().process_id, Some(primary_id()));
+
+    let state = fake.state.lock().unwrap();
+    assert_eq!(state.content, b"world\n");
+    assert_eq!(state.redirects, 0);
+}
+
+#[tokio::test]
+async fn test_append_registers() {
+    let (fake, client) = serve().await;
+
+    let resp = client
+        .append(
+            broker::AppendRequest {
+                union_registers: Some(label_set(&[("owner", "one")])),
+                ..request("a/journal")
+            },
+            "one\n".into(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(resp.registers, Some(label_set(&[("owner", "one")])));
+
+    // A failed register check is surfaced as a broker status.
+    let err = client
+        .append(
+            broker::AppendRequest {
+                check_registers: Some(broker::LabelSelector {
+                    include: Some(label_set(&[("owner", "two")])),
+                    exclude: None,
+                }),
+                ..request("a/journal")
+            },
+            "two\n".into(),
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        Error::BrokerStatus(broker::Status::RegisterMismatch)
+    ));
+
+    // A passing check applies register updates.
+    let resp = client
+        .append(
+            broker::AppendRequest {
+                check_registers: Some(broker::LabelSelector {
+                    include: Some(label_set(&[("owner", "one")])),
+                    exclude: None,
+                }),
+                union_registers: Some(label_set(&[("owner", "two")])),
+                subtract_registers: Some(label_set(&[("owner", "one")])),
+                ..request("a/journal")
+            },
+            "three\n".into(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(resp.registers, Some(label_set(&[("owner", "two")])));
+
+    assert_eq!(fake.state.lock().unwrap().content, b"one\nthree\n");
+}
+
+#[tokio::test]
+async fn test_append_json_lines() {
+    let (fake, client) = serve().await;
+    let mut appender = client.append_json_lines(request("a/journal"));
+
+    assert!(appender.is_empty());
+    assert!(appender.flush().await.unwrap().is_none());
+
+    appender
+        .push(&serde_json::json!({"hello": "world"}))
+        .unwrap();
+    appender.push(&vec![1, 2, 3]).unwrap();
+    assert_eq!(appender.len(), 26);
+
+    // An append failure retains buffered documents.
+    fake.state.lock().unwrap().redirects = 1;
+    assert!(appender.flush().await.is_err());
+    assert_eq!(appender.len(), 26);
+
+    let resp = appender.flush().await.unwrap().unwrap();
+    assert_eq!(resp.commit.unwrap().end, 26);
+    assert!(appender.is_empty());
+
+    appender.push("more").unwrap();
+    let resp = appender.flush().await.unwrap().unwrap();
+    assert_eq!(resp.commit.unwrap().begin, 26);
+
+    let state = fake.state.lock().unwrap();
+    assert_eq!(
+        std::str::from_utf8(&state.content).unwrap(),
+        "{\"hello\":\"world\"}\n[1,2,3]\n\"more\"\n"
+    );
+    assert_eq!(state.appends, 2);
+}
+
+fn request(journal: &str) -> broker::AppendRequest {
+    broker::AppendRequest {
+        journal: journal.to_string(),
+        ..Default::default()
+    }
+}
+
+fn label_set(labels: &[(&str, &str)]) -> broker::LabelSet {
+    broker::LabelSet {
+        labels: labels
+            .iter()
+            .map(|(name, value)| broker::Label {
+                name: name.to_string(),
+                value: value.to_string(),
+                prefix: false,
+            })
+            .collect(),
+    }
+}
+
+// Start a FakeBroker on a local port, returning it and a Client which dispatches to it.
+async fn serve() -> (FakeBroker, journal::Client) {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let endpoint = format!("http://{}", listener.local_addr().unwrap());
+
+    let fake = FakeBroker {
+        state: Arc::new(Mutex::new(FakeState {
+            endpoint: endpoint.clone(),
+            ..Default::default()
+        })),
+    };
+
+    let incoming = futures::stream::try_unfold(listener, |listener| async move {
+        let (conn, _addr) = listener.accept().await?;
+        Ok::<_, std::io::Error>(Some((conn, listener)))
+    });
+    tokio::spawn(
+        tonic::transport::Server::builder()
+            .add_service(JournalServer::new(fake.clone()))
+            .serve_with_incoming(incoming),
+    );
+
+    let client = journal::Client::new(
+        endpoint,
+        gazette::Metadata::default(),
+        gazette::Router::new("local"),
+    );
+    (fake, client)
+}
+
+#[derive(Default)]
+struct FakeState {
+    // Number of upcoming appends to reject as NOT_JOURNAL_PRIMARY_BROKER.
+    redirects: usize,
+    // Endpoint of this broker, returned in redirection routes.
+    endpoint: String,
+    // Number of committed appends.
+    appends: usize,
+    // Committed journal content and current registers.
+    content: Vec<u8>,
+    registers: Vec<broker::Label>,
+}
+
+/// FakeBroker is an in-process broker which serves a single journal.
+#[derive(Clone)]
+struct FakeBroker {
+    state: Arc<Mutex<FakeState>>,
+}
+
+impl FakeState {
+    fn header(&self) -> broker::Header {
+        broker::Header {
+            process_id: Some(primary_id()),
+            route: Some(broker::Route {
+                members: vec![primary_id()],
+                primary: 0,
+                endpoints: vec![self.endpoint.clone()],
+            }),
+            etcd: None,
+        }
+    }
+
+    fn registers(&self) -> broker::LabelSet {
+        broker::LabelSet {
+            labels: self.registers.clone(),
+        }
+    }
+
+    fn matches(&self, selector: &broker::LabelSelector) -> bool {
+        let has = |label: &broker::Label| self.registers.contains(label);
+
+        selector
+            .include
+            .iter()
+            .flat_map(|set| set.labels.iter())
+            .all(has)
+            && !selector
+                .exclude
+                .iter()
+                .flat_map(|set| set.labels.iter())
+                .any(has)
+    }
+}
+
+fn primary_id() -> broker::process_spec::Id {
+    broker::process_spec::Id {
+        zone: "local".to_string(),
+        suffix: "primary".to_string(),
+    }
+}
+
+type Unimplemented<T> = BoxStream<'static, Result<T, tonic::Status>>;
+
+#[tonic::async_trait]
+impl Journal for FakeBroker {
+    type ListStream = Unimplemented<broker::ListResponse>;
+    type ReadStream = Unimplemented<broker::ReadResponse>;
+    type ReplicateStream = Unimplemented<broker::ReplicateResponse>;
+
+    async fn append(
+        &self,
+        request: tonic::Request<tonic::Streaming<broker::AppendRequest>>,
+    ) -> Result<tonic::Response<broker::AppendResponse>, tonic::Status> {
+        let mut stream = request.into_inner();
+
+        let Some(req) = stream.next().await.transpose()? else {
+            return Err(tonic::Status::invalid_argument("expected AppendRequest"));
+        };
+
+        {
+            let mut state = self.state.lock().unwrap();
+
+            // Reject as though we're not the primary, returning a route to the primary.
+            if state.redirects != 0 {
+                state.redirects -= 1;
+
+                return Ok(tonic::Response::new(broker::AppendResponse {
+                    status: broker::Status::NotJournalPrimaryBroker as i32,
+                    header: Some(broker::Header {
+                        process_id: Some(broker::process_spec::Id {
+                            zone: "local".to_string(),
+                            suffix: "not-primary".to_string(),
+                        }),
+                        ..state.header()
+                    }),
+                    ..Default::default()
+                }));
+            }
+        }
+
+        // Read content chunks until an empty chunk, which commits the append.
+        let mut content = Vec::new();
+        let mut total_chunks = 0;
+
+        loop {
+            let Some(chunk) = stream.next().await.transpose()? else {
+                return Err(tonic::Status::aborted(
+                    "append rolled back (unexpected EOF)",
+                ));
+            };
+            if chunk.content.is_empty() {
+                break;
+            }
+            content.extend_from_slice(&chunk.content);
+            total_chunks += 1;
+        }
+
+        let mut state = self.state.lock().unwrap();
+
+        if let Some(selector) = &req.check_registers {
+            if !state.matches(selector) {
+                return Ok(tonic::Response::new(broker::AppendResponse {
+                    status: broker::Status::RegisterMismatch as i32,
+                    header: Some(state.header()),
+                    registers: Some(state.registers()),
+                    ..Default::default()
+                }));
+            }
+        }
+        for label in req.subtract_registers.iter().flat_map(|set| &set.labels) {
+            state.registers.retain(|l| l != label);
+        }
+        for label in req.union_registers.iter().flat_map(|set| &set.labels) {
+            if !state.registers.contains(label) {
+                state.registers.push(label.clone());
+            }
+        }
+
+        let begin = state.content.len() as i64;
+        state.content.extend_from_slice(&content);
+        state.appends += 1;
+
+        Ok(tonic::Response::new(broker::AppendResponse {
+            status: broker::Status::Ok as i32,
+            header: Some(state.header()),
+            commit: Some(broker::Fragment {
+                journal: req.journal,
+                begin,
+                end: state.content.len() as i64,
+                ..Default::default()
+            }),
+            registers: Some(state.registers()),
+            total_chunks,
+            delayed_chunks: 0,
+        }))
+    }
+
+    async fn list(
+        &self,
+        _request: tonic::Request<broker::ListRequest>,
+    ) -> Result<tonic::Response<Self::ListStream>, tonic::Status> {
+        Err(tonic::Status::unimplemented("list"))
+    }
+
+    async fn apply(
+        &self,
+        _request: tonic::Request<broker::ApplyRequest>,
+    ) -> Result<tonic::Response<broker::ApplyResponse>, tonic::Status> {
+        Err(tonic::Status::unimplemented("apply"))
+    }
+
+    async fn read(
+        &self,
+        _request: tonic::Request<broker::ReadRequest>,
+    ) -> Result<tonic::Response<Self::ReadStream>, tonic::Status> {
+        Err(tonic::Status::unimplemented("read"))
+    }
+
+    async fn replicate(
+        &self,
+        _request: tonic::Request<tonic::Streaming<broker::ReplicateRequest>>,
+    ) -> Result<tonic::Response<Self::ReplicateStream>, tonic::Status> {
+        Err(tonic::Status::unimplemented("replicate"))
+    }
+
+    async fn list_fragments(
+        &self,
+        _request: tonic::Request<broker::FragmentsRequest>,
+    ) -> Result<tonic::Response<broker::FragmentsResponse>, tonic::Status> {
+        Err(tonic::Status::unimplemented("list_fragments"))
+    }
+}
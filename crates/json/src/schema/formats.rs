@@ -3,7 +3,11 @@ use bigdecimal::BigDecimal;
 use fancy_regex::Regex;
 use iri_string::spec::{IriSpec, UriSpec};
 use serde::{Deserialize, Serialize};
-use std::{net::IpAddr, str::FromStr};
+use std::{
+    net::IpAddr,
+    str::FromStr,
+    sync::{Arc, RwLock},
+};
 use time::macros::format_description;
 use uuid::Uuid;
 
@@ -47,6 +51,72 @@ pub enum Format {
     #[serde(alias = "uint32", alias = "uint64")]
     Integer,
     Number,
+    Base64,
+    Ulid,
+    /// Custom is a format which was registered at runtime through `register()`.
+    /// It's serialized as its bare registered name.
+    #[serde(untagged)]
+    Custom(CustomFormat),
+}
+
+/// CustomFormat is the index of a registered custom format.
+/// It's a compact index rather than a name so that `Format` remains small.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct CustomFormat(u16);
+
+impl CustomFormat {
+    pub fn name(&self) -> &'static str {
+        REGISTRY.read().unwrap()[self.0 as usize].name
+    }
+}
+
+impl std::fmt::Debug for CustomFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CustomFormat").field(&self.name()).finish()
+    }
+}
+
+impl Serialize for CustomFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for CustomFormat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let name = String::deserialize(deserializer)?;
+
+        match lookup_custom(&name) {
+            Some(Format::Custom(custom)) => Ok(custom),
+            _ => Err(serde::de::Error::custom(format!(
+                "unknown format {name:?} (it's not built-in and has not been registered)"
+            ))),
+        }
+    }
+}
+
+/// CustomValidator is a validation function of a registered custom format.
+pub type CustomValidator = Arc<dyn Fn(&str) -> ValidationResult + Send + Sync>;
+
+#[derive(thiserror::Error, Debug)]
+pub enum RegisterError {
+    #[error("format {0:?} is a built-in format and cannot be re-registered")]
+    BuiltIn(String),
+    #[error("cannot register format {0:?}, as too many custom formats are already registered")]
+    TooMany(String),
+}
+
+struct Registered {
+    name: &'static str,
+    // Should this format participate in `Format::detect()`?
+    detect: bool,
+    validator: CustomValidator,
 }
 
 // Some are from https://github.com/JamesNK/Newtonsoft.Json.Schema/blob/master/Src/Newtonsoft.Json.Schema/Infrastructure/FormatHelpers.cs
@@ -66,18 +136,86 @@ lazy_static::lazy_static! {
     static ref JSON_POINTER_RE: Regex = Regex::new(r"^(\/([^~]|(~[01]))*)*$").expect("Is a valid regex");
     static ref MACADDR: Regex = Regex::new(r"^([0-9A-Fa-f]{2}[:-]?){5}[0-9A-Fa-f]{2}$").expect("Is a valid regex");
     static ref MACADDR8: Regex = Regex::new(r"^([0-9A-Fa-f]{2}[:-]?){7}[0-9A-Fa-f]{2}$").expect("Is a valid regex");
+    // Standard (RFC 4648 section 4) base64 with required padding.
+    static ref BASE64_RE: Regex = Regex::new(r"^(?:[A-Za-z0-9+/]{4})*(?:[A-Za-z0-9+/]{2}==|[A-Za-z0-9+/]{3}=)?\z").expect("Is a valid regex");
+    // Crockford base32 encoding of a 128-bit value. The leading character is at most 7,
+    // as larger values would overflow 128 bits.
+    static ref ULID_RE: Regex = Regex::new(r"^[0-7][0-9A-HJKMNP-TV-Za-hjkmnp-tv-z]{25}\z").expect("Is a valid regex");
+
+    // Custom formats registered by the embedding application, in registration order.
+    static ref REGISTRY: RwLock<Vec<Registered>> = RwLock::new(Vec::new());
 }
 
-impl ToString for Format {
-    fn to_string(&self) -> String {
+/// Register a custom format `name` which is validated by `validator`.
+/// If `detect` is true, then the format also participates in `Format::detect()`,
+/// after all built-in formats which are detected.
+///
+/// Registrations are process-global, and are typically done once at startup
+/// before any schemas using the format are built. Registering an already-registered
+/// custom format replaces its validator. The returned Format may be used directly,
+/// and is equal to the Format parsed from a schema `format` keyword having `name`.
+pub fn register<F>(name: &str, detect: bool, validator: F) -> Result<Format, RegisterError>
+where
+    F: Fn(&str) -> ValidationResult + Send + Sync + 'static,
+{
+    if serde_json::from_value::<Format>(serde_json::Value::String(name.to_string()))
+        .map(|format| !matches!(format, Format::Custom(_)))
+        .unwrap_or(false)
+    {
+        return Err(RegisterError::BuiltIn(name.to_string()));
+    }
+    let validator: CustomValidator = Arc::new(validator);
+    let mut registry = REGISTRY.write().unwrap();
+
+    if let Some(index) = registry.iter().position(|r| r.name == name) {
+        let entry = &mut registry[index];
+        entry.detect = detect;
+        entry.validator = validator;
+        return Ok(Format::Custom(CustomFormat(index as u16)));
+    }
+    let Ok(index) = u16::try_from(registry.len()) else {
+        return Err(RegisterError::TooMany(name.to_string()));
+    };
+    // Registrations are never removed, so indices remain stable. Names are leaked
+    // because they're registered once and live for the process lifetime.
+    let name: &'static str = Box::leak(name.to_string().into_boxed_str());
+
+    registry.push(Registered {
+        name,
+        detect,
+        validator,
+    });
+    Ok(Format::Custom(CustomFormat(index)))
+}
+
+/// Return the Format of custom format `name`, if it's registered.
+pub fn lookup_custom(name: &str) -> Option<Format> {
+    REGISTRY
+        .read()
+        .unwrap()
+        .iter()
+        .position(|r| r.name == name)
+        .map(|index| Format::Custom(CustomFormat(index as u16)))
+}
+
+impl std::fmt::Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if let serde_json::Value::String(s) = serde_json::json!(self) {
-            s
+            f.write_str(&s)
         } else {
             panic!("Format must serialize as JSON string")
         }
     }
 }
 
+/// Parse an RFC 3339 date-time, as validated by `Format::DateTime`.
+/// Fractional seconds may have any precision (beyond nanoseconds are truncated),
+/// and offsets may be `Z` or any `+HH:MM` / `-HH:MM`, including offsets of a
+/// fraction of an hour only (like `-00:30`) which retain their sign.
+pub fn parse_date_time(val: &str) -> Result<time::OffsetDateTime, time::error::Parse> {
+    time::OffsetDateTime::parse(val, &time::format_description::well_known::Rfc3339)
+}
+
 impl Format {
     pub fn validate(&self, val: &str) -> ValidationResult {
         match self {
@@ -93,10 +231,7 @@ impl Format {
                     &format_description!("[year]-[month]-[day]"),
                 ))
             }
-            Self::DateTime => ValidationResult::from(time::OffsetDateTime::parse(
-                val,
-                &time::format_description::well_known::Rfc3339,
-            )),
+            Self::DateTime => ValidationResult::from(parse_date_time(val)),
             Self::Time => {
                 // [first] will choose the first matching format to parse the value
                 // see https://time-rs.github.io/book/api/format-description.html for more info
@@ -182,6 +317,14 @@ impl Format {
                 BigDecimal::from_str(val).is_ok() && !val.contains("_")
                     || ["NaN", "Infinity", "-Infinity"].contains(&val),
             ),
+            Self::Base64 => ValidationResult::from(BASE64_RE.is_match(val).unwrap_or(false)),
+            Self::Ulid => ValidationResult::from(ULID_RE.is_match(val).unwrap_or(false)),
+            Self::Custom(CustomFormat(index)) => {
+                // Clone out the validator so that the registry lock
+                // isn't held while calling into embedder code.
+                let validator = REGISTRY.read().unwrap()[*index as usize].validator.clone();
+                validator(val)
+            }
         }
     }
 
@@ -193,9 +336,29 @@ impl Format {
             _ if Format::DateTime.validate(val).is_ok() => Some(Format::DateTime),
             _ if Format::Date.validate(val).is_ok() => Some(Format::Date),
             _ if Format::Uuid.validate(val).is_ok() => Some(Format::Uuid),
-            _ => None,
+            _ => Self::detect_custom(val),
         }
     }
+
+    // Detect the first registered custom Format, which participates in detection
+    // and matches the given string.
+    fn detect_custom(val: &str) -> Option<Self> {
+        // Clone out matching validators so that the registry lock
+        // isn't held while calling into embedder code.
+        let candidates: Vec<(u16, CustomValidator)> = REGISTRY
+            .read()
+            .unwrap()
+            .iter()
+            .enumerate()
+            .filter(|(_, r)| r.detect)
+            .map(|(index, r)| (index as u16, r.validator.clone()))
+            .collect();
+
+        candidates
+            .into_iter()
+            .find(|(_, validator)| validator(val).is_ok())
+            .map(|(index, _)| Format::Custom(CustomFormat(index)))
+    }
 }
 
 #[cfg(test)]
@@ -219,6 +382,17 @@ mod test {
             ("date-time", "2022-09-11T10:31:25+00:00", true),
             ("date-time", "2022-09-11T10:31:25-00:00", true),
             ("datetime", "2022-09-11T10:31:25.123Z", true), // Accepted alias.
+            ("date-time", "2022-09-11T10:31:25.5+05:30", true),
+            ("date-time", "2022-09-11T10:31:25.123456-09:30", true),
+            ("date-time", "2022-09-11T10:31:25.123+05:45", true),
+            ("date-time", "2022-09-11T10:31:25.123+05:60", false),
+            ("date-time", "2022-09-11T10:31:25.+05:30", false),
+            ("date-time", "2022-09-11T10:31:25.123+0530", false),
+            ("date-time", "2022-09-11T10:31:25.5-00:30", true),
+            ("date-time", "2022-09-11T10:31:25.5+00:45", true),
+            ("date-time", "2022-09-11T10:31:25.5+5:30", false),
+            ("date-time", "2022-09-11T10:31:25.5+05:30:00", false),
+            ("date-time", "2022-09-11T10:31:25.5+24:00", false),
             ("date-time", "10:31:25.123Z", false),
             ("time", "10:31:25.123Z", true),
             ("time", "10:31:25.123z", true),
@@ -283,6 +457,17 @@ mod test {
             ("number", "Infinity", true),
             ("number", "-Infinity", true),
             ("number", "infinity", false),
+            ("base64", "", true),
+            ("base64", "aGVsbG8=", true),
+            ("base64", "aGVsbG8gd29ybGQ+Lw==", true),
+            ("base64", "aGVsbG8", false), // Missing padding.
+            ("base64", "aGVs bG8=", false),
+            ("base64", "aGVsbG8_", false), // URL-safe alphabet.
+            ("ulid", "01ARZ3NDEKTSV4RRFFQ69G5FAV", true),
+            ("ulid", "01arz3ndektsv4rrffq69g5fav", true),
+            ("ulid", "81ARZ3NDEKTSV4RRFFQ69G5FAV", false), // Overflows 128 bits.
+            ("ulid", "01ARZ3NDEKTSV4RRFFQ69G5FAU", false), // 'U' is excluded.
+            ("ulid", "01ARZ3NDEKTSV4RRFFQ69G5FA", false),
         ] {
             let format: Format =
                 serde_json::from_value(serde_json::Value::String(format.to_string())).unwrap();
@@ -299,4 +484,116 @@ mod test {
             }
         }
     }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    fn format_size_regression() {
+        // Format is embedded within every doc::Shape, and must stay small.
+        assert_eq!(std::mem::size_of::<Option<Format>>(), 4);
+    }
+
+    #[test]
+    fn test_date_time_fractional_offsets() {
+        use super::parse_date_time;
+
+        for (value, expect) in [
+            ("2022-09-11T10:31:25.5-00:30", "2022-09-11T11:01:25.5Z"),
+            ("2022-09-11T10:31:25.5+00:30", "2022-09-11T10:01:25.5Z"),
+            ("2022-09-11T10:31:25.123+05:45", "2022-09-11T04:46:25.123Z"),
+            (
+                "2022-09-11T00:01:25.987654321-09:30",
+                "2022-09-11T09:31:25.987654321Z",
+            ),
+        ] {
+            let utc = parse_date_time(value)
+                .unwrap()
+                .to_offset(time::UtcOffset::UTC)
+                .format(&time::format_description::well_known::Rfc3339)
+                .unwrap();
+            assert_eq!(utc, expect, "{value}");
+        }
+    }
+
+    #[test]
+    fn test_custom_format_registration() {
+        use super::{lookup_custom, register};
+
+        assert!(lookup_custom("test-iso-country").is_none());
+        assert!(serde_json::from_value::<Format>(serde_json::json!("test-iso-country")).is_err());
+
+        let country = register("test-iso-country", false, |val| {
+            ValidationResult::from(["US", "DE", "FR"].contains(&val))
+        })
+        .unwrap();
+        let phone = register("test-e164-phone", false, |val| {
+            ValidationResult::from(
+                val.len() > 2
+                    && val.len() <= 16
+                    && val.starts_with('+')
+                    && val[1..].bytes().all(|b| b.is_ascii_digit()),
+            )
+        })
+        .unwrap();
+
+        let currency = register("test-currency-code", true, |val| {
+            ValidationResult::from(val.len() == 3 && val.bytes().all(|b| b.is_ascii_uppercase()))
+        })
+        .unwrap();
+
+        // Built-in formats may not be re-registered.
+        assert!(register("date-time", false, |_| ValidationResult::Valid).is_err());
+        assert!(register("datetime", false, |_| ValidationResult::Valid).is_err());
+
+        // Registered formats round-trip through serde as their bare name.
+        let parsed: Format = serde_json::from_value(serde_json::json!("test-iso-country")).unwrap();
+        assert_eq!(parsed, country);
+        assert_eq!(country.to_string(), "test-iso-country");
+        assert_eq!(
+            format!("{country:?}"),
+            r#"Custom(CustomFormat("test-iso-country"))"#
+        );
+        assert_eq!(lookup_custom("test-e164-phone"), Some(phone));
+
+        assert!(country.validate("US").is_ok());
+        assert!(!country.validate("XX").is_ok());
+
+        // Schemas may use registered formats, which are applied by the validator.
+        let schema = crate::schema::build::build_schema::<crate::schema::CoreAnnotation>(
+            url::Url::parse("http://example/schema").unwrap(),
+            &serde_json::json!({"type": "string", "format": "test-e164-phone"}),
+        )
+        .unwrap();
+        let mut index = crate::schema::index::IndexBuilder::new();
+        index.add(&schema).unwrap();
+        let index = index.into_index();
+
+        let mut validator = crate::validator::Validator::<
+            crate::schema::CoreAnnotation,
+            crate::validator::SpanContext,
+        >::new(&index);
+
+        for (doc, expect_valid) in [("+4915112345678", true), ("not a phone", false)] {
+            validator.prepare(&schema.curi).unwrap();
+            crate::de::walk(&serde_json::json!(doc), &mut validator).unwrap();
+            assert_eq!(!validator.invalid(), expect_valid, "{doc}");
+        }
+        assert!(phone.validate("+4915112345678").is_ok());
+        assert!(!phone.validate("4915112345678").is_ok());
+
+        // Only formats registered with `detect` participate in detection,
+        // and only after built-in formats.
+        assert_eq!(Format::detect("EUR"), Some(currency));
+        assert_eq!(Format::detect("US"), None);
+        assert_eq!(Format::detect("+4915112345678"), Some(Format::Integer));
+        assert_eq!(Format::detect("1234"), Some(Format::Integer));
+
+        // Re-registration replaces the validator.
+        let country2 = register("test-iso-country", false, |val| {
+            ValidationResult::from(val == "CA")
+        })
+        .unwrap();
+        assert_eq!(country, country2);
+        assert!(!country.validate("US").is_ok());
+        assert!(country.validate("CA").is_ok());
+    }
 }
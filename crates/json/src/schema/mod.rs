@@ -279,7 +279,7 @@ impl Display for Validation {
                 write!(f, "Must be one of \"{}\"", enums)
             },
             Pattern(ptrn) => write!(f, "Must match the pattern \"{}\"", ptrn),
-            Format(fmt) => write!(f, "Must match the format \"{:?}", fmt),
+            Format(fmt) => write!(f, "Must match the format \"{}\"", fmt),
             MultipleOf(n) => write!(f, "Must be a multiple of {}", n),
             Maximum(n) => write!(f, "Must be less than or equal to {}", n),
             ExclusiveMaximum(n) => write!(f, "Must be less than {}", n),
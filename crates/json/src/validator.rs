@@ -4,6 +4,9 @@ use fxhash::FxHashSet as HashSet;
 use std::borrow::Cow;
 use std::fmt::Display;
 
+mod output;
+pub use output::{best_match, build_detailed_output, build_verbose_output, explain, BestMatch};
+
 pub enum ValidationResult {
     Valid,
     Invalid(Option<String>),
@@ -38,6 +41,12 @@ where
 }
 
 pub trait Context: Sized + std::fmt::Debug {
+    /// Retain errors of failed "anyOf" and "oneOf" branches as Outcome::Branch,
+    /// when the "anyOf" or "oneOf" as a whole doesn't match?
+    /// This is required to explain which branch came closest to matching,
+    /// but is additional work that's wasted if errors aren't being reported.
+    const RETAIN_BRANCHES: bool = false;
+
     fn with_details<'sm, 'a, A>(
         loc: &'a Location<'a>,
         span: &'a Span,
@@ -66,6 +75,8 @@ pub struct FullContext {
 }
 
 impl Context for FullContext {
+    const RETAIN_BRANCHES: bool = true;
+
     fn with_details<'sm, 'a, A>(
         loc: &'a Location<'a>,
         span: &'a Span,
@@ -151,6 +162,10 @@ pub enum Outcome<'sm, A: Annotation> {
     OneOfMultipleMatched,
     ReferenceNotFound(url::Url),
     Annotation(&'sm A),
+    // Branch is an error of a failed "anyOf" or "oneOf" branch, where no branch
+    // of the application matched. It's not itself an error, but helps explain one.
+    // Only produced if Context::RETAIN_BRANCHES.
+    Branch(Box<Outcome<'sm, A>>),
 }
 
 impl<A: Annotation> Display for Outcome<'_, A> {
@@ -175,6 +190,7 @@ impl<A: Annotation> Display for Outcome<'_, A> {
             }
             ReferenceNotFound(url) => write!(f, "Could not find reference {}", url),
             Annotation(a) => write!(f, "Annotation: {:?}", a),
+            Branch(inner) => inner.fmt(f),
         }
     }
 }
@@ -188,7 +204,19 @@ impl<'sm, A: Annotation> Outcome<'sm, A> {
             | Outcome::OneOfNotMatched
             | Outcome::OneOfMultipleMatched
             | Outcome::ReferenceNotFound(_) => true,
-            Outcome::Annotation(_) => false,
+            Outcome::Annotation(_) | Outcome::Branch(_) => false,
+        }
+    }
+
+    /// Unwrap any Branch nesting of this Outcome, returning the inner Outcome
+    /// and the number of unwrapped "anyOf" or "oneOf" branches.
+    pub fn unwrap_branches(&self) -> (&Self, usize) {
+        match self {
+            Outcome::Branch(inner) => {
+                let (inner, depth) = inner.unwrap_branches();
+                (inner, depth + 1)
+            }
+            _ => (self, 0),
         }
     }
 }
@@ -237,6 +265,10 @@ where
     //
     // Conditioned on C::RETAIN_OUTPUT.
     outcomes_unevaluated: Vec<(usize, (Outcome<'sm, A>, C))>,
+    // Errors of invalid "anyOf" and "oneOf" applications of this scope,
+    // which become Outcome::Branch outcomes of this scope iff it's determined
+    // that no application matched. Conditioned on C::RETAIN_BRANCHES.
+    outcomes_branches: Vec<(Outcome<'sm, A>, C)>,
 
     // Interned properties which were observed while evaluating this scope.
     seen_interned: intern::Set,
@@ -658,6 +690,7 @@ where
             invalid: false,
             outcomes: self.outcomes_pool.pop().unwrap_or_else(Vec::new),
             outcomes_unevaluated: self.outcomes_uneval_pool.pop().unwrap_or_else(Vec::new),
+            outcomes_branches: self.outcomes_pool.pop().unwrap_or_else(Vec::new),
             seen_interned: 0 as intern::Set,
             valid_if: None,
             valid_any_of: self.bits_pool.pop().unwrap_or_else(BitVec::new),
@@ -674,6 +707,7 @@ where
             let Scope {
                 mut outcomes,
                 mut outcomes_unevaluated,
+                mut outcomes_branches,
                 mut valid_any_of,
                 mut valid_one_of,
                 mut evaluated,
@@ -683,6 +717,7 @@ where
 
             outcomes.truncate(0);
             outcomes_unevaluated.truncate(0);
+            outcomes_branches.truncate(0);
             valid_any_of.truncate(0);
             valid_one_of.truncate(0);
             evaluated.truncate(0);
@@ -690,6 +725,7 @@ where
 
             self.outcomes_pool.push(outcomes);
             self.outcomes_uneval_pool.push(outcomes_unevaluated);
+            self.outcomes_pool.push(outcomes_branches);
             self.bits_pool.push(valid_any_of);
             self.bits_pool.push(valid_one_of);
             self.bits_pool.push(evaluated);
@@ -783,7 +819,8 @@ where
         use Outcome::*;
 
         // "anyOf": assert at least one application was valid.
-        if !scope.valid_any_of.is_empty() && !scope.valid_any_of.any() {
+        let any_of_not_matched = !scope.valid_any_of.is_empty() && !scope.valid_any_of.any();
+        if any_of_not_matched {
             scope.invalid = true;
             scope.add_outcome(AnyOfNotMatched, C::with_details(loc, span, scope, parents));
         }
@@ -794,7 +831,19 @@ where
         if c == 0 {
             scope.invalid = true;
             scope.add_outcome(OneOfNotMatched, C::with_details(loc, span, scope, parents));
-        } else if c > 1 {
+        }
+
+        // Retain errors of failed branches only if no branch matched.
+        // Otherwise, they're not relevant to explaining a validation failure.
+        if any_of_not_matched || c == 0 {
+            for (outcome, ctx) in scope.outcomes_branches.drain(..) {
+                scope.outcomes.push((Branch(Box::new(outcome)), ctx));
+            }
+        } else {
+            scope.outcomes_branches.clear();
+        }
+
+        if c > 1 {
             scope.invalid = true;
             scope.add_outcome(
                 OneOfMultipleMatched,
@@ -939,8 +988,14 @@ where
                 if !scope.invalid {
                     parent.outcomes.extend(scope.outcomes.drain(..));
                     parent.evaluated |= scope.evaluated.iter().copied();
-                } else {
-                    //parent.outcomes_debug.extend(scope.outcomes.drain(..));
+                } else if C::RETAIN_BRANCHES && matches!(app, App::AnyOf { .. } | App::OneOf { .. })
+                {
+                    parent.outcomes_branches.extend(
+                        scope
+                            .outcomes
+                            .drain(..)
+                            .filter(|(o, _)| o.is_error() || matches!(o, Outcome::Branch(_))),
+                    );
                 }
             }
             // Required children project validity and outcomes to their parent.
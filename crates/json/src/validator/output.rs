@@ -0,0 +1,485 @@
+use super::{Context, FullContext, Outcome};
+use crate::schema::{keywords, Annotation};
+use std::collections::BTreeMap;
+
+/// Build "detailed" output from a set of validator outcomes.
+/// Errors are arranged into a hierarchy which follows the structure of the schema,
+/// and nodes of the hierarchy having only a single child are collapsed.
+/// Errors of failed "anyOf" and "oneOf" branches are included within the hierarchy.
+/// See: https://json-schema.org/draft/2019-09/json-schema-core.html#rfc.section.10.4.3
+pub fn build_detailed_output<'sm, A: Annotation>(
+    outcomes: &[(Outcome<'sm, A>, FullContext)],
+) -> serde_json::Value {
+    build_output(outcomes, false)
+}
+
+/// Build "verbose" output from a set of validator outcomes.
+/// Like "detailed" output, but the hierarchy is never collapsed
+/// and collected annotations are also included.
+/// See: https://json-schema.org/draft/2019-09/json-schema-core.html#rfc.section.10.4.4
+pub fn build_verbose_output<'sm, A: Annotation>(
+    outcomes: &[(Outcome<'sm, A>, FullContext)],
+) -> serde_json::Value {
+    build_output(outcomes, true)
+}
+
+/// BestMatch is the most relevant error of a failed validation.
+#[derive(Debug)]
+pub struct BestMatch<'o, 'sm, A: Annotation> {
+    /// Error Outcome, which is never an Outcome::Branch.
+    pub outcome: &'o Outcome<'sm, A>,
+    /// Context of the error.
+    pub ctx: &'o FullContext,
+    /// Keyword locations of "anyOf" or "oneOf" branches which were chosen
+    /// as closest-to-matching in order to arrive at this error.
+    pub branches: Vec<String>,
+}
+
+/// Pick the error of a set of validator outcomes which best explains why validation failed.
+///
+/// Errors at shallower document locations are preferred, and errors of specific keywords
+/// are preferred over "anyOf" or "oneOf" errors at the same location.
+/// Where the chosen error is that no "anyOf" or "oneOf" branch matched, the branch which
+/// came closest to matching is chosen and its errors are examined in turn. A branch is closer
+/// if it wasn't a type mismatch of the document location, if its errors are at deeper
+/// document locations (it matched more of the document), and if it has fewer errors.
+pub fn best_match<'o, 'sm, A: Annotation>(
+    outcomes: &'o [(Outcome<'sm, A>, FullContext)],
+) -> Option<BestMatch<'o, 'sm, A>> {
+    let errors: Vec<(usize, &Outcome<'sm, A>, &FullContext)> = outcomes
+        .iter()
+        .filter_map(|(outcome, ctx)| {
+            let (inner, depth) = outcome.unwrap_branches();
+            inner.is_error().then_some((depth, inner, ctx))
+        })
+        .collect();
+
+    let mut branches = Vec::new();
+    let mut depth = 0;
+    let mut prefix = String::new();
+
+    loop {
+        let (outcome, ctx) = errors
+            .iter()
+            .filter(|(d, _, ctx)| *d == depth && is_within(&ctx.keyword_location, &prefix))
+            .min_by_key(|(_, outcome, ctx)| {
+                (
+                    pointer_depth(&ctx.instance_ptr),
+                    matches!(outcome, Outcome::AnyOfNotMatched | Outcome::OneOfNotMatched),
+                )
+            })
+            .map(|(_, outcome, ctx)| (*outcome, *ctx))?;
+
+        let keyword = match outcome {
+            Outcome::AnyOfNotMatched => keywords::ANY_OF,
+            Outcome::OneOfNotMatched => keywords::ONE_OF,
+            _ => {
+                return Some(BestMatch {
+                    outcome,
+                    ctx,
+                    branches,
+                })
+            }
+        };
+        let branch_prefix = format!("{}/{keyword}/", ctx.keyword_location);
+
+        // Group errors of the next branch depth by their branch index.
+        let mut candidates: BTreeMap<usize, Vec<(&Outcome<'sm, A>, &FullContext)>> =
+            BTreeMap::new();
+
+        for (d, outcome, ctx) in errors.iter() {
+            if *d != depth + 1 {
+                continue;
+            }
+            let Some(suffix) = ctx.keyword_location.strip_prefix(&branch_prefix) else {
+                continue;
+            };
+            let Ok(index) = suffix.split('/').next().unwrap_or_default().parse() else {
+                continue;
+            };
+            candidates.entry(index).or_default().push((*outcome, *ctx));
+        }
+
+        let closest = candidates.iter().min_by_key(|(index, errors)| {
+            let type_mismatch = errors.iter().any(|(outcome, err_ctx)| {
+                matches!(
+                    outcome,
+                    Outcome::Invalid(crate::schema::Validation::Type(_), _)
+                ) && err_ctx.instance_ptr == ctx.instance_ptr
+            });
+            let max_depth = errors
+                .iter()
+                .map(|(_, ctx)| pointer_depth(&ctx.instance_ptr))
+                .max()
+                .unwrap_or_default();
+
+            (
+                type_mismatch,
+                std::cmp::Reverse(max_depth),
+                errors.len(),
+                **index,
+            )
+        });
+
+        // If we don't have errors of any branch, the best we can do is report
+        // that no branch matched.
+        let Some((index, _)) = closest else {
+            return Some(BestMatch {
+                outcome,
+                ctx,
+                branches,
+            });
+        };
+
+        prefix = format!("{branch_prefix}{index}");
+        branches.push(prefix.clone());
+        depth += 1;
+    }
+}
+
+/// Explain a failed validation of `document`, as a short and human-friendly
+/// message built from the best_match() of its `outcomes`.
+pub fn explain<'sm, A: Annotation>(
+    outcomes: &[(Outcome<'sm, A>, FullContext)],
+    document: &serde_json::Value,
+) -> Option<String> {
+    let BestMatch {
+        outcome,
+        ctx,
+        branches,
+    } = best_match(outcomes)?;
+
+    let instance_ptr = percent_encoding::percent_decode_str(&ctx.instance_ptr).decode_utf8_lossy();
+
+    let location = if instance_ptr.is_empty() {
+        "document root".to_string()
+    } else {
+        format!("document location {instance_ptr:?}")
+    };
+    let expected = match outcome {
+        Outcome::Invalid(validation, None) => validation.to_string(),
+        Outcome::Invalid(validation, Some(detail)) => format!("{validation} ({detail})"),
+        _ => outcome.to_string(),
+    };
+    let actual = match document.pointer(&instance_ptr) {
+        Some(actual) => truncate_value(actual),
+        None => "nothing".to_string(),
+    };
+
+    let mut out = format!("{location} is invalid: {expected}, but found {actual}");
+
+    if let Some(closest) = branches.last() {
+        out.push_str(&format!(
+            " (no alternative matched, and the closest was {closest})"
+        ));
+    }
+    Some(out)
+}
+
+// Is `location` equal to or nested within the keyword location `prefix`?
+// `#/oneOf/1` is within `#/oneOf/1` and `#/oneOf/1/type`, but not `#/oneOf/10`.
+fn is_within(location: &str, prefix: &str) -> bool {
+    match location.strip_prefix(prefix) {
+        Some(suffix) => prefix.is_empty() || suffix.is_empty() || suffix.starts_with('/'),
+        None => false,
+    }
+}
+
+// Number of JSON-Pointer components of an instance location.
+fn pointer_depth(ptr: &str) -> usize {
+    ptr.matches('/').count()
+}
+
+// Render a compact, possibly-truncated representation of a document value.
+fn truncate_value(value: &serde_json::Value) -> String {
+    const MAX_LEN: usize = 64;
+
+    let mut s = value.to_string();
+    if s.len() > MAX_LEN {
+        let mut end = MAX_LEN;
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        s.truncate(end);
+        s.push_str("...");
+    }
+    s
+}
+
+// Node of the output hierarchy, corresponding to a schema keyword location.
+#[derive(Default)]
+struct Node {
+    // Output units of errors or annotations at exactly this location.
+    units: Vec<serde_json::Value>,
+    // Instance locations of all units under this node.
+    instance_ptrs: Vec<String>,
+    // Is every unit under this Node valid (is an annotation)?
+    valid: bool,
+    children: BTreeMap<String, Node>,
+}
+
+fn build_output<'sm, A: Annotation>(
+    outcomes: &[(Outcome<'sm, A>, FullContext)],
+    verbose: bool,
+) -> serde_json::Value {
+    let mut root = Node {
+        valid: true,
+        ..Default::default()
+    };
+
+    for (outcome, ctx) in outcomes {
+        let (inner, _) = outcome.unwrap_branches();
+
+        let unit = match inner {
+            Outcome::Annotation(annotation) if verbose => serde_json::json!({
+                "valid": true,
+                "keywordLocation": ctx.keyword_location,
+                "instanceLocation": ctx.instance_ptr,
+                "absoluteKeywordLocation": ctx.canonical_uri,
+                "annotation": format!("{annotation:?}"),
+            }),
+            Outcome::Annotation(_) => continue,
+            _ => ctx.basic_output_entry(inner.to_string()),
+        };
+        let valid = matches!(inner, Outcome::Annotation(_));
+
+        let mut node = &mut root;
+        node.instance_ptrs.push(ctx.instance_ptr.clone());
+        node.valid &= valid;
+
+        let mut location = String::from("#");
+        for component in ctx.keyword_location.split('/').skip(1) {
+            location.push('/');
+            location.push_str(component);
+
+            node = node
+                .children
+                .entry(location.clone())
+                .or_insert_with(|| Node {
+                    valid: true,
+                    ..Default::default()
+                });
+            node.instance_ptrs.push(ctx.instance_ptr.clone());
+            node.valid &= valid;
+        }
+        node.units.push(unit);
+    }
+
+    render_node("#".to_string(), root, verbose)
+}
+
+fn render_node(keyword_location: String, node: Node, verbose: bool) -> serde_json::Value {
+    let Node {
+        mut units,
+        instance_ptrs,
+        valid,
+        children,
+    } = node;
+
+    // Collapse non-root nodes having no units of their own and only one child.
+    if !verbose && units.is_empty() && children.len() == 1 && keyword_location != "#" {
+        let (location, child) = children.into_iter().next().unwrap();
+        return render_node(location, child, verbose);
+    }
+    // A node having a single unit and no children is that unit.
+    if !verbose && units.len() == 1 && children.is_empty() && keyword_location != "#" {
+        return units.pop().unwrap();
+    }
+
+    units.extend(
+        children
+            .into_iter()
+            .map(|(location, child)| render_node(location, child, verbose)),
+    );
+
+    // The instance location of a node is the longest common prefix of its units.
+    let mut instance_ptr = instance_ptrs.first().cloned().unwrap_or_default();
+    for ptr in instance_ptrs.iter().skip(1) {
+        while !(ptr == &instance_ptr || ptr.starts_with(&format!("{instance_ptr}/"))) {
+            let end = instance_ptr.rfind('/').unwrap_or(0);
+            instance_ptr.truncate(end);
+        }
+    }
+
+    let mut out = serde_json::json!({
+        "valid": valid,
+        "keywordLocation": keyword_location,
+        "instanceLocation": instance_ptr,
+    });
+    out[if valid { "annotations" } else { "errors" }] = serde_json::Value::Array(units);
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::schema::{build::build_schema, index::IndexBuilder, CoreAnnotation};
+    use crate::validator::Validator;
+    use serde_json::json;
+
+    #[test]
+    fn test_one_of_best_match_and_output() {
+        let schema = json!({
+            "oneOf": [
+                {"type": "string"},
+                {
+                    "type": "object",
+                    "properties": {"kind": {"const": "a"}, "a": {"type": "integer"}},
+                    "required": ["kind", "a"],
+                },
+                {
+                    "type": "object",
+                    "properties": {"kind": {"const": "b"}, "b": {"type": "string"}},
+                    "required": ["kind", "b"],
+                },
+            ]
+        });
+        let schema = build_schema::<CoreAnnotation>(
+            url::Url::parse("http://example/schema").unwrap(),
+            &schema,
+        )
+        .unwrap();
+        let mut index = IndexBuilder::new();
+        index.add(&schema).unwrap();
+        let index = index.into_index();
+
+        let mut validator = Validator::<CoreAnnotation, FullContext>::new(&index);
+        let doc = json!({"kind": "a", "a": "nope"});
+        validator.prepare(&schema.curi).unwrap();
+        crate::de::walk(&doc, &mut validator).unwrap();
+        assert!(validator.invalid());
+
+        // Branch errors don't appear in basic output.
+        assert_eq!(
+            super::super::build_basic_output(validator.outcomes()),
+            json!({
+                "valid": false,
+                "errors": [{
+                    "absoluteKeywordLocation": "http://example/schema",
+                    "error": "Document does not match any of the \"oneOf\" schemas",
+                    "instanceLocation": "",
+                    "keywordLocation": "#",
+                }],
+            })
+        );
+
+        let best = best_match(validator.outcomes()).unwrap();
+        assert_eq!(best.ctx.keyword_location, "#/oneOf/1/properties/a");
+        assert_eq!(best.ctx.instance_ptr, "/a");
+        assert_eq!(best.branches, vec!["#/oneOf/1".to_string()]);
+
+        assert_eq!(
+            explain(validator.outcomes(), &doc).unwrap(),
+            "document location \"/a\" is invalid: Must be of type \"integer\", but found \"nope\" (no alternative matched, and the closest was #/oneOf/1)",
+        );
+
+        assert_eq!(
+            build_detailed_output(validator.outcomes()),
+            json!({
+                "valid": false,
+                "keywordLocation": "#",
+                "instanceLocation": "",
+                "errors": [
+                    {
+                        "absoluteKeywordLocation": "http://example/schema",
+                        "error": "Document does not match any of the \"oneOf\" schemas",
+                        "instanceLocation": "",
+                        "keywordLocation": "#",
+                    },
+                    {
+                        "valid": false,
+                        "keywordLocation": "#/oneOf",
+                        "instanceLocation": "",
+                        "errors": [
+                            {
+                                "absoluteKeywordLocation": "http://example/schema#/oneOf/0",
+                                "error": "Invalid: Must be of type \"string\".",
+                                "instanceLocation": "",
+                                "keywordLocation": "#/oneOf/0",
+                            },
+                            {
+                                "absoluteKeywordLocation": "http://example/schema#/oneOf/1/properties/a",
+                                "error": "Invalid: Must be of type \"integer\".",
+                                "instanceLocation": "/a",
+                                "keywordLocation": "#/oneOf/1/properties/a",
+                            },
+                            {
+                                "valid": false,
+                                "keywordLocation": "#/oneOf/2",
+                                "instanceLocation": "",
+                                "errors": [
+                                    {
+                                        "absoluteKeywordLocation": "http://example/schema#/oneOf/2",
+                                        "error": "Invalid: Properties \"kind\", \"b\" are required.",
+                                        "instanceLocation": "",
+                                        "keywordLocation": "#/oneOf/2",
+                                    },
+                                    {
+                                        "absoluteKeywordLocation": "http://example/schema#/oneOf/2/properties/kind",
+                                        "error": "Invalid: Must be the constant \"b\".",
+                                        "instanceLocation": "/kind",
+                                        "keywordLocation": "#/oneOf/2/properties/kind",
+                                    },
+                                ],
+                            },
+                        ],
+                    },
+                ],
+            })
+        );
+
+        // A valid document has no best match, and verbose output includes annotations.
+        let doc = json!({"kind": "b", "b": "yes"});
+        validator.prepare(&schema.curi).unwrap();
+        crate::de::walk(&doc, &mut validator).unwrap();
+        assert!(!validator.invalid());
+        assert!(best_match(validator.outcomes()).is_none());
+        assert_eq!(
+            build_verbose_output(validator.outcomes()),
+            json!({
+                "valid": true,
+                "keywordLocation": "#",
+                "instanceLocation": "",
+                "annotations": [],
+            })
+        );
+    }
+
+    #[test]
+    fn test_best_match_doesnt_confuse_branch_indices() {
+        // Branch #/oneOf/1 is closest, and its errors must not be
+        // conflated with those of #/oneOf/10.
+        let mut branches = vec![json!({"type": "string"}); 11];
+        branches[1] = json!({
+            "type": "object",
+            "properties": {"a": {"type": "integer"}},
+        });
+        branches[10] = json!({"type": "object", "required": ["other"]});
+
+        let schema = json!({ "oneOf": branches });
+        let schema = build_schema::<CoreAnnotation>(
+            url::Url::parse("http://example/schema").unwrap(),
+            &schema,
+        )
+        .unwrap();
+        let mut index = IndexBuilder::new();
+        index.add(&schema).unwrap();
+        let index = index.into_index();
+
+        let mut validator = Validator::<CoreAnnotation, FullContext>::new(&index);
+        let doc = json!({"a": "nope"});
+        validator.prepare(&schema.curi).unwrap();
+        crate::de::walk(&doc, &mut validator).unwrap();
+        assert!(validator.invalid());
+
+        let best = best_match(validator.outcomes()).unwrap();
+        assert_eq!(best.ctx.keyword_location, "#/oneOf/1/properties/a");
+        assert_eq!(best.ctx.instance_ptr, "/a");
+        assert_eq!(best.branches, vec!["#/oneOf/1".to_string()]);
+
+        assert!(is_within("#/oneOf/1", "#/oneOf/1"));
+        assert!(is_within("#/oneOf/1/type", "#/oneOf/1"));
+        assert!(!is_within("#/oneOf/10", "#/oneOf/1"));
+        assert!(is_within("#", ""));
+    }
+}
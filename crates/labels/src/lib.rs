@@ -29,6 +29,11 @@ pub const SPLIT_SOURCE: &str = "estuary.dev/split-source";
 pub const LOG_LEVEL: &str = "estuary.dev/log-level";
 pub const LOGS_JOURNAL: &str = "estuary.dev/logs-journal";
 pub const STATS_JOURNAL: &str = "estuary.dev/stats-journal";
+// Shard labels related to resource requests and limits of connector containers.
+pub const CONNECTOR_CPU_REQUEST: &str = "estuary.dev/connector-cpu-request";
+pub const CONNECTOR_CPU_LIMIT: &str = "estuary.dev/connector-cpu-limit";
+pub const CONNECTOR_MEMORY_REQUEST: &str = "estuary.dev/connector-memory-request";
+pub const CONNECTOR_MEMORY_LIMIT: &str = "estuary.dev/connector-memory-limit";
 // Shard labels related to network connectivity to shards.
 pub const HOSTNAME: &str = "estuary.dev/hostname";
 pub const EXPOSE_PORT: &str = "estuary.dev/expose-port";
@@ -91,6 +91,74 @@ pub fn decode_labeling(set: &LabelSet) -> Result<ops::ShardLabeling, Error> {
     })
 }
 
+/// ConnectorResources are CPU and memory requests and limits of a task's
+/// connector containers. Memory is in bytes.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ConnectorResources {
+    pub cpu_request: Option<f64>,
+    pub cpu_limit: Option<f64>,
+    pub memory_request: Option<u64>,
+    pub memory_limit: Option<u64>,
+}
+
+/// Encode ConnectorResources into a LabelSet.
+/// Labels of unset resources are removed.
+pub fn encode_connector_resources(mut set: LabelSet, resources: &ConnectorResources) -> LabelSet {
+    let ConnectorResources {
+        cpu_request,
+        cpu_limit,
+        memory_request,
+        memory_limit,
+    } = resources;
+
+    for (name, value) in [
+        (
+            crate::CONNECTOR_CPU_REQUEST,
+            cpu_request.map(|v| v.to_string()),
+        ),
+        (crate::CONNECTOR_CPU_LIMIT, cpu_limit.map(|v| v.to_string())),
+        (
+            crate::CONNECTOR_MEMORY_REQUEST,
+            memory_request.map(|v| v.to_string()),
+        ),
+        (
+            crate::CONNECTOR_MEMORY_LIMIT,
+            memory_limit.map(|v| v.to_string()),
+        ),
+    ] {
+        set = match value {
+            Some(value) => set_value(set, name, &value),
+            None => crate::remove(set, name),
+        };
+    }
+    set
+}
+
+/// Decode ConnectorResources from a LabelSet.
+pub fn decode_connector_resources(set: &LabelSet) -> Result<ConnectorResources, Error> {
+    fn parse<T: std::str::FromStr>(set: &LabelSet, name: &str) -> Result<Option<T>, Error> {
+        let value = maybe_one(set, name)?;
+
+        if value.is_empty() {
+            return Ok(None);
+        }
+        match value.parse() {
+            Ok(v) => Ok(Some(v)),
+            Err(_) => Err(Error::InvalidValue {
+                name: name.to_string(),
+                value: value.to_string(),
+            }),
+        }
+    }
+
+    Ok(ConnectorResources {
+        cpu_request: parse(set, crate::CONNECTOR_CPU_REQUEST)?,
+        cpu_limit: parse(set, crate::CONNECTOR_CPU_LIMIT)?,
+        memory_request: parse(set, crate::CONNECTOR_MEMORY_REQUEST)?,
+        memory_limit: parse(set, crate::CONNECTOR_MEMORY_LIMIT)?,
+    })
+}
+
 /// Encode a RangeSpec into a LabelSet.
 pub fn encode_range_spec(mut set: LabelSet, spec: &flow::RangeSpec) -> LabelSet {
     let fmt = |v: u32| format!("{v:08x}");
@@ -345,4 +413,47 @@ mod test {
         insta::assert_json_snapshot!(case(set),
             @r###""both split-source split/source and split-target split/target are set but shouldn't be""###);
     }
+
+    #[test]
+    fn test_connector_resources() {
+        let resources = ConnectorResources {
+            cpu_request: Some(0.25),
+            cpu_limit: Some(2.0),
+            memory_request: None,
+            memory_limit: Some(1 << 30),
+        };
+        let set = build_set([(crate::CONNECTOR_MEMORY_REQUEST, "1024")]);
+        let set = encode_connector_resources(set, &resources);
+
+        insta::assert_json_snapshot!(set, @r###"
+        {
+          "labels": [
+            {
+              "name": "estuary.dev/connector-cpu-limit",
+              "value": "2"
+            },
+            {
+              "name": "estuary.dev/connector-cpu-request",
+              "value": "0.25"
+            },
+            {
+              "name": "estuary.dev/connector-memory-limit",
+              "value": "1073741824"
+            }
+          ]
+        }
+        "###);
+
+        assert_eq!(decode_connector_resources(&set).unwrap(), resources);
+        assert_eq!(
+            decode_connector_resources(&LabelSet::default()).unwrap(),
+            ConnectorResources::default()
+        );
+
+        let set = crate::set_value(set, crate::CONNECTOR_MEMORY_LIMIT, "1g");
+        assert_eq!(
+            decode_connector_resources(&set).unwrap_err().to_string(),
+            "invalid value \"1g\" for label estuary.dev/connector-memory-limit"
+        );
+    }
 }
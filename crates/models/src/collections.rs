@@ -81,16 +81,78 @@ pub enum Projection {
         #[serde(default)]
         partition: bool,
     },
+    Computed {
+        /// # Expression which computes this projection.
+        expression: ProjectionExpression,
+        /// # Is this projection a logical partition?
+        #[serde(default)]
+        partition: bool,
+    },
+}
+
+/// ProjectionExpression computes the value of a projection
+/// from other locations of collection documents.
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema, PartialEq)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub enum ProjectionExpression {
+    /// # Concatenation of the string forms of locations.
+    /// Locations which don't exist or are null are skipped.
+    Concat {
+        /// # Locations which are concatenated.
+        of: Vec<JsonPointer>,
+        /// # Separator between concatenated locations.
+        #[serde(default, skip_serializing_if = "String::is_empty")]
+        separator: String,
+    },
+    /// # Lower-cased form of a string location.
+    Lower(JsonPointer),
+    /// # Upper-cased form of a string location.
+    Upper(JsonPointer),
+    /// # Hex-encoded MD5 hash of locations.
+    /// Locations are hashed as a JSON array of their values,
+    /// where locations which don't exist are null.
+    Hash(Vec<JsonPointer>),
+    /// # First of the locations which exists and isn't null.
+    Coalesce(Vec<JsonPointer>),
+    /// # Date-time location truncated to a unit.
+    DateTrunc {
+        /// # Date-time location which is truncated.
+        location: JsonPointer,
+        /// # Unit to which the date-time is truncated.
+        unit: DateTruncUnit,
+    },
+}
+
+/// DateTruncUnit is a unit to which date-times are truncated.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, JsonSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum DateTruncUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Month,
+    Year,
 }
 
 impl Projection {
-    pub fn as_parts(&self) -> (&JsonPointer, bool) {
+    /// Returns the document location of this projection (if it's not computed),
+    /// and whether it's a logical partition.
+    pub fn as_parts(&self) -> (Option<&JsonPointer>, bool) {
         match self {
-            Self::Pointer(location) => (location, false),
+            Self::Pointer(location) => (Some(location), false),
             Self::Extended {
                 location,
                 partition,
-            } => (location, *partition),
+            } => (Some(location), *partition),
+            Self::Computed { partition, .. } => (None, *partition),
+        }
+    }
+
+    fn example_computed() -> Self {
+        Projection::Computed {
+            expression: ProjectionExpression::Lower(JsonPointer::example()),
+            partition: false,
         }
     }
 
@@ -125,6 +187,7 @@ fn projections_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::sch
         "examples": [{
             "a_field": Projection::example_pointer(),
             "a_partition": Projection::example_extended(),
+            "a_computed": Projection::example_computed(),
         }],
     }))
     .unwrap()
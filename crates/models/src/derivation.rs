@@ -1,6 +1,6 @@
 use super::{
-    CompositeKey, ConnectorConfig, DeriveUsingSqlite, DeriveUsingTypescript, LocalConfig, RawValue,
-    ShardTemplate, Source, Transform,
+    CompositeKey, ConnectorConfig, DeriveUsingMapping, DeriveUsingSqlite, DeriveUsingTypescript,
+    DeriveUsingWasm, LocalConfig, RawValue, ShardTemplate, Source, Transform,
 };
 use schemars::{schema::Schema, JsonSchema};
 use serde::{Deserialize, Serialize};
@@ -36,6 +36,10 @@ pub enum DeriveUsing {
     Sqlite(DeriveUsingSqlite),
     /// # A TypeScript derivation.
     Typescript(DeriveUsingTypescript),
+    /// # A WebAssembly derivation.
+    Wasm(DeriveUsingWasm),
+    /// # A declarative mapping derivation.
+    Mapping(DeriveUsingMapping),
     /// # A local command (development only).
     Local(LocalConfig),
 }
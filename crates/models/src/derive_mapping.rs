@@ -0,0 +1,107 @@
+use super::JsonPointer;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// A declarative mapping derivation has no configuration of its own.
+/// Each of its transforms has a lambda which is a MappingLambda.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, JsonSchema, PartialEq)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct DeriveUsingMapping {}
+
+/// MappingLambda is the lambda of a transform of a mapping derivation.
+/// It maps each source document which passes its filter into a
+/// single output document.
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema, PartialEq)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[schemars(example = "MappingLambda::example")]
+pub struct MappingLambda {
+    /// # Predicates which source documents must match.
+    /// Source documents which don't match every predicate are skipped.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub filter: Vec<MappingPredicate>,
+    /// # Locations of the output document and their values.
+    /// Keys are JSON pointers of output document locations.
+    pub fields: BTreeMap<JsonPointer, MappingField>,
+}
+
+/// MappingPredicate tests a location of source documents.
+/// Exactly one of `eq`, `in`, `exists`, or `range` must be set.
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema, PartialEq)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct MappingPredicate {
+    /// # Location of source documents which is tested.
+    pub ptr: JsonPointer,
+    /// # Location must equal this value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eq: Option<Value>,
+    /// # Location must equal one of these values.
+    #[serde(default, rename = "in", skip_serializing_if = "Option::is_none")]
+    pub in_: Option<Vec<Value>>,
+    /// # Location must (or must not) exist.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exists: Option<bool>,
+    /// # Location must be within this range.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub range: Option<MappingRange>,
+}
+
+/// MappingRange is an inclusive range of numbers or strings.
+/// Either or both of `minimum` and `maximum` may be set.
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema, PartialEq)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct MappingRange {
+    /// # Minimum value of the location, inclusive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub minimum: Option<Value>,
+    /// # Maximum value of the location, inclusive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maximum: Option<Value>,
+}
+
+/// MappingField is the value of an output document location.
+/// Exactly one of `from` or `const` must be set.
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema, PartialEq)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct MappingField {
+    /// # Location of source documents which is projected.
+    /// If the location doesn't exist in a source document,
+    /// then it's also omitted from the output document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<JsonPointer>,
+    /// # Constant value of the location.
+    #[serde(default, rename = "const", skip_serializing_if = "Option::is_none")]
+    pub const_: Option<Value>,
+    /// # Type to which the value is cast.
+    /// Null values are not cast, and remain null.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cast: Option<MappingCast>,
+}
+
+/// MappingCast is a type to which a mapped value is cast.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, JsonSchema, PartialEq)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub enum MappingCast {
+    Boolean,
+    Integer,
+    Number,
+    String,
+}
+
+impl MappingLambda {
+    pub fn example() -> Self {
+        serde_json::from_value(serde_json::json!({
+            "filter": [
+                {"ptr": "/status", "in": ["active", "pending"]},
+                {"ptr": "/amount", "range": {"minimum": 0}},
+            ],
+            "fields": {
+                "/id": {"from": "/order_id"},
+                "/amount": {"from": "/amount", "cast": "number"},
+                "/source": {"const": "orders"},
+            }
+        }))
+        .unwrap()
+    }
+}
@@ -14,6 +14,11 @@ pub struct DeriveUsingTypescript {
     /// interface.
     #[schemars(schema_with = "DeriveUsingTypescript::module_schema")]
     pub module: RawValue,
+    /// # Generate runtime validators of documents.
+    /// When true, generated types are accompanied by Zod schemas
+    /// which validate documents at runtime.
+    #[serde(default, skip_serializing_if = "super::is_false")]
+    pub validators: bool,
 }
 
 impl DeriveUsingTypescript {
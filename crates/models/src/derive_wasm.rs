@@ -0,0 +1,62 @@
+use super::{RawValue, RelativeUrl};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{from_value, json};
+
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema, PartialEq)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct DeriveUsingWasm {
+    /// # WebAssembly module implementing this derivation.
+    /// Module is either a relative URL of a WebAssembly module file,
+    /// in binary (.wasm) or text (.wat) format, or is an inline
+    /// WebAssembly text-format module. The module must export its
+    /// `memory`, an `alloc` function, and a `publish` function.
+    /// Stateful modules also export `update`, `snapshot`, and `restore` functions.
+    #[schemars(schema_with = "DeriveUsingWasm::module_schema")]
+    pub module: RawValue,
+    /// # Fuel available to each invocation of the module.
+    /// Fuel bounds the number of WebAssembly instructions which an
+    /// invocation of `update` or `publish` may execute.
+    /// If not set, a default (currently 1,000,000,000) is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fuel: Option<u64>,
+    /// # Maximum linear memory of the module.
+    /// Memory is a number of bytes with an optional "k", "m", or "g" unit suffix,
+    /// such as "128m". If not set, a default (currently "64m") is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(schema_with = "super::shards::memory_schema")]
+    pub memory_limit: Option<String>,
+}
+
+impl DeriveUsingWasm {
+    /// Default fuel of each module invocation.
+    pub const DEFAULT_FUEL: u64 = 1_000_000_000;
+    /// Default linear memory limit of a module, in bytes.
+    pub const DEFAULT_MEMORY_LIMIT: u64 = 64 << 20;
+    /// Prefix of data URLs which embed a base64 binary WebAssembly module.
+    pub const DATA_URL_PREFIX: &'static str = "data:application/wasm;base64,";
+
+    /// Is `module` embedded inline, as a data URL or as WebAssembly text?
+    /// Otherwise it's the URL of a module which must be loaded.
+    /// WebAssembly text begins with an S-expression or a comment,
+    /// possibly after leading whitespace.
+    pub fn is_inline_module(module: &str) -> bool {
+        let text = module.trim_start();
+        module.starts_with(Self::DATA_URL_PREFIX) || text.starts_with('(') || text.starts_with(";;")
+    }
+
+    fn module_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let url_schema = RelativeUrl::json_schema(gen);
+
+        from_value(json!({
+            "oneOf": [
+                url_schema,
+                {
+                    "type": "string",
+                    "contentMediaType": "text/x.wat",
+                }
+            ]
+        }))
+        .unwrap()
+    }
+}
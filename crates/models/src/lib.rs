@@ -7,8 +7,10 @@ pub mod collate;
 mod collections;
 mod connector;
 mod derivation;
+mod derive_mapping;
 mod derive_sqlite;
 mod derive_typescript;
+mod derive_wasm;
 mod id;
 mod journals;
 mod labels;
@@ -24,11 +26,15 @@ mod tests;
 pub use crate::labels::{Label, LabelSelector, LabelSet};
 pub use captures::{AutoDiscover, CaptureBinding, CaptureDef, CaptureEndpoint};
 pub use catalogs::{Capability, Catalog, CatalogType};
-pub use collections::{CollectionDef, Projection};
+pub use collections::{CollectionDef, DateTruncUnit, Projection, ProjectionExpression};
 pub use connector::{split_image_tag, ConnectorConfig, DekafConfig, LocalConfig};
 pub use derivation::{Derivation, DeriveUsing, Shuffle, ShuffleType, TransformDef};
+pub use derive_mapping::{
+    DeriveUsingMapping, MappingCast, MappingField, MappingLambda, MappingPredicate, MappingRange,
+};
 pub use derive_sqlite::DeriveUsingSqlite;
 pub use derive_typescript::DeriveUsingTypescript;
+pub use derive_wasm::DeriveUsingWasm;
 pub use id::{Id, IdGenerator};
 pub use journals::{
     AzureStorageConfig, CompressionCodec, CustomStore, FragmentTemplate, GcsBucketAndPrefix,
@@ -45,7 +51,7 @@ pub use references::{
     Prefix, RelativeUrl, StorageEndpoint, Test, Token, Transform, CATALOG_PREFIX_RE, TOKEN_RE,
 };
 pub use schemas::Schema;
-pub use shards::ShardTemplate;
+pub use shards::{ConnectorResources, ShardTemplate};
 pub use source::{FullSource, OnIncompatibleSchemaChange, PartitionSelector, Source};
 pub use source_capture::{SourceCapture, SourceCaptureDef, SourceCaptureSchemaMode};
 pub use tests::{TestDef, TestDocuments, TestStep, TestStepIngest, TestStepVerify};
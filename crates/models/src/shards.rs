@@ -73,6 +73,77 @@ pub struct ShardTemplate {
     // we'll introduce a modular logging capability.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub log_level: Option<String>,
+    /// # Resource requests and limits of the task's connector containers.
+    /// If not set, the data-plane defaults are used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resources: Option<ConnectorResources>,
+}
+
+/// ConnectorResources are CPU and memory requests and limits
+/// of a task's connector containers.
+#[derive(Serialize, Deserialize, Debug, Default, JsonSchema, Clone, PartialEq)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[schemars(example = "ConnectorResources::example")]
+pub struct ConnectorResources {
+    /// # Number of CPUs reserved for each connector container.
+    /// Under contention, the connector is allotted at least this share of CPU.
+    /// If not set, no CPU is reserved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu_request: Option<f64>,
+    /// # Maximum number of CPUs usable by each connector container.
+    /// If not set, the data-plane default (currently 2 CPUs) is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu_limit: Option<f64>,
+    /// # Memory reserved for each connector container.
+    /// Memory is a number of bytes with an optional "k", "m", or "g" unit suffix,
+    /// such as "512m". If not set, no memory is reserved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(schema_with = "memory_schema")]
+    pub memory_request: Option<String>,
+    /// # Maximum memory usable by each connector container.
+    /// Memory is a number of bytes with an optional "k", "m", or "g" unit suffix,
+    /// such as "2g". If not set, the data-plane default (currently "1g") is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(schema_with = "memory_schema")]
+    pub memory_limit: Option<String>,
+}
+
+impl ConnectorResources {
+    /// Default CPU limit of connector containers.
+    pub const DEFAULT_CPU_LIMIT: f64 = 2.0;
+    /// Default memory limit of connector containers, in bytes.
+    pub const DEFAULT_MEMORY_LIMIT: u64 = 1 << 30;
+
+    pub fn example() -> Self {
+        Self {
+            cpu_limit: Some(4.0),
+            memory_limit: Some("2g".to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Parse a memory size, such as "512m", into its number of bytes.
+    /// Returns None if `size` is not a valid memory size.
+    pub fn parse_memory(size: &str) -> Option<u64> {
+        let (digits, scale) = match size.as_bytes().last()? {
+            b'k' => (&size[..size.len() - 1], 1 << 10),
+            b'm' => (&size[..size.len() - 1], 1 << 20),
+            b'g' => (&size[..size.len() - 1], 1 << 30),
+            _ => (size, 1),
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        digits.parse::<u64>().ok()?.checked_mul(scale)
+    }
+}
+
+pub(crate) fn memory_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+    serde_json::from_value(serde_json::json!({
+        "type": ["string", "null"],
+        "pattern": "^\\d+(k|m|g)?$"
+    }))
+    .unwrap()
 }
 
 impl ShardTemplate {
@@ -92,6 +163,7 @@ impl ShardTemplate {
             ring_buffer_size: o4,
             read_channel_size: o5,
             log_level: o6,
+            resources: o7,
         } = self;
 
         !disable
@@ -101,5 +173,30 @@ impl ShardTemplate {
             && o4.is_none()
             && o5.is_none()
             && o6.is_none()
+            && o7.is_none()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ConnectorResources;
+
+    #[test]
+    fn test_parse_memory() {
+        for (size, expect) in [
+            ("1024", Some(1024)),
+            ("512k", Some(512 << 10)),
+            ("512m", Some(512 << 20)),
+            ("2g", Some(2 << 30)),
+            ("", None),
+            ("g", None),
+            ("1.5g", None),
+            ("-1m", None),
+            ("1G", None),
+            ("1gb", None),
+            ("99999999999999999999g", None),
+        ] {
+            assert_eq!(ConnectorResources::parse_memory(size), expect, "{size}");
+        }
     }
 }
@@ -56,6 +56,11 @@ pub struct Projection {
     /// Inference of this projection.
     #[prost(message, optional, tag = "6")]
     pub inference: ::core::option::Option<Inference>,
+    /// JSON-encoded expression which computes this projection,
+    /// or empty if this projection is a document location.
+    /// Computed projections have a synthetic `ptr` which doesn't exist in documents.
+    #[prost(string, tag = "7")]
+    pub expression_json: ::prost::alloc::string::String,
 }
 /// Inference details type information which is statically known
 /// about a given document location.
@@ -344,6 +349,8 @@ pub mod collection_spec {
             Typescript = 2,
             Image = 3,
             Local = 4,
+            Wasm = 5,
+            Mapping = 6,
         }
         impl ConnectorType {
             /// String value of the enum field names used in the ProtoBuf definition.
@@ -357,6 +364,8 @@ pub mod collection_spec {
                     ConnectorType::Typescript => "TYPESCRIPT",
                     ConnectorType::Image => "IMAGE",
                     ConnectorType::Local => "LOCAL",
+                    ConnectorType::Wasm => "WASM",
+                    ConnectorType::Mapping => "MAPPING",
                 }
             }
             /// Creates an enum from field names used in the ProtoBuf definition.
@@ -367,6 +376,8 @@ pub mod collection_spec {
                     "TYPESCRIPT" => Some(Self::Typescript),
                     "IMAGE" => Some(Self::Image),
                     "LOCAL" => Some(Self::Local),
+                    "WASM" => Some(Self::Wasm),
+                    "MAPPING" => Some(Self::Mapping),
                     _ => None,
                 }
             }
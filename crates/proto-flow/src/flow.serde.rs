@@ -1344,6 +1344,8 @@ impl serde::Serialize for collection_spec::derivation::ConnectorType {
             Self::Typescript => "TYPESCRIPT",
             Self::Image => "IMAGE",
             Self::Local => "LOCAL",
+            Self::Wasm => "WASM",
+            Self::Mapping => "MAPPING",
         };
         serializer.serialize_str(variant)
     }
@@ -1360,6 +1362,8 @@ impl<'de> serde::Deserialize<'de> for collection_spec::derivation::ConnectorType
             "TYPESCRIPT",
             "IMAGE",
             "LOCAL",
+            "WASM",
+            "MAPPING",
         ];
 
         struct GeneratedVisitor;
@@ -1405,6 +1409,8 @@ impl<'de> serde::Deserialize<'de> for collection_spec::derivation::ConnectorType
                     "TYPESCRIPT" => Ok(collection_spec::derivation::ConnectorType::Typescript),
                     "IMAGE" => Ok(collection_spec::derivation::ConnectorType::Image),
                     "LOCAL" => Ok(collection_spec::derivation::ConnectorType::Local),
+                    "WASM" => Ok(collection_spec::derivation::ConnectorType::Wasm),
+                    "MAPPING" => Ok(collection_spec::derivation::ConnectorType::Mapping),
                     _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
                 }
             }
@@ -4517,6 +4523,9 @@ impl serde::Serialize for Projection {
         if self.inference.is_some() {
             len += 1;
         }
+        if !self.expression_json.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("flow.Projection", len)?;
         if !self.ptr.is_empty() {
             struct_ser.serialize_field("ptr", &self.ptr)?;
@@ -4536,6 +4545,9 @@ impl serde::Serialize for Projection {
         if let Some(v) = self.inference.as_ref() {
             struct_ser.serialize_field("inference", v)?;
         }
+        if !self.expression_json.is_empty() {
+            struct_ser.serialize_field("expression", crate::as_raw_json(&self.expression_json)?)?;
+        }
         struct_ser.end()
     }
 }
@@ -4554,6 +4566,8 @@ impl<'de> serde::Deserialize<'de> for Projection {
             "is_primary_key",
             "isPrimaryKey",
             "inference",
+            "expression_json",
+            "expression",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -4564,6 +4578,7 @@ impl<'de> serde::Deserialize<'de> for Projection {
             IsPartitionKey,
             IsPrimaryKey,
             Inference,
+            ExpressionJson,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -4591,6 +4606,7 @@ impl<'de> serde::Deserialize<'de> for Projection {
                             "isPartitionKey" | "is_partition_key" => Ok(GeneratedField::IsPartitionKey),
                             "isPrimaryKey" | "is_primary_key" => Ok(GeneratedField::IsPrimaryKey),
                             "inference" => Ok(GeneratedField::Inference),
+                            "expression" | "expression_json" => Ok(GeneratedField::ExpressionJson),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -4616,6 +4632,7 @@ impl<'de> serde::Deserialize<'de> for Projection {
                 let mut is_partition_key__ = None;
                 let mut is_primary_key__ = None;
                 let mut inference__ = None;
+                let mut expression_json__ : Option<Box<serde_json::value::RawValue>> = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Ptr => {
@@ -4654,6 +4671,12 @@ impl<'de> serde::Deserialize<'de> for Projection {
                             }
                             inference__ = map_.next_value()?;
                         }
+                        GeneratedField::ExpressionJson => {
+                            if expression_json__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expression"));
+                            }
+                            expression_json__ = Some(map_.next_value()?);
+                        }
                     }
                 }
                 Ok(Projection {
@@ -4663,6 +4686,7 @@ impl<'de> serde::Deserialize<'de> for Projection {
                     is_partition_key: is_partition_key__.unwrap_or_default(),
                     is_primary_key: is_primary_key__.unwrap_or_default(),
                     inference: inference__,
+                    expression_json: expression_json__.map(|r| Box::<str>::from(r).into()).unwrap_or_default(),
                 })
             }
         }
@@ -32,6 +32,7 @@ fn ex_projections() -> Vec<flow::Projection> {
                 maximum: 0.0,
             }),
         }),
+        expression_json: String::new(),
     }]
 }
 
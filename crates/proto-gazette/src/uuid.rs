@@ -1,7 +1,7 @@
 /// Producer is the unique node identifier portion of a v1 UUID.
 /// Gazette uses Producer to identify distinct writers of collection data,
 /// as the key of a vector clock.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Producer(pub [u8; 6]);
 
 /// Clock is a v1 UUID 60-bit timestamp (60 MSBs), followed by 4 bits of sequence
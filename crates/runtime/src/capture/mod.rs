@@ -36,6 +36,9 @@ struct Binding {
     key_extractors: Vec<doc::Extractor>,
     // Partition values which are extracted from written documents.
     partition_extractors: Vec<doc::Extractor>,
+    // Shape of the target collection's write schema, if it has
+    // locations which must be redacted from captured documents.
+    redact_shape: Option<doc::Shape>,
     // Specification of this binding.
     resource_path: Vec<String>,
     // Serialization policy for the Target collection.
@@ -369,11 +369,16 @@ pub fn recv_connector_captured(
         .doc_bytes_to_heap_node(doc_json.as_bytes())
         .context("couldn't parse captured document as JSON")?;
 
-    let uuid_ptr = &task
+    let binding_spec = task
         .bindings
         .get(binding as usize)
-        .with_context(|| "invalid captured binding {binding}")?
-        .document_uuid_ptr;
+        .with_context(|| "invalid captured binding {binding}")?;
+
+    // Redact sensitive locations before the document is combined or persisted.
+    if let Some(shape) = &binding_spec.redact_shape {
+        shape.redact(&mut doc, alloc);
+    }
+    let uuid_ptr = &binding_spec.document_uuid_ptr;
 
     if !uuid_ptr.0.is_empty() {
         if let Some(node) = uuid_ptr.create_heap_node(&mut doc, alloc) {
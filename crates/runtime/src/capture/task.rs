@@ -148,11 +148,14 @@ impl Binding {
         let partition_extractors =
             extractors::for_fields(&partition_fields, &projections, &ser_policy)?;
 
+        let redact_shape = crate::redact_shape(&write_schema_json)?;
+
         Ok(Self {
             collection_name: name.clone(),
             document_uuid_ptr,
             key_extractors,
             partition_extractors,
+            redact_shape,
             resource_path: resource_path.clone(),
             ser_policy,
             write_schema_json: write_schema_json.clone(),
@@ -38,13 +38,27 @@ pub async fn flow_runtime_protocol(image: &str) -> anyhow::Result<RuntimeProtoco
     Ok(inspection.runtime_protocol)
 }
 
+/// Decode the connector resource requests and limits of a task's shard template.
+/// If the template is not available, then default resources are used.
+pub fn shard_template_resources(
+    shard_template: Option<&proto_gazette::consumer::ShardSpec>,
+) -> anyhow::Result<labels::shard::ConnectorResources> {
+    match shard_template.and_then(|spec| spec.labels.as_ref()) {
+        Some(set) => labels::shard::decode_connector_resources(set)
+            .context("decoding connector resources of shard template"),
+        None => Ok(Default::default()),
+    }
+}
+
 /// Start an image connector container, returning its description and a dialed tonic Channel.
 /// The container is attached to the given `network`, and its logs are dispatched to `log_handler`.
+/// `resources` are cgroup CPU and memory requests and limits of the container.
 /// `task_name` and `task_type` are used only to label the container.
 pub async fn start(
     image: &str,
     log_handler: impl crate::LogHandler,
     log_level: ops::LogLevel,
+    resources: labels::shard::ConnectorResources,
     network: &str,
     task_name: &str,
     task_type: ops::TaskType,
@@ -117,9 +131,18 @@ pub async fn start(
         "--env=LOG_FORMAT=json".to_string(),
         format!("--env=LOG_LEVEL={}", log_level.as_str_name()),
         // Cgroup memory / CPU resource limits.
-        // TODO(johnny): we intend to tighten these down further, over time.
-        "--memory=1g".to_string(),
-        "--cpus=2".to_string(),
+        format!(
+            "--memory={}",
+            resources
+                .memory_limit
+                .unwrap_or(models::ConnectorResources::DEFAULT_MEMORY_LIMIT)
+        ),
+        format!(
+            "--cpus={}",
+            resources
+                .cpu_limit
+                .unwrap_or(models::ConnectorResources::DEFAULT_CPU_LIMIT)
+        ),
         // For now, we support only Linux amd64 connectors.
         "--platform=linux/amd64".to_string(),
         // Attach labels that let us group connector resource usage under a few dimensions.
@@ -128,6 +151,18 @@ pub async fn start(
         format!("--label=task-type={}", task_type.as_str_name()),
     ];
 
+    // Requests are soft reservations which apply only under contention.
+    if let Some(memory_request) = resources.memory_request {
+        docker_args.push(format!("--memory-reservation={memory_request}"));
+    }
+    if let Some(cpu_request) = resources.cpu_request {
+        // CPU shares are relative weights, where 1024 is the weight of one CPU.
+        docker_args.push(format!(
+            "--cpu-shares={}",
+            (cpu_request * 1024.0).ceil() as u64
+        ));
+    }
+
     if publish_ports {
         // Bind a random port, and then check what port was given to us.
         let l = tokio::net::TcpListener::bind("0.0.0.0:0")
@@ -16,6 +16,7 @@ pub async fn start<L: LogHandler>(
     BoxStream<'static, anyhow::Result<Response>>,
 )> {
     let log_level = initial.get_internal()?.log_level();
+    let resources = extract_resources(&initial)?;
     let (endpoint, config_json) = extract_endpoint(&mut initial)?;
     let (mut connector_tx, connector_rx) = mpsc::channel(crate::CHANNEL_BUFFER);
 
@@ -52,6 +53,7 @@ pub async fn start<L: LogHandler>(
                 image,
                 runtime.log_handler.clone(),
                 log_level,
+                resources,
                 &runtime.container_network,
                 connector_rx,
                 start_rpc,
@@ -91,12 +93,36 @@ pub async fn start<L: LogHandler>(
             connector_tx.try_send(initial).unwrap();
             ::derive_sqlite::connector(connector_rx).boxed()
         }
+        models::DeriveUsing::Wasm(_) => {
+            connector_tx.try_send(initial).unwrap();
+            ::derive_wasm::connector(connector_rx).boxed()
+        }
+        models::DeriveUsing::Mapping(_) => {
+            connector_tx.try_send(initial).unwrap();
+            ::derive_mapping::connector(connector_rx).boxed()
+        }
         models::DeriveUsing::Typescript(_) => unreachable!(),
     };
 
     Ok((connector_tx, connector_rx))
 }
 
+// Extract connector resources from the task's shard template, if the request includes it.
+fn extract_resources(request: &Request) -> anyhow::Result<labels::shard::ConnectorResources> {
+    let derivation = match request {
+        Request {
+            open: Some(open), ..
+        } => open
+            .collection
+            .as_ref()
+            .and_then(|collection| collection.derivation.as_ref()),
+        _ => None,
+    };
+    crate::container::shard_template_resources(
+        derivation.and_then(|derivation| derivation.shard_template.as_ref()),
+    )
+}
+
 fn extract_endpoint<'r>(
     request: &'r mut Request,
 ) -> anyhow::Result<(models::DeriveUsing, &'r mut String)> {
@@ -145,6 +171,20 @@ fn extract_endpoint<'r>(
             ),
             config_json,
         ))
+    } else if connector_type == ConnectorType::Wasm as i32 {
+        Ok((
+            models::DeriveUsing::Wasm(
+                serde_json::from_str(config_json).context("parsing connector config")?,
+            ),
+            config_json,
+        ))
+    } else if connector_type == ConnectorType::Mapping as i32 {
+        Ok((
+            models::DeriveUsing::Mapping(
+                serde_json::from_str(config_json).context("parsing connector config")?,
+            ),
+            config_json,
+        ))
     } else if connector_type == ConnectorType::Typescript as i32 {
         Ok((
             models::DeriveUsing::Connector(models::ConnectorConfig {
@@ -25,6 +25,9 @@ pub struct Task {
     key_extractors: Vec<doc::Extractor>,
     // Partition values which are extracted from written documents.
     partition_extractors: Vec<doc::Extractor>,
+    // Shape of the derivation collection's write schema, if it has
+    // locations which must be redacted from published documents.
+    redact_shape: Option<doc::Shape>,
     // Serialization policy for the Target collection.
     ser_policy: doc::SerPolicy,
     // ShardRef of this task.
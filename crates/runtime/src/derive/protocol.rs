@@ -190,6 +190,10 @@ pub fn recv_connector_published_or_flushed(
         .doc_bytes_to_heap_node(doc_json.as_bytes())
         .context("couldn't parse derived document as JSON")?;
 
+    // Redact sensitive locations before the document is combined or persisted.
+    if let Some(shape) = &task.redact_shape {
+        shape.redact(&mut doc, alloc);
+    }
     let uuid_ptr = &task.document_uuid_ptr;
 
     if !uuid_ptr.0.is_empty() {
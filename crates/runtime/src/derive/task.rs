@@ -45,6 +45,7 @@ impl Task {
         let ser_policy = doc::SerPolicy::noop();
 
         let document_uuid_ptr = doc::Pointer::from(uuid_ptr);
+        let redact_shape = crate::redact_shape(&write_schema_json)?;
         let key_extractors = extractors::for_key(&key, &projections, &ser_policy)?;
         let partition_extractors =
             extractors::for_fields(&partition_fields, &projections, &ser_policy)?;
@@ -67,6 +68,7 @@ impl Task {
             document_uuid_ptr,
             key_extractors,
             partition_extractors,
+            redact_shape,
             ser_policy,
             shard_ref,
             transforms,
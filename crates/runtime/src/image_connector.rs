@@ -15,6 +15,7 @@ pub async fn serve<Request, Response, StartRpc, Attach>(
     image: String,            // Container image to run.
     log_handler: impl crate::LogHandler, // Handler for connector logs.
     log_level: ops::LogLevel, // Log-level of the connector, if known.
+    resources: labels::shard::ConnectorResources, // Container resource requests and limits.
     network: &str,            // Container network to use.
     request_rx: mpsc::Receiver<Request>, // Caller's input request stream.
     start_rpc: StartRpc,      // Begins RPC over a started container channel.
@@ -34,6 +35,7 @@ where
         &image,
         log_handler.clone(),
         log_level,
+        resources,
         &network,
         &task_name,
         task_type,
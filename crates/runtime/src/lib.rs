@@ -335,6 +335,20 @@ where
     })
 }
 
+// Infer the Shape of a collection's write schema, returning it only if it
+// has locations which must be redacted from written documents.
+fn redact_shape(write_schema_json: &str) -> anyhow::Result<Option<doc::Shape>> {
+    let schema = doc::validation::build_bundle(write_schema_json)
+        .context("collection write_schema_json is not a JSON schema")?;
+
+    let mut index = doc::SchemaIndexBuilder::new();
+    index.add(&schema)?;
+    let index = index.into_index();
+
+    let shape = doc::Shape::infer(&schema, &index);
+    Ok(shape.has_redactions().then_some(shape))
+}
+
 fn truncate_chars(s: &str, max_chars: usize) -> &str {
     match s.char_indices().nth(max_chars) {
         None => s,
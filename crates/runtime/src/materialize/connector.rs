@@ -16,6 +16,7 @@ pub async fn start<L: LogHandler>(
     BoxStream<'static, anyhow::Result<Response>>,
 )> {
     let log_level = initial.get_internal()?.log_level();
+    let resources = extract_resources(&initial)?;
     let (endpoint, config_json) = extract_endpoint(&mut initial)?;
     let (mut connector_tx, connector_rx) = mpsc::channel(crate::CHANNEL_BUFFER);
 
@@ -52,6 +53,7 @@ pub async fn start<L: LogHandler>(
                 image,
                 runtime.log_handler.clone(),
                 log_level,
+                resources,
                 &runtime.container_network,
                 connector_rx,
                 start_rpc,
@@ -95,6 +97,20 @@ pub async fn start<L: LogHandler>(
     Ok((connector_tx, connector_rx))
 }
 
+// Extract connector resources from the task's shard template, if the request includes it.
+fn extract_resources(request: &Request) -> anyhow::Result<labels::shard::ConnectorResources> {
+    let spec = match request {
+        Request {
+            apply: Some(apply), ..
+        } => apply.materialization.as_ref(),
+        Request {
+            open: Some(open), ..
+        } => open.materialization.as_ref(),
+        _ => None,
+    };
+    crate::container::shard_template_resources(spec.and_then(|spec| spec.shard_template.as_ref()))
+}
+
 fn extract_endpoint<'r>(
     request: &'r mut Request,
 ) -> anyhow::Result<(models::MaterializationEndpoint, &'r mut String)> {
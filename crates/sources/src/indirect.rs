@@ -351,6 +351,7 @@ fn indirect_derivation(
                 threshold,
             );
         }
+        models::DeriveUsing::Mapping(_) => {}
         models::DeriveUsing::Sqlite(models::DeriveUsingSqlite { migrations }) => {
             is_sql = true;
 
@@ -370,7 +371,7 @@ fn indirect_derivation(
                 );
             }
         }
-        models::DeriveUsing::Typescript(models::DeriveUsingTypescript { module }) => {
+        models::DeriveUsing::Typescript(models::DeriveUsingTypescript { module, .. }) => {
             indirect_raw(
                 Scope::new(scope)
                     .push_prop("derive")
@@ -384,6 +385,20 @@ fn indirect_derivation(
                 threshold,
             );
         }
+        models::DeriveUsing::Wasm(models::DeriveUsingWasm { module, .. }) => {
+            indirect_wasm(
+                Scope::new(scope)
+                    .push_prop("derive")
+                    .push_prop("using")
+                    .push_prop("wasm")
+                    .push_prop("module"),
+                module,
+                base,
+                imports,
+                resources,
+                threshold,
+            );
+        }
     }
 
     for (
@@ -660,6 +675,54 @@ fn indirect_raw(
         models::RawValue::from_string(serde_json::to_string(&filename).unwrap()).unwrap();
 }
 
+// Binary WebAssembly modules are embedded as base64 data URLs,
+// and are indirected as binary `.wasm` resources.
+// Other modules are WebAssembly text, indirected as `.wat` resources.
+fn indirect_wasm(
+    scope: Scope,
+    content_dom: &mut models::RawValue,
+    base: &str,
+    imports: &mut tables::Imports,
+    resources: &mut tables::Resources,
+    threshold: usize,
+) {
+    let binary = match serde_json::from_str::<&str>(content_dom.get()) {
+        Ok(module) => module
+            .strip_prefix(models::DeriveUsingWasm::DATA_URL_PREFIX)
+            .and_then(|b64| base64::decode(b64).ok()),
+        Err(_) => None,
+    };
+    let Some(binary) = binary else {
+        return indirect_raw(
+            scope,
+            content_dom,
+            format!("{base}.wat"),
+            imports,
+            resources,
+            threshold,
+        );
+    };
+
+    if content_dom.get().len() <= threshold {
+        return;
+    }
+    let scope = scope.flatten();
+    let filename = format!("{base}.wasm");
+
+    tables::Resource {
+        resource: scope.join(&filename).unwrap(),
+        content_type: ContentType::Config,
+        content: binary.into(),
+        content_dom: std::mem::take(content_dom),
+    }
+    .upsert_if_changed(resources);
+
+    imports.insert_row(&scope, scope.join(&filename).unwrap());
+
+    *content_dom =
+        models::RawValue::from_string(serde_json::to_string(&filename).unwrap()).unwrap();
+}
+
 fn base_name(name: &impl AsRef<str>) -> &str {
     let name = name.as_ref();
 
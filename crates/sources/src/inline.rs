@@ -164,6 +164,7 @@ fn inline_derivation(
             imports,
             resources,
         ),
+        models::DeriveUsing::Mapping(_) => {}
         models::DeriveUsing::Sqlite(models::DeriveUsingSqlite { migrations }) => {
             for (index, migration) in migrations.iter_mut().enumerate() {
                 inline_config(
@@ -179,7 +180,7 @@ fn inline_derivation(
                 );
             }
         }
-        models::DeriveUsing::Typescript(models::DeriveUsingTypescript { module }) => {
+        models::DeriveUsing::Typescript(models::DeriveUsingTypescript { module, .. }) => {
             inline_config(
                 Scope::new(scope)
                     .push_prop("derive")
@@ -191,6 +192,24 @@ fn inline_derivation(
                 resources,
             );
         }
+        models::DeriveUsing::Wasm(models::DeriveUsingWasm { module, .. })
+            if !serde_json::from_str::<&str>(module.get())
+                .map(models::DeriveUsingWasm::is_inline_module)
+                .unwrap_or_default() =>
+        {
+            inline_config(
+                Scope::new(scope)
+                    .push_prop("derive")
+                    .push_prop("using")
+                    .push_prop("wasm")
+                    .push_prop("module"),
+                module,
+                imports,
+                resources,
+            );
+        }
+        // Modules which are embedded inline have nothing to inline.
+        models::DeriveUsing::Wasm(_) => {}
     }
 
     for (
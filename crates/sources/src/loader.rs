@@ -36,6 +36,9 @@ pub enum LoadError {
     ResourceNotUTF8,
 }
 
+/// Magic bytes which begin every binary WebAssembly module.
+pub(crate) const WASM_MAGIC: &[u8] = b"\0asm";
+
 /// Fetcher resolves a resource URL to its byte content.
 pub trait Fetcher: Send + Sync {
     fn fetch<'a>(
@@ -144,6 +147,7 @@ impl<F: Fetcher> Loader<F> {
             // We must map the raw `content` into a document object model.
             // * If we expect this resource is a document, parse it as such.
             // * If it's UTF8, then wrap it in a string.
+            // * If it's a binary WebAssembly module, wrap it in a base64 data URL.
             // * Otherwise, record a LoadError for non-UTF8 content.
             let content_dom = if is_dom {
                 // Parse YAML and JSON.
@@ -167,6 +171,15 @@ impl<F: Fetcher> Loader<F> {
                 serde_transcode::transcode(content_dom, &mut serializer).expect("must transcode");
 
                 RawValue::from_string(String::from_utf8(buf).unwrap()).unwrap()
+            } else if content.starts_with(WASM_MAGIC) {
+                // Binary modules may also happen to be valid UTF-8,
+                // so they're checked for before text content.
+                let content = format!(
+                    "{}{}",
+                    models::DeriveUsingWasm::DATA_URL_PREFIX,
+                    base64::encode(&content)
+                );
+                RawValue::from_string(serde_json::to_string(&content).unwrap()).unwrap()
             } else if let Ok(content) = std::str::from_utf8(&content) {
                 RawValue::from_string(serde_json::to_string(&content).unwrap()).unwrap()
             } else {
@@ -525,6 +538,28 @@ impl<F: Fetcher> Loader<F> {
                     .boxed(),
                 );
             }
+            models::DeriveUsing::Wasm(models::DeriveUsingWasm { module, .. }) => {
+                // Modules which are already embedded inline have nothing to load.
+                let is_inline = serde_json::from_str::<&str>(module.get())
+                    .map(models::DeriveUsingWasm::is_inline_module)
+                    .unwrap_or_default();
+
+                if !is_inline {
+                    tasks.push(
+                        async move {
+                            self.load_config(
+                                scope
+                                    .push_prop("using")
+                                    .push_prop("wasm")
+                                    .push_prop("module"),
+                                module,
+                            )
+                            .await
+                        }
+                        .boxed(),
+                    );
+                }
+            }
             models::DeriveUsing::Local(models::LocalConfig { config, .. }) => {
                 tasks.push(
                     async move {
@@ -540,6 +575,8 @@ impl<F: Fetcher> Loader<F> {
                     .boxed(),
                 );
             }
+            // Mapping derivations have no configuration to load.
+            models::DeriveUsing::Mapping(_) => {}
         };
 
         for (index, transform) in spec.transforms.iter().enumerate() {
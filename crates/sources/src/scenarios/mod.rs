@@ -74,6 +74,7 @@ mod test {
         test_schema_with_references,
         test_simple_catalog,
         test_test_case,
+        test_wasm_modules,
     }
 }
 // MockFetcher queues and returns oneshot futures for started fetches.
@@ -89,6 +89,10 @@ tables!(
         val broker_address: String,
         // Address of reactors within the data-plane.
         val reactor_address: String,
+        // Maximum CPU limit of connector containers within the data-plane.
+        val connector_cpu_limit_max: f64,
+        // Maximum memory limit, in bytes, of connector containers within the data-plane.
+        val connector_memory_limit_max: i64,
     }
 
     table RoleGrants (row #[derive(serde::Deserialize, serde::Serialize)] RoleGrant, sql "role_grants") {
@@ -401,6 +405,8 @@ primitive_sql_types!(
     url::Url => "TEXT",
     bool => "BOOLEAN",
     u32 => "INTEGER",
+    i64 => "INTEGER",
+    f64 => "REAL",
 );
 
 // primitive_sql_types generates SqlColumn but not Column implementations.
@@ -425,6 +431,8 @@ impl Column for bool {
     }
 }
 impl Column for u32 {}
+impl Column for i64 {}
+impl Column for f64 {}
 
 string_wrapper_types!(
     models::Capture,
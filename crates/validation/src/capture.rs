@@ -1,5 +1,6 @@
 use super::{
-    indexed, reference, storage_mapping, walk_transition, Connectors, Error, NoOpConnectors, Scope,
+    indexed, reference, shard_template, storage_mapping, walk_transition, Connectors, Error,
+    NoOpConnectors, Scope,
 };
 use itertools::Itertools;
 use proto_flow::{capture, flow, ops::log::Level as LogLevel};
@@ -149,6 +150,8 @@ async fn walk_capture(
     let data_plane =
         reference::walk_data_plane(scope, capture, data_plane_id, data_planes, errors)?;
 
+    shard_template::walk_connector_resources(scope, capture, shard_template, data_plane, errors);
+
     // We've completed all cheap validation checks.
     // If we've already encountered errors then stop now.
     if !errors.is_empty() {
@@ -284,14 +284,7 @@ fn walk_collection_projections(
         .iter()
         .filter_map(|(field, projection)| {
             let scope = scope.push_prop(field);
-
-            let (ptr, partition) = match projection {
-                models::Projection::Pointer(ptr) => (ptr, false),
-                models::Projection::Extended {
-                    location,
-                    partition,
-                } => (location, *partition),
-            };
+            let (_, partition) = projection.as_parts();
 
             if partition {
                 indexed::walk_name(
@@ -303,6 +296,27 @@ fn walk_collection_projections(
                 );
             }
 
+            let ptr = match projection {
+                models::Projection::Pointer(ptr)
+                | models::Projection::Extended { location: ptr, .. } => ptr,
+                models::Projection::Computed { expression, .. } => {
+                    // Partitioned projections must also be computable from the write schema.
+                    let write_schema = match read_schema_bundle {
+                        Some(_) if partition => Some(write_schema),
+                        _ => None,
+                    };
+                    return Some(walk_computed_projection(
+                        scope,
+                        field,
+                        expression,
+                        partition,
+                        effective_read_schema,
+                        write_schema,
+                        errors,
+                    ));
+                }
+            };
+
             if ptr.as_str() == "" {
                 saw_root_projection = true;
             } else if ptr.as_str() == UUID_DATE_TIME_PTR && !partition {
@@ -340,6 +354,7 @@ fn walk_collection_projections(
                 is_primary_key: key.iter().any(|k| k == ptr),
                 is_partition_key: partition,
                 inference: Some(assemble::inference(r_shape, r_exists)),
+                expression_json: String::new(),
             })
         })
         .collect::<Vec<_>>();
@@ -391,6 +406,7 @@ fn walk_collection_projections(
             is_primary_key: true,
             is_partition_key: false,
             inference: Some(assemble::inference(r_shape, r_exists)),
+            expression_json: String::new(),
         });
     }
 
@@ -418,6 +434,7 @@ fn walk_collection_projections(
             is_primary_key: false,
             is_partition_key: false,
             inference: Some(assemble::inference(r_shape, r_exists)),
+            expression_json: String::new(),
         });
     }
 
@@ -446,6 +463,144 @@ fn walk_collection_projections(
     projections
 }
 
+// Walk a computed projection, mapping it into a built flow::Projection
+// having a synthetic location and an inference of its computed value.
+fn walk_computed_projection(
+    scope: Scope,
+    field: &models::Field,
+    expression: &models::ProjectionExpression,
+    partition: bool,
+    read_schema: &schema::Schema,
+    write_schema: Option<&schema::Schema>,
+    errors: &mut tables::Errors,
+) -> flow::Projection {
+    use models::ProjectionExpression as Expr;
+    let scope = scope.push_prop("expression");
+
+    // Locations referenced by the expression, and the types they may have.
+    let (locations, expect): (Vec<&models::JsonPointer>, types::Set) = match expression {
+        Expr::Concat { of, .. } | Expr::Hash(of) | Expr::Coalesce(of) => {
+            (of.iter().collect(), types::ANY)
+        }
+        Expr::Lower(ptr) | Expr::Upper(ptr) | Expr::DateTrunc { location: ptr, .. } => {
+            (vec![ptr], types::STRING | types::NULL)
+        }
+    };
+
+    if locations.is_empty() {
+        Error::ProjectionExpressionEmpty {
+            field: field.to_string(),
+        }
+        .push(scope, errors);
+    }
+
+    for ptr in &locations {
+        for schema in std::iter::once(read_schema).chain(write_schema) {
+            if let Err(err) = schema.walk_ptr(ptr, false) {
+                Error::from(err).push(scope, errors);
+                continue;
+            }
+            let (shape, _exists) = schema.shape.locate(&doc::Pointer::from_str(ptr));
+
+            if shape.type_ - expect != types::INVALID {
+                Error::ProjectionExpressionType {
+                    field: field.to_string(),
+                    ptr: ptr.to_string(),
+                    type_: shape.type_,
+                    expect,
+                    schema: schema.curi.clone(),
+                }
+                .push(scope, errors);
+            }
+
+            // DateTrunc parses its location as a date-time, which must be
+            // guaranteed by the schema's format.
+            if let Expr::DateTrunc { .. } = expression {
+                let expect = json::schema::formats::Format::DateTime;
+
+                if shape.type_.overlaps(types::STRING) && shape.string.format != Some(expect) {
+                    Error::ProjectionExpressionFormat {
+                        field: field.to_string(),
+                        ptr: ptr.to_string(),
+                        format: shape.string.format,
+                        expect,
+                        schema: schema.curi.clone(),
+                    }
+                    .push(scope, errors);
+                }
+            }
+        }
+    }
+
+    // Is the location possibly null, or possibly undefined?
+    let maybe_null = |ptr: &models::JsonPointer| {
+        let (shape, exists) = read_schema.shape.locate(&doc::Pointer::from_str(ptr));
+        shape.type_.overlaps(types::NULL) || exists != doc::shape::location::Exists::Must
+    };
+
+    let nullable = |is_null: bool| {
+        if is_null {
+            types::NULL
+        } else {
+            types::INVALID
+        }
+    };
+
+    let shape = match expression {
+        Expr::Concat { .. } | Expr::Hash(_) => {
+            let mut shape = doc::Shape::anything();
+            shape.type_ = types::STRING;
+            shape
+        }
+        Expr::Lower(ptr) | Expr::Upper(ptr) => {
+            let mut shape = doc::Shape::anything();
+            shape.type_ = types::STRING | nullable(maybe_null(ptr));
+            shape
+        }
+        Expr::DateTrunc { location, .. } => {
+            let mut shape = doc::Shape::anything();
+            shape.type_ = types::STRING | nullable(maybe_null(location));
+            shape.string.format = Some(json::schema::formats::Format::DateTime);
+            shape
+        }
+        Expr::Coalesce(of) => {
+            // Coalesce is null only if every location may be null.
+            let mut shape = doc::Shape::nothing();
+            for ptr in of {
+                let (r_shape, _exists) = read_schema.shape.locate(&doc::Pointer::from_str(ptr));
+                shape = doc::Shape::union(shape, r_shape.clone());
+            }
+            let null = nullable(of.iter().all(|ptr| maybe_null(ptr)));
+            shape.type_ = (shape.type_ - types::NULL) | null;
+            shape
+        }
+    };
+
+    if partition && !shape.type_.is_keyable_type() {
+        Error::ProjectionExpressionNotKeyable {
+            field: field.to_string(),
+            type_: shape.type_,
+        }
+        .push(scope, errors);
+    }
+
+    let mut ptr = doc::Pointer::from_str(COMPUTED_PTR);
+    ptr.push(doc::ptr::Token::Property(field.to_string()));
+
+    flow::Projection {
+        ptr: ptr.to_string(),
+        field: field.to_string(),
+        explicit: true,
+        is_primary_key: false,
+        is_partition_key: partition,
+        inference: Some(assemble::inference(
+            &shape,
+            doc::shape::location::Exists::Must,
+        )),
+        expression_json: serde_json::to_string(expression).unwrap(),
+    }
+}
+
 pub fn walk_selector(
     scope: Scope,
     collection: &flow::CollectionSpec,
@@ -527,5 +682,9 @@ const UUID_PTR: &str = "/_meta/uuid";
 /// This pointer typically pairs with the FLOW_PUBLISHED_AT field.
 const UUID_DATE_TIME_PTR: &str = "/_meta/uuid/date-time";
 
+/// The JSON Pointer under which computed projections are given synthetic locations.
+/// Computed projections are evaluated, and their locations don't exist in documents.
+const COMPUTED_PTR: &str = "/_meta/computed";
+
 /// Used to check if a pointer ends with an empty key, so we can skip projecting those fields.
 const EMPTY_KEY: &'static [doc::ptr::Token] = &[doc::ptr::Token::Property(String::new())];
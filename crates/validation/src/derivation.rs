@@ -1,6 +1,6 @@
 use super::{
-    collection, indexed, reference, schema, storage_mapping, Connectors, Error, NoOpConnectors,
-    Scope,
+    collection, indexed, mapping, reference, schema, shard_template, storage_mapping, Connectors,
+    Error, NoOpConnectors, Scope,
 };
 use proto_flow::{
     derive, flow,
@@ -198,6 +198,14 @@ async fn walk_derivation(
             ConnectorType::Typescript as i32,
             serde_json::to_string(config).unwrap(),
         ),
+        models::DeriveUsing::Wasm(config) => (
+            ConnectorType::Wasm as i32,
+            serde_json::to_string(config).unwrap(),
+        ),
+        models::DeriveUsing::Mapping(config) => (
+            ConnectorType::Mapping as i32,
+            serde_json::to_string(config).unwrap(),
+        ),
     };
 
     let scope_transforms = scope.push_prop("transforms");
@@ -325,6 +333,25 @@ async fn walk_derivation(
         errors,
     )?;
 
+    shard_template::walk_connector_resources(
+        scope,
+        &built_collection.collection,
+        shard_template,
+        data_plane,
+        errors,
+    );
+
+    // Mapping lambdas are declarative, and are validated statically.
+    if let models::DeriveUsing::Mapping(_) = using {
+        mapping::walk_mapping_transforms(
+            scope_transforms,
+            &enabled_transforms,
+            built_collections,
+            built_collection,
+            errors,
+        );
+    }
+
     // We've completed all cheap validation checks.
     // If we've already encountered errors then stop now.
     if !errors.is_empty() {
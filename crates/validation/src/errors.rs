@@ -113,6 +113,26 @@ pub enum Error {
         canonical_ptr: String,
         wrong_ptr: String,
     },
+    #[error("computed projection {field} requires that location {ptr} be {expect}, but schema {schema} accepts {type_}")]
+    ProjectionExpressionType {
+        field: String,
+        ptr: String,
+        type_: types::Set,
+        expect: types::Set,
+        schema: Url,
+    },
+    #[error("computed projection {field} requires that location {ptr} have format {expect}, but schema {schema} has format {format:?}")]
+    ProjectionExpressionFormat {
+        field: String,
+        ptr: String,
+        format: Option<json::schema::formats::Format>,
+        expect: json::schema::formats::Format,
+        schema: Url,
+    },
+    #[error("computed projection {field} must reference at least one location")]
+    ProjectionExpressionEmpty { field: String },
+    #[error("computed projection {field} produces {type_}, but partitions may only be null-able integers, strings, or booleans")]
+    ProjectionExpressionNotKeyable { field: String, type_: types::Set },
     #[error("{category} partition selector field {field} value {value} is incompatible with the projections type, {type_:?}")]
     SelectorTypeMismatch {
         category: String,
@@ -143,6 +163,26 @@ pub enum Error {
     },
     #[error("transform {transform} is missing `shuffle`, which is now a required field (https://go.estuary.dev/LK19Py). If you're unsure of what shuffle to use, try `shuffle: any`")]
     ShuffleUnset { transform: String },
+    #[error("transform {transform} lambda is not a valid mapping: {detail}")]
+    MappingLambdaInvalid {
+        transform: String,
+        detail: serde_json::Error,
+    },
+    #[error(
+        "mapping predicate of {ptr} must have exactly one of `eq`, `in`, `exists`, or `range`"
+    )]
+    MappingPredicateTests { ptr: String },
+    #[error("mapping field {ptr} must have exactly one of `from` or `const`")]
+    MappingFieldSource { ptr: String },
+    #[error("mapping field {ptr} may produce {type_}, but schema {schema} accepts only {expect} at that location")]
+    MappingFieldType {
+        ptr: String,
+        type_: types::Set,
+        expect: types::Set,
+        schema: Url,
+    },
+    #[error("transform {transform} mapping doesn't produce collection key location {ptr}")]
+    MappingKeyUnmapped { transform: String, ptr: String },
     #[error("connector returned an invalid generated file URL {url:?}")]
     InvalidGeneratedFileUrl {
         url: String,
@@ -177,7 +217,7 @@ pub enum Error {
     TestStepNotBeforeAfter,
     #[error("a `notBefore` constraint must happen before `notAfter`")]
     NotBeforeAfterOrder,
-    #[error("test ingest document is invalid against the collection schema: {}", serde_json::to_string_pretty(.0).unwrap())]
+    #[error("test ingest document is invalid against the collection schema: {0}")]
     IngestDocInvalid(doc::FailedValidation),
     #[error("{entity} {name} bindings duplicate the endpoint resource {resource} at {rhs_scope}")]
     BindingDuplicatesResource {
@@ -232,6 +272,33 @@ pub enum Error {
         this_entity: String,
         data_plane_id: models::Id,
     },
+    #[error("{this_entity} {resource} {value:?} is not a valid memory size (expected a number of bytes with optional 'k', 'm', or 'g' suffix)")]
+    ConnectorMemoryInvalid {
+        this_entity: String,
+        resource: &'static str,
+        value: String,
+    },
+    #[error("{this_entity} {resource} {value} must be greater than zero")]
+    ConnectorResourceNotPositive {
+        this_entity: String,
+        resource: &'static str,
+        value: String,
+    },
+    #[error("{this_entity} {resource} {value} exceeds the maximum of its data-plane ({maximum})")]
+    ConnectorResourceExceedsMaximum {
+        this_entity: String,
+        resource: &'static str,
+        value: String,
+        maximum: String,
+    },
+    #[error("{this_entity} {request} {request_value} exceeds its effective {limit} {limit_value}")]
+    ConnectorRequestExceedsLimit {
+        this_entity: String,
+        request: &'static str,
+        request_value: String,
+        limit: &'static str,
+        limit_value: String,
+    },
     #[error("expected draft model to be equal to the live model because `is_touch: true`")]
     TouchModelChanged,
     #[error("cannot touch because live model does not exist")]
@@ -7,10 +7,12 @@ mod collection;
 mod derivation;
 mod errors;
 mod indexed;
+mod mapping;
 mod materialization;
 mod noop;
 mod reference;
 mod schema;
+mod shard_template;
 mod storage_mapping;
 mod test_step;
 
@@ -0,0 +1,180 @@
+use super::{schema, Error, Scope};
+use json::schema::types;
+
+// Statically validate the mapping lambdas of a mapping derivation's transforms
+// against the schemas of their source collections and the derived collection.
+pub fn walk_mapping_transforms(
+    scope: Scope,
+    enabled_transforms: &[(usize, &models::TransformDef)],
+    built_collections: &tables::BuiltCollections,
+    built_collection: &tables::BuiltCollection,
+    errors: &mut tables::Errors,
+) {
+    // Collections without a built specification are being deleted.
+    let Some(target_spec) = &built_collection.spec else {
+        return;
+    };
+    let target_schema = schema::Schema::new(&target_spec.write_schema_json).unwrap();
+
+    for (transform_index, transform) in enabled_transforms {
+        let scope = scope.push_item(*transform_index);
+        let models::TransformDef {
+            name,
+            source,
+            lambda,
+            ..
+        } = transform;
+
+        let lambda: models::MappingLambda = match serde_json::from_str(lambda.get()) {
+            Ok(lambda) => lambda,
+            Err(detail) => {
+                Error::MappingLambdaInvalid {
+                    transform: name.to_string(),
+                    detail,
+                }
+                .push(scope.push_prop("lambda"), errors);
+                continue;
+            }
+        };
+        let scope = scope.push_prop("lambda");
+
+        // Errors of the source reference were already reported.
+        let Ok(source_index) =
+            built_collections.binary_search_by_key(&source.collection(), |b| &b.collection)
+        else {
+            continue;
+        };
+        // Errors of deleted source collections were also already reported.
+        let Some(source_spec) = &built_collections[source_index].spec else {
+            continue;
+        };
+        let source_schema = schema::Schema::new(if source_spec.read_schema_json.is_empty() {
+            &source_spec.write_schema_json
+        } else {
+            &source_spec.read_schema_json
+        })
+        .unwrap();
+
+        walk_mapping_lambda(scope, &lambda, &source_schema, &target_schema, errors);
+
+        // Every component of the collection key must be produced by the mapping.
+        for key_ptr in &target_spec.key {
+            let is_mapped = lambda.fields.keys().any(|ptr| {
+                key_ptr == ptr.as_str()
+                    || key_ptr
+                        .strip_prefix(ptr.as_str())
+                        .is_some_and(|suffix| suffix.starts_with('/'))
+            });
+            if !is_mapped {
+                Error::MappingKeyUnmapped {
+                    transform: name.to_string(),
+                    ptr: key_ptr.clone(),
+                }
+                .push(scope.push_prop("fields"), errors);
+            }
+        }
+    }
+}
+
+fn walk_mapping_lambda(
+    scope: Scope,
+    lambda: &models::MappingLambda,
+    source_schema: &schema::Schema,
+    target_schema: &schema::Schema,
+    errors: &mut tables::Errors,
+) {
+    let models::MappingLambda { filter, fields } = lambda;
+
+    let scope_filter = scope.push_prop("filter");
+    let scope_fields = scope.push_prop("fields");
+
+    for (index, predicate) in filter.iter().enumerate() {
+        let scope = scope_filter.push_item(index);
+        let models::MappingPredicate {
+            ptr,
+            eq,
+            in_,
+            exists,
+            range,
+        } = predicate;
+
+        let tests = [
+            eq.is_some(),
+            in_.is_some(),
+            exists.is_some(),
+            range.is_some(),
+        ];
+        if tests.into_iter().filter(|t| *t).count() != 1 {
+            Error::MappingPredicateTests {
+                ptr: ptr.to_string(),
+            }
+            .push(scope, errors);
+        }
+        walk_mapping_ptr(scope.push_prop("ptr"), source_schema, ptr, errors);
+    }
+
+    for (ptr, field) in fields {
+        let scope = scope_fields.push_prop(ptr);
+        let models::MappingField { from, const_, cast } = field;
+
+        if !walk_mapping_ptr(scope, target_schema, ptr, errors) {
+            continue;
+        }
+
+        // Determine the types which this field may produce.
+        let type_ = match (from, const_) {
+            (Some(from), None) => {
+                if !walk_mapping_ptr(scope.push_prop("from"), source_schema, from, errors) {
+                    continue;
+                }
+                let (shape, _exists) = source_schema.shape.locate(&doc::Pointer::from_str(from));
+                shape.type_
+            }
+            (None, Some(value)) => types::Set::for_value(value),
+            _ => {
+                Error::MappingFieldSource {
+                    ptr: ptr.to_string(),
+                }
+                .push(scope, errors);
+                continue;
+            }
+        };
+        // Casts produce their type, but nulls are not cast.
+        let type_ = match cast {
+            None => type_,
+            Some(models::MappingCast::Boolean) => types::BOOLEAN | (type_ & types::NULL),
+            Some(models::MappingCast::Integer) => types::INTEGER | (type_ & types::NULL),
+            Some(models::MappingCast::Number) => types::INT_OR_FRAC | (type_ & types::NULL),
+            Some(models::MappingCast::String) => types::STRING | (type_ & types::NULL),
+        };
+
+        let (shape, _exists) = target_schema.shape.locate(&doc::Pointer::from_str(ptr));
+
+        if type_ - shape.type_ != types::INVALID {
+            Error::MappingFieldType {
+                ptr: ptr.to_string(),
+                type_,
+                expect: shape.type_,
+                schema: target_schema.curi.clone(),
+            }
+            .push(scope, errors);
+        }
+    }
+}
+
+// Walk a mapping location of a schema, returning whether it's valid.
+// Unlike keys and projections, mapped locations may be unknown to the schema.
+fn walk_mapping_ptr(
+    scope: Scope,
+    schema: &schema::Schema,
+    ptr: &models::JsonPointer,
+    errors: &mut tables::Errors,
+) -> bool {
+    match schema.walk_ptr(ptr, false) {
+        Ok(()) | Err(Error::PtrIsImplicit { .. }) => true,
+        Err(err) => {
+            err.push(scope, errors);
+            false
+        }
+    }
+}
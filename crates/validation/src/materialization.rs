@@ -1,6 +1,6 @@
 use super::{
-    collection, indexed, reference, storage_mapping, walk_transition, Connectors, Error,
-    NoOpConnectors, Scope,
+    collection, indexed, reference, shard_template, storage_mapping, walk_transition, Connectors,
+    Error, NoOpConnectors, Scope,
 };
 use itertools::Itertools;
 use proto_flow::{flow, materialize, ops::log::Level as LogLevel};
@@ -164,6 +164,14 @@ async fn walk_materialization(
     let data_plane =
         reference::walk_data_plane(scope, materialization, data_plane_id, data_planes, errors)?;
 
+    shard_template::walk_connector_resources(
+        scope,
+        materialization,
+        shard_template,
+        data_plane,
+        errors,
+    );
+
     // We've completed all cheap validation checks.
     // If we've already encountered errors then stop now.
     if !errors.is_empty() {
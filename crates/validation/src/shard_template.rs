@@ -0,0 +1,116 @@
+use super::{Error, Scope};
+
+/// Validate the connector resources of a task's ShardTemplate,
+/// which must be within the bounds of the task's data-plane.
+pub fn walk_connector_resources(
+    scope: Scope,
+    this_entity: &str,
+    shard_template: &models::ShardTemplate,
+    data_plane: &tables::DataPlane,
+    errors: &mut tables::Errors,
+) {
+    let Some(models::ConnectorResources {
+        cpu_request,
+        cpu_limit,
+        memory_request,
+        memory_limit,
+    }) = &shard_template.resources
+    else {
+        return;
+    };
+    let scope = scope.push_prop("shards");
+    let scope = scope.push_prop("resources");
+
+    let mut walk_cpus = |prop: &'static str, value: &Option<f64>| -> Option<f64> {
+        let value = (*value)?;
+
+        if value <= 0.0 {
+            Error::ConnectorResourceNotPositive {
+                this_entity: this_entity.to_string(),
+                resource: prop,
+                value: value.to_string(),
+            }
+            .push(scope.push_prop(prop), errors);
+            None
+        } else if value > data_plane.connector_cpu_limit_max {
+            Error::ConnectorResourceExceedsMaximum {
+                this_entity: this_entity.to_string(),
+                resource: prop,
+                value: value.to_string(),
+                maximum: data_plane.connector_cpu_limit_max.to_string(),
+            }
+            .push(scope.push_prop(prop), errors);
+            None
+        } else {
+            Some(value)
+        }
+    };
+    let cpu_request = walk_cpus("cpuRequest", cpu_request);
+    let cpu_limit = walk_cpus("cpuLimit", cpu_limit);
+
+    let mut walk_memory = |prop: &'static str, value: &Option<String>| -> Option<u64> {
+        let value = value.as_ref()?;
+
+        let Some(bytes) = models::ConnectorResources::parse_memory(value) else {
+            Error::ConnectorMemoryInvalid {
+                this_entity: this_entity.to_string(),
+                resource: prop,
+                value: value.clone(),
+            }
+            .push(scope.push_prop(prop), errors);
+            return None;
+        };
+        if bytes == 0 {
+            Error::ConnectorResourceNotPositive {
+                this_entity: this_entity.to_string(),
+                resource: prop,
+                value: value.clone(),
+            }
+            .push(scope.push_prop(prop), errors);
+            None
+        } else if bytes > data_plane.connector_memory_limit_max.max(0) as u64 {
+            Error::ConnectorResourceExceedsMaximum {
+                this_entity: this_entity.to_string(),
+                resource: prop,
+                value: value.clone(),
+                maximum: format!("{} bytes", data_plane.connector_memory_limit_max),
+            }
+            .push(scope.push_prop(prop), errors);
+            None
+        } else {
+            Some(bytes)
+        }
+    };
+    let memory_request = walk_memory("memoryRequest", memory_request);
+    let memory_limit = walk_memory("memoryLimit", memory_limit);
+
+    // Requests may not exceed their effective limits.
+    if let Some(request) = cpu_request {
+        let limit = cpu_limit.unwrap_or(models::ConnectorResources::DEFAULT_CPU_LIMIT);
+
+        if request > limit {
+            Error::ConnectorRequestExceedsLimit {
+                this_entity: this_entity.to_string(),
+                request: "cpuRequest",
+                request_value: request.to_string(),
+                limit: "cpuLimit",
+                limit_value: limit.to_string(),
+            }
+            .push(scope.push_prop("cpuRequest"), errors);
+        }
+    }
+    if let Some(request) = memory_request {
+        let limit = memory_limit.unwrap_or(models::ConnectorResources::DEFAULT_MEMORY_LIMIT);
+
+        if request > limit {
+            Error::ConnectorRequestExceedsLimit {
+                this_entity: this_entity.to_string(),
+                request: "memoryRequest",
+                request_value: format!("{request} bytes"),
+                limit: "memoryLimit",
+                limit_value: format!("{limit} bytes"),
+            }
+            .push(scope.push_prop("memoryRequest"), errors);
+        }
+    }
+}
@@ -131,6 +131,8 @@ pub fn run(fixture_yaml: &str, patch_yaml: &str) -> Outcome {
             models::Collection::new("ops/stats"),
             "broker:address".to_string(),
             "reactor:address".to_string(),
+            4.0,
+            8 << 30,
         );
     }
 
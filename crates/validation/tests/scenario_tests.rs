@@ -646,6 +646,81 @@ test://example/int-halve:
     insta::assert_debug_snapshot!(errors);
 }
 
+#[test]
+fn test_mapping_derivation_errors() {
+    let errors = common::run_errors(
+        &MODEL_YAML,
+        r#"
+test://example/int-halve:
+  collections:
+    testing/int-halve:
+      derive:
+        using:
+          typescript: null
+          mapping: {}
+        transforms:
+          - name: halveIntString
+            source: testing/int-string
+            shuffle: any
+            lambda:
+              filter:
+                - ptr: /bit
+                  eq: true
+                  exists: true
+                - ptr: /double//slash
+                  exists: true
+              fields:
+                /bit: { const: true }
+                /extra: { const: nope }
+                /len: { from: /str }
+                /other: { from: /int, const: 1 }
+                /partitionString: { from: /str }
+                /str: { from: /str, cast: integer }
+          - name: halveSelf
+            source: testing/int-halve
+            shuffle: any
+            lambda: not a mapping
+"#,
+    );
+    insta::assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn test_computed_projection_errors() {
+    let errors = common::run_errors(
+        &MODEL_YAML,
+        r#"
+test://example/int-string:
+  collections:
+    testing/int-string:
+      projections:
+        coalesced:
+          expression: { coalesce: [/str, /int] }
+          partition: true
+        hashed:
+          expression: { hash: [/int, /str] }
+          partition: true
+        lowerInt:
+          expression: { lower: /int }
+        lowerStr:
+          expression: { lower: /str }
+        truncStr:
+          expression: { dateTrunc: { location: /str, unit: day } }
+        truncTs:
+          expression: { dateTrunc: { location: /ts, unit: hour } }
+        noLocations:
+          expression: { concat: { of: [] } }
+        unknown:
+          expression: { coalesce: [/missing] }
+
+test://example/int-string.schema:
+  properties:
+    ts: { type: string, format: date-time }
+"#,
+    );
+    insta::assert_debug_snapshot!(errors);
+}
+
 #[test]
 fn test_shuffle_key_length_mismatch() {
     let errors = common::run_errors(
@@ -1292,3 +1367,93 @@ driver:
     );
     insta::assert_debug_snapshot!(errors);
 }
+
+#[test]
+fn test_connector_resources_exceed_maximum() {
+    let errors = common::run_errors(
+        &MODEL_YAML,
+        r#"
+test://example/int-string-captures:
+  captures:
+    testing/s3-source:
+      shards:
+        resources:
+          cpuLimit: 4.5
+          memoryLimit: 9g
+test://example/int-halve:
+  collections:
+    testing/int-halve:
+      derive:
+        shards:
+          resources:
+            # Values equal to the data-plane maximum are allowed.
+            cpuRequest: 4
+            cpuLimit: 4
+            memoryLimit: 16g
+"#,
+    );
+    insta::assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn test_connector_resources_not_positive() {
+    let errors = common::run_errors(
+        &MODEL_YAML,
+        r#"
+test://example/int-string-captures:
+  captures:
+    testing/s3-source:
+      shards:
+        resources:
+          cpuRequest: 0
+          cpuLimit: -1.5
+          memoryRequest: "0"
+          memoryLimit: 0m
+"#,
+    );
+    insta::assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn test_connector_memory_invalid() {
+    let errors = common::run_errors(
+        &MODEL_YAML,
+        r#"
+test://example/webhook-deliveries:
+  materializations:
+    testing/webhook/deliveries:
+      shards:
+        resources:
+          memoryRequest: 1.5g
+          memoryLimit: 2GB
+"#,
+    );
+    insta::assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn test_connector_request_exceeds_limit() {
+    let errors = common::run_errors(
+        &MODEL_YAML,
+        r#"
+test://example/int-string-captures:
+  captures:
+    testing/s3-source:
+      shards:
+        resources:
+          cpuRequest: 2
+          cpuLimit: 1
+          memoryRequest: 2g
+          memoryLimit: 1g
+test://example/webhook-deliveries:
+  materializations:
+    testing/webhook/deliveries:
+      shards:
+        resources:
+          # Requests are also bounded by default limits.
+          cpuRequest: 3
+          memoryRequest: 2g
+"#,
+    );
+    insta::assert_debug_snapshot!(errors);
+}
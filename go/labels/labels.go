@@ -60,6 +60,14 @@ const (
 	LogsJournal = "estuary.dev/logs-journal"
 	// Journal to which task stats are directed.
 	StatsJournal = "estuary.dev/stats-journal"
+	// ConnectorCPURequest is the number of CPUs reserved for the task's connector containers.
+	ConnectorCPURequest = "estuary.dev/connector-cpu-request"
+	// ConnectorCPULimit is the maximum number of CPUs usable by the task's connector containers.
+	ConnectorCPULimit = "estuary.dev/connector-cpu-limit"
+	// ConnectorMemoryRequest is the bytes of memory reserved for the task's connector containers.
+	ConnectorMemoryRequest = "estuary.dev/connector-memory-request"
+	// ConnectorMemoryLimit is the maximum bytes of memory usable by the task's connector containers.
+	ConnectorMemoryLimit = "estuary.dev/connector-memory-limit"
 
 	Hostname = "estuary.dev/hostname"
 
@@ -120,6 +120,8 @@ const (
 	CollectionSpec_Derivation_TYPESCRIPT             CollectionSpec_Derivation_ConnectorType = 2
 	CollectionSpec_Derivation_IMAGE                  CollectionSpec_Derivation_ConnectorType = 3
 	CollectionSpec_Derivation_LOCAL                  CollectionSpec_Derivation_ConnectorType = 4
+	CollectionSpec_Derivation_WASM                   CollectionSpec_Derivation_ConnectorType = 5
+	CollectionSpec_Derivation_MAPPING                CollectionSpec_Derivation_ConnectorType = 6
 )
 
 var CollectionSpec_Derivation_ConnectorType_name = map[int32]string{
@@ -128,6 +130,8 @@ var CollectionSpec_Derivation_ConnectorType_name = map[int32]string{
 	2: "TYPESCRIPT",
 	3: "IMAGE",
 	4: "LOCAL",
+	5: "WASM",
+	6: "MAPPING",
 }
 
 var CollectionSpec_Derivation_ConnectorType_value = map[string]int32{
@@ -136,6 +140,8 @@ var CollectionSpec_Derivation_ConnectorType_value = map[string]int32{
 	"TYPESCRIPT":             2,
 	"IMAGE":                  3,
 	"LOCAL":                  4,
+	"WASM":                   5,
+	"MAPPING":                6,
 }
 
 func (x CollectionSpec_Derivation_ConnectorType) String() string {
@@ -470,10 +476,14 @@ type Projection struct {
 	// Does this location form (part of) the collection key?
 	IsPrimaryKey bool `protobuf:"varint,5,opt,name=is_primary_key,json=isPrimaryKey,proto3" json:"is_primary_key,omitempty"`
 	// Inference of this projection.
-	Inference            Inference `protobuf:"bytes,6,opt,name=inference,proto3" json:"inference"`
-	XXX_NoUnkeyedLiteral struct{}  `json:"-"`
-	XXX_unrecognized     []byte    `json:"-"`
-	XXX_sizecache        int32     `json:"-"`
+	Inference Inference `protobuf:"bytes,6,opt,name=inference,proto3" json:"inference"`
+	// JSON-encoded expression which computes this projection,
+	// or empty if this projection is a document location.
+	// Computed projections have a synthetic `ptr` which doesn't exist in documents.
+	ExpressionJson       encoding_json.RawMessage `protobuf:"bytes,7,opt,name=expression_json,json=expression,proto3,casttype=encoding/json.RawMessage" json:"expression_json,omitempty"`
+	XXX_NoUnkeyedLiteral struct{}                 `json:"-"`
+	XXX_unrecognized     []byte                   `json:"-"`
+	XXX_sizecache        int32                    `json:"-"`
 }
 
 func (m *Projection) Reset()         { *m = Projection{} }
@@ -2266,234 +2276,233 @@ func init() {
 func init() { proto.RegisterFile("go/protocols/flow/flow.proto", fileDescriptor_d0677502142fec31) }
 
 var fileDescriptor_d0677502142fec31 = []byte{
-	// 3630 bytes of a gzipped FileDescriptorProto
-	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xd4, 0x5a, 0xcb, 0x6f, 0x23, 0xc9,
-	0x79, 0x57, 0xf3, 0xcd, 0x8f, 0x0f, 0xb5, 0x4a, 0x9a, 0x19, 0x0e, 0xbd, 0x23, 0x6a, 0xe8, 0x18,
-	0xab, 0xdd, 0xf5, 0x52, 0x6b, 0x2d, 0xec, 0xec, 0xcc, 0x60, 0x62, 0xf0, 0xa5, 0x59, 0xce, 0x50,
-	0x24, 0xd3, 0xa4, 0xbc, 0x1e, 0x03, 0x46, 0xa3, 0xd9, 0x5d, 0xa4, 0x7a, 0xd4, 0xec, 0x66, 0xba,
-	0x8b, 0x1a, 0xd1, 0x97, 0x04, 0x41, 0x80, 0x00, 0x8b, 0x04, 0xf0, 0x25, 0xb0, 0x6f, 0xde, 0x18,
-	0x48, 0xe0, 0x7f, 0x21, 0xc9, 0x25, 0x40, 0x2e, 0x7b, 0x0a, 0x0c, 0x04, 0x49, 0x80, 0x1c, 0x66,
-	0x11, 0xe7, 0x60, 0x9f, 0x92, 0x5b, 0x10, 0xcc, 0x29, 0xa8, 0x47, 0x37, 0x9b, 0xa2, 0x46, 0x8f,
-	0xb5, 0x7d, 0xf0, 0x85, 0xa8, 0xfa, 0x5e, 0x5d, 0xf5, 0x7d, 0x55, 0xbf, 0xef, 0xab, 0x2a, 0xc2,
-	0x5b, 0x63, 0x67, 0x6f, 0xea, 0x3a, 0xc4, 0xd1, 0x1d, 0xcb, 0xdb, 0x1b, 0x59, 0xce, 0x4b, 0xf6,
-	0x53, 0x61, 0x34, 0x14, 0xa3, 0xed, 0xe2, 0xf6, 0xd0, 0x75, 0x4e, 0xb0, 0x1b, 0xc8, 0x05, 0x0d,
-	0x2e, 0x55, 0xdc, 0xd1, 0x1d, 0xdb, 0x9b, 0x4d, 0x2e, 0x91, 0xd8, 0x1a, 0x3b, 0x63, 0x87, 0x35,
-	0xf7, 0x68, 0x4b, 0x50, 0x4b, 0x63, 0xc7, 0x19, 0x5b, 0x98, 0x0b, 0x0f, 0x67, 0xa3, 0x3d, 0x62,
-	0x4e, 0xb0, 0x47, 0xb4, 0xc9, 0x94, 0x0b, 0x94, 0x1f, 0x43, 0xbc, 0x6f, 0x99, 0x3a, 0x46, 0x5b,
-	0x10, 0x1f, 0xe2, 0xb1, 0x69, 0x17, 0xa4, 0x1d, 0x69, 0x37, 0xa7, 0xf0, 0x0e, 0x92, 0x21, 0x8a,
-	0x6d, 0xa3, 0x10, 0x61, 0x34, 0xda, 0x7c, 0x98, 0xfd, 0xf9, 0x5f, 0x97, 0xd6, 0x7e, 0xf8, 0xd3,
-	0xd2, 0xda, 0x8f, 0x7f, 0x5a, 0x5a, 0x2b, 0xdb, 0x90, 0x3e, 0x3a, 0x6a, 0x35, 0x7a, 0x9a, 0x4b,
-	0x3c, 0x84, 0x20, 0x66, 0x3b, 0x06, 0x66, 0x16, 0x12, 0x0a, 0x6b, 0xa3, 0x47, 0x10, 0xd7, 0x2d,
-	0x47, 0x3f, 0x61, 0x26, 0x12, 0xb5, 0xaf, 0xbd, 0x7e, 0x55, 0xba, 0x3f, 0x76, 0x2a, 0x63, 0xed,
-	0x07, 0x98, 0x10, 0x5c, 0x31, 0xf0, 0xe9, 0x9e, 0xee, 0xb8, 0x78, 0x6f, 0x82, 0x3d, 0x4f, 0x1b,
-	0xe3, 0x4a, 0x9d, 0x0a, 0x2b, 0x5c, 0xe7, 0xa1, 0xfc, 0xab, 0xcf, 0x4a, 0xd2, 0xd2, 0xf7, 0xfe,
-	0x55, 0x02, 0xe8, 0xb9, 0xce, 0x0b, 0xac, 0x13, 0xd3, 0x61, 0xc3, 0x9b, 0x12, 0x97, 0x7d, 0x30,
-	0xad, 0xd0, 0x26, 0x9d, 0xc6, 0xc8, 0xc4, 0x16, 0x1f, 0x72, 0x5a, 0xe1, 0x1d, 0x54, 0x84, 0x14,
-	0x3e, 0x9b, 0x5a, 0xa6, 0x6e, 0x92, 0x42, 0x74, 0x47, 0xda, 0x4d, 0x29, 0x41, 0x1f, 0xed, 0x82,
-	0x6c, 0x7a, 0xea, 0x54, 0x73, 0x89, 0x49, 0x6d, 0xaa, 0x27, 0x78, 0x5e, 0x88, 0x31, 0x99, 0xbc,
-	0xe9, 0xf5, 0x7c, 0xf2, 0x33, 0x3c, 0x47, 0xbf, 0x07, 0x79, 0x2a, 0xe9, 0x9a, 0x13, 0xcd, 0x9d,
-	0x33, 0xb9, 0x38, 0x93, 0xcb, 0x9a, 0x5e, 0x8f, 0x13, 0xa9, 0xd4, 0x87, 0x90, 0x36, 0xed, 0x11,
-	0x76, 0xb1, 0xad, 0xe3, 0x42, 0x62, 0x47, 0xda, 0xcd, 0xec, 0xaf, 0x57, 0x58, 0xc0, 0x5b, 0x3e,
-	0xb9, 0x16, 0xfb, 0xfc, 0x55, 0x69, 0x4d, 0x59, 0xc8, 0x95, 0x7f, 0x16, 0x87, 0x74, 0xc0, 0xa6,
-	0x93, 0x20, 0xf3, 0x29, 0xf6, 0x0a, 0xd2, 0x4e, 0x94, 0x4e, 0x82, 0x75, 0x50, 0x05, 0x12, 0x1e,
-	0x71, 0x4d, 0x7b, 0xcc, 0xa6, 0x90, 0xd9, 0xbf, 0x7d, 0xce, 0x6a, 0xa5, 0xcf, 0xb8, 0x8a, 0x90,
-	0x62, 0x56, 0x4c, 0x62, 0x61, 0x36, 0x1b, 0x6a, 0x85, 0x76, 0xd0, 0x0e, 0x64, 0x0c, 0xec, 0xe9,
-	0xae, 0x39, 0xa5, 0xd3, 0x62, 0x33, 0x48, 0x2b, 0x61, 0x12, 0x7a, 0x04, 0x59, 0x03, 0x8f, 0xb4,
-	0x99, 0x45, 0xd4, 0x17, 0x9e, 0x63, 0xb3, 0x39, 0xa4, 0x6b, 0x6f, 0xbd, 0x7e, 0x55, 0x2a, 0x60,
-	0x5b, 0x77, 0x0c, 0xd3, 0x1e, 0xef, 0x51, 0x46, 0x45, 0xd1, 0x5e, 0x1e, 0xf2, 0xb0, 0x29, 0x49,
-	0xa1, 0x81, 0x6e, 0x43, 0xc2, 0xc3, 0xba, 0x8b, 0x49, 0x21, 0xc9, 0x7c, 0x23, 0x7a, 0x74, 0xf0,
-	0xf8, 0xcc, 0xf4, 0x88, 0x57, 0x48, 0xed, 0x48, 0xbb, 0xf9, 0xd5, 0xc1, 0x37, 0x19, 0x57, 0x11,
-	0x52, 0xe8, 0x1b, 0x90, 0xb4, 0xe9, 0x7a, 0x37, 0xf5, 0x42, 0x9a, 0xcd, 0xf6, 0xce, 0x79, 0x85,
-	0x0e, 0x67, 0x2b, 0xbe, 0x5c, 0xf1, 0x27, 0x12, 0x24, 0xb8, 0x0b, 0xd0, 0x7d, 0xc8, 0xea, 0x8e,
-	0x4d, 0xb0, 0x4d, 0x54, 0xea, 0x3b, 0xe6, 0xb0, 0xb4, 0x92, 0x11, 0xb4, 0xc1, 0x7c, 0x8a, 0xe9,
-	0x40, 0x47, 0x8e, 0x3b, 0xd1, 0x88, 0x70, 0x8f, 0xe8, 0xa1, 0x77, 0x40, 0xf6, 0x55, 0xfd, 0xd9,
-	0xb2, 0xa9, 0xa4, 0x95, 0x75, 0x41, 0x6f, 0x0a, 0x32, 0xba, 0x07, 0x30, 0xd1, 0xce, 0x54, 0x0b,
-	0xdb, 0x63, 0x72, 0xcc, 0xdc, 0x94, 0x53, 0xd2, 0x13, 0xed, 0xac, 0xcd, 0x08, 0x4f, 0x63, 0x29,
-	0x49, 0x8e, 0x3c, 0x8d, 0xa5, 0x22, 0x72, 0xf4, 0x69, 0x2c, 0x15, 0x97, 0x13, 0xc5, 0x3f, 0x86,
-	0xa4, 0x18, 0x35, 0x2a, 0x41, 0xe6, 0x58, 0xf3, 0xd4, 0x89, 0x69, 0x9b, 0x93, 0xd9, 0x84, 0xad,
-	0xe0, 0x94, 0x02, 0xc7, 0x9a, 0x77, 0xc8, 0x29, 0xa8, 0x00, 0x49, 0x9f, 0x49, 0x97, 0xb2, 0xa4,
-	0xf8, 0xdd, 0x40, 0x55, 0x3b, 0x63, 0xdc, 0xe8, 0x42, 0x95, 0x53, 0x98, 0xaa, 0x60, 0xc6, 0x84,
-	0x2a, 0xef, 0x96, 0x6b, 0x90, 0xe0, 0x7e, 0x46, 0x19, 0x48, 0xb6, 0x3a, 0xdf, 0xa9, 0xb6, 0x5b,
-	0x0d, 0x79, 0x0d, 0xa5, 0x20, 0x76, 0x78, 0xd4, 0x1f, 0xc8, 0x12, 0x4a, 0x42, 0xf4, 0xb0, 0xfa,
-	0x5c, 0x8e, 0xa0, 0x2c, 0xa4, 0x5a, 0x87, 0xbd, 0x76, 0xab, 0xde, 0x1a, 0xc8, 0x51, 0x04, 0x90,
-	0xa8, 0x57, 0x3b, 0x9d, 0xee, 0x40, 0x8e, 0x95, 0x9f, 0x43, 0xa6, 0x83, 0xc9, 0x4b, 0xc7, 0x3d,
-	0xe9, 0x39, 0x2e, 0x0b, 0xb8, 0x3d, 0x9b, 0x0c, 0xb1, 0x2b, 0x80, 0x43, 0xf4, 0xe8, 0x96, 0xf3,
-	0x11, 0x4a, 0xec, 0xc5, 0xa0, 0x4f, 0x75, 0xa6, 0xb3, 0xa1, 0x65, 0xea, 0x62, 0xf0, 0xa2, 0x57,
-	0xfe, 0xdf, 0x3c, 0xe4, 0xeb, 0x8e, 0x65, 0xf1, 0xdd, 0xdd, 0x9f, 0x62, 0x1d, 0x95, 0x21, 0x66,
-	0x6b, 0x13, 0x8e, 0x29, 0xe9, 0x5a, 0xfe, 0xf5, 0xab, 0x12, 0x2c, 0x24, 0x14, 0xc6, 0x43, 0x0d,
-	0xd8, 0x78, 0xe9, 0x9a, 0x04, 0xab, 0x9e, 0x7e, 0x8c, 0x27, 0x1a, 0x5f, 0xb5, 0xa9, 0x6b, 0xac,
-	0xda, 0x0c, 0x53, 0xeb, 0x33, 0x2d, 0x54, 0x03, 0xd9, 0xc5, 0x9a, 0xb1, 0x64, 0x24, 0x73, 0x0d,
-	0x23, 0x40, 0xb5, 0x84, 0x0d, 0x19, 0xa2, 0x14, 0x16, 0xa2, 0x6c, 0xdb, 0xd2, 0x26, 0xba, 0x0b,
-	0xa9, 0xd9, 0xcc, 0x34, 0x54, 0x0a, 0x53, 0x7c, 0xa1, 0x25, 0x69, 0xbf, 0x47, 0x5c, 0xba, 0xd2,
-	0x16, 0xa8, 0xc3, 0x70, 0xca, 0x2b, 0xc4, 0x99, 0xe6, 0x7a, 0x40, 0x3f, 0x60, 0x64, 0xf4, 0x11,
-	0x64, 0xa6, 0x01, 0xea, 0x79, 0x85, 0xc4, 0x4e, 0x74, 0x37, 0xb3, 0x2f, 0xf3, 0x1d, 0xb1, 0x80,
-	0x43, 0x01, 0x2b, 0x61, 0x51, 0xea, 0x1b, 0x4d, 0x3f, 0x51, 0x09, 0x9e, 0x4c, 0x2d, 0x8d, 0x60,
-	0x3e, 0xad, 0xe4, 0x75, 0x7c, 0xa3, 0xe9, 0x27, 0x03, 0xa1, 0x85, 0x1a, 0x80, 0x16, 0x43, 0xf5,
-	0x6d, 0x89, 0x8d, 0x79, 0xab, 0x12, 0x64, 0xa2, 0xa7, 0xce, 0xcc, 0xb5, 0x35, 0x8b, 0x06, 0x4e,
-	0xd9, 0x08, 0x14, 0x02, 0x2b, 0xdf, 0x06, 0x30, 0xb0, 0x6b, 0x9e, 0x6a, 0x0c, 0x79, 0xb2, 0x4c,
-	0xbb, 0xc4, 0x27, 0xb1, 0x1c, 0xf5, 0x4a, 0x23, 0x10, 0x53, 0x42, 0x2a, 0xc5, 0x7f, 0x03, 0x80,
-	0x05, 0x0b, 0x0d, 0x20, 0xaf, 0x3b, 0xb6, 0x8d, 0x75, 0xe2, 0xb8, 0x7c, 0x9f, 0x4b, 0x0c, 0x5b,
-	0xde, 0xbf, 0xc2, 0x66, 0xa5, 0xee, 0x6b, 0x51, 0x24, 0x50, 0x72, 0x7a, 0xb8, 0x8b, 0x1e, 0x00,
-	0xc5, 0x89, 0x91, 0x39, 0xe6, 0xbe, 0x8a, 0x5c, 0xc3, 0x57, 0x09, 0xae, 0x80, 0xba, 0x00, 0xc4,
-	0xd5, 0x6c, 0x8f, 0x42, 0x89, 0xc7, 0x56, 0x41, 0x66, 0xff, 0x9d, 0xab, 0x06, 0x33, 0xf0, 0x35,
-	0x44, 0xf8, 0x42, 0x26, 0xd0, 0x27, 0xb0, 0xe1, 0x1d, 0xcf, 0x46, 0x23, 0x0b, 0xd3, 0x74, 0xa3,
-	0xf2, 0xa4, 0x10, 0xdb, 0x89, 0xee, 0xe6, 0xf7, 0xdf, 0xbb, 0xca, 0x6e, 0x9f, 0x2b, 0xb2, 0x29,
-	0xae, 0x0b, 0x2b, 0xcf, 0xf0, 0x7c, 0xc0, 0x72, 0xc9, 0x43, 0xc8, 0x7b, 0xc7, 0x9a, 0x6b, 0x2c,
-	0x82, 0x19, 0x67, 0xe1, 0xd8, 0xac, 0xf8, 0x85, 0x46, 0xa5, 0x4f, 0xf9, 0x2c, 0x94, 0x39, 0x26,
-	0x1a, 0x84, 0xb1, 0x05, 0xb7, 0x5c, 0xac, 0x3b, 0xa7, 0xd8, 0x9d, 0xab, 0x96, 0x33, 0x5e, 0x98,
-	0x48, 0x5c, 0xb6, 0x1e, 0x36, 0x7d, 0x9d, 0xb6, 0x33, 0x0e, 0x4c, 0x7d, 0x0b, 0x72, 0x36, 0xc7,
-	0x12, 0x75, 0xea, 0xb8, 0xc4, 0x2b, 0x24, 0x99, 0xcf, 0x36, 0xf8, 0xdc, 0x42, 0x30, 0xa3, 0x64,
-	0xed, 0x45, 0xc7, 0x2b, 0xfe, 0x24, 0x0e, 0xe9, 0xc0, 0x6f, 0xe8, 0xfe, 0x12, 0x46, 0xe4, 0x5e,
-	0xbf, 0x2a, 0x2d, 0x98, 0x02, 0x22, 0x1e, 0x02, 0xe8, 0x81, 0xa7, 0x58, 0x4c, 0x33, 0xfb, 0x5b,
-	0x17, 0x79, 0xd0, 0x0f, 0xc2, 0x42, 0x1a, 0xb5, 0xc3, 0x8b, 0xdf, 0xc3, 0x16, 0x5b, 0x2a, 0x22,
-	0x07, 0xdf, 0x59, 0x4c, 0xb6, 0xad, 0x0d, 0xb1, 0xd5, 0x17, 0x6c, 0x61, 0x66, 0xb1, 0x09, 0x7c,
-	0x06, 0xc7, 0x45, 0xd3, 0x71, 0x4d, 0xc2, 0xcb, 0x8c, 0x9c, 0x12, 0xf4, 0xd1, 0xd7, 0x01, 0x31,
-	0x08, 0x32, 0xb0, 0xa5, 0xcd, 0x55, 0x0f, 0xeb, 0x8e, 0xcd, 0x30, 0x81, 0x4a, 0x31, 0x70, 0x6a,
-	0x50, 0x46, 0x9f, 0xd3, 0x69, 0x1e, 0x08, 0x2d, 0x0e, 0x06, 0x0a, 0x69, 0x05, 0x16, 0x91, 0x46,
-	0x47, 0x50, 0xf4, 0x05, 0x2c, 0x6d, 0x32, 0x34, 0x34, 0x35, 0xbc, 0xb0, 0xaf, 0x03, 0x02, 0x9b,
-	0x42, 0xbf, 0xcd, 0xd4, 0xeb, 0x7c, 0x95, 0x1f, 0x00, 0xba, 0xc0, 0xdc, 0x75, 0xf0, 0x36, 0x6b,
-	0x85, 0xed, 0x7c, 0x05, 0xd2, 0x6c, 0xb6, 0x8e, 0x6d, 0xcd, 0x19, 0x96, 0xa4, 0x94, 0x14, 0x25,
-	0x74, 0x6d, 0x6b, 0x8e, 0x2a, 0xb0, 0xf9, 0x82, 0xaf, 0x1e, 0x95, 0xa3, 0xf2, 0x6c, 0x34, 0x32,
-	0xcf, 0x0a, 0xc0, 0x20, 0x74, 0x43, 0xb0, 0x14, 0x8a, 0xbc, 0x8c, 0x81, 0x1e, 0x00, 0xd8, 0x0e,
-	0x51, 0x87, 0x78, 0xe4, 0xb8, 0x98, 0xe1, 0x76, 0x66, 0xbf, 0x58, 0xe1, 0xd5, 0x6f, 0xc5, 0xaf,
-	0x7e, 0x2b, 0x03, 0xbf, 0xfa, 0x55, 0xd2, 0xb6, 0x43, 0x6a, 0x4c, 0x18, 0xfd, 0x3e, 0xd0, 0x8e,
-	0xaa, 0x8d, 0x08, 0x76, 0x05, 0x2a, 0x5d, 0xa6, 0x99, 0xb2, 0x1d, 0x52, 0xa5, 0xb2, 0x34, 0x94,
-	0x43, 0x4d, 0x3f, 0x19, 0x99, 0x96, 0x55, 0xc8, 0xf1, 0x50, 0xfa, 0xfd, 0xf2, 0xf7, 0x21, 0xb7,
-	0x84, 0x32, 0xa8, 0x08, 0xb7, 0x45, 0xc2, 0x55, 0xeb, 0xdd, 0x4e, 0xa7, 0x59, 0x1f, 0x74, 0x15,
-	0x75, 0xf0, 0xbc, 0xd7, 0x94, 0xd7, 0x68, 0x7a, 0xed, 0xff, 0x61, 0xbb, 0x35, 0x68, 0xca, 0x12,
-	0xca, 0x03, 0x50, 0x6a, 0xbf, 0xae, 0xb4, 0x7a, 0x03, 0x39, 0x82, 0xd2, 0x10, 0x6f, 0x1d, 0x56,
-	0x9f, 0x34, 0xe5, 0x28, 0x6d, 0xb6, 0xbb, 0xf5, 0x6a, 0x5b, 0x8e, 0x95, 0x0f, 0x21, 0x13, 0xda,
-	0xdf, 0xa8, 0x00, 0x5b, 0xbe, 0xf1, 0xfe, 0xc7, 0x47, 0x07, 0x07, 0xed, 0xa6, 0x6f, 0x3a, 0x03,
-	0xc9, 0x5a, 0xb7, 0xdb, 0x6e, 0x56, 0x3b, 0xb2, 0xc4, 0x93, 0xfe, 0xa0, 0xf9, 0xa4, 0xa9, 0xc8,
-	0x11, 0xf6, 0xd1, 0x81, 0xd2, 0xea, 0x3c, 0x91, 0xa3, 0xe5, 0x1f, 0x45, 0x20, 0xcf, 0x52, 0x0d,
-	0x5f, 0xa6, 0x74, 0xd5, 0x23, 0x88, 0x9d, 0xe0, 0xb9, 0x5f, 0x82, 0xb2, 0x36, 0xcd, 0xdb, 0xa7,
-	0x9a, 0x35, 0xc3, 0x5e, 0x21, 0xc2, 0xa8, 0xa2, 0x47, 0x1d, 0x61, 0x38, 0xfa, 0x6c, 0x82, 0x6d,
-	0x22, 0x4a, 0xad, 0xa0, 0x8f, 0x4e, 0xe1, 0x16, 0xcb, 0x6d, 0xe1, 0xc5, 0xa2, 0x4e, 0xb4, 0x29,
-	0x83, 0xb1, 0xcc, 0xfe, 0xd7, 0xf9, 0x26, 0x5c, 0xfe, 0x38, 0xef, 0xf2, 0x65, 0xf2, 0xd4, 0x73,
-	0xec, 0x43, 0x6d, 0xda, 0xb4, 0x89, 0x3b, 0xaf, 0xbd, 0xf5, 0xe9, 0x17, 0x97, 0xa5, 0xac, 0xd1,
-	0x42, 0xad, 0xd8, 0x84, 0x3b, 0x6f, 0xb0, 0xe2, 0x67, 0x69, 0x71, 0x6a, 0xa0, 0x59, 0x7a, 0x0b,
-	0xe2, 0x6c, 0x2a, 0xfe, 0xa9, 0x81, 0x75, 0x1e, 0x46, 0x3e, 0x92, 0xca, 0xff, 0x17, 0x87, 0x4c,
-	0x5d, 0x9b, 0x92, 0x99, 0x8b, 0x59, 0x3d, 0x52, 0x5a, 0xc2, 0x9a, 0xcc, 0xeb, 0x57, 0xa5, 0xa4,
-	0x60, 0x0b, 0xa4, 0x39, 0x58, 0x49, 0x4a, 0x11, 0x96, 0x94, 0xfc, 0x44, 0xb7, 0xb0, 0x75, 0xa3,
-	0x34, 0x14, 0xbd, 0x41, 0x1a, 0xfa, 0x26, 0xa4, 0x86, 0xa6, 0x4d, 0x45, 0x3c, 0xe1, 0xe5, 0xbb,
-	0xab, 0x1f, 0xaf, 0x71, 0x09, 0x25, 0x10, 0xa5, 0xf5, 0x88, 0x69, 0x13, 0xec, 0x9e, 0x6a, 0xd6,
-	0x39, 0xec, 0x59, 0xf7, 0xe9, 0x3e, 0xf4, 0xac, 0xa6, 0x8f, 0xc4, 0xaf, 0x9f, 0x3e, 0x92, 0xbf,
-	0x7e, 0xfa, 0x48, 0x5d, 0x2f, 0x7d, 0xfc, 0x8f, 0x04, 0x49, 0x31, 0x7f, 0xf4, 0x14, 0xb6, 0x5c,
-	0xec, 0x39, 0x33, 0x57, 0xc7, 0x4b, 0x78, 0x26, 0x5d, 0xc3, 0xe1, 0x79, 0x5f, 0x53, 0x20, 0xda,
-	0x57, 0x21, 0x17, 0xd8, 0x9a, 0x6a, 0xe4, 0x58, 0x6c, 0x93, 0xac, 0x4f, 0xec, 0x69, 0xe4, 0xf8,
-	0x5c, 0x2a, 0x8a, 0xde, 0x28, 0x15, 0x85, 0x11, 0x27, 0xb6, 0x8c, 0x38, 0x14, 0x4e, 0x3d, 0x42,
-	0x4b, 0x3c, 0xff, 0x60, 0x9a, 0x56, 0x52, 0x8c, 0xf0, 0x0c, 0xcf, 0xcb, 0xfb, 0xe7, 0xe1, 0x68,
-	0xa9, 0xfe, 0x0f, 0x30, 0x26, 0xb9, 0xc0, 0x98, 0x54, 0xf9, 0xdf, 0x01, 0x36, 0x0f, 0x35, 0x82,
-	0x5d, 0x53, 0xb3, 0xcc, 0x1f, 0x68, 0x41, 0x49, 0xfe, 0xf6, 0xd2, 0x16, 0xd8, 0x7c, 0xfd, 0xaa,
-	0xb4, 0x7e, 0x4e, 0x4c, 0x6c, 0x85, 0xce, 0x1b, 0xb6, 0xc2, 0xdb, 0x7c, 0xb6, 0x17, 0xd8, 0xfe,
-	0xad, 0x6d, 0x89, 0xc7, 0x2b, 0x5b, 0xe2, 0xfe, 0x9b, 0x07, 0xb1, 0xba, 0x35, 0x7e, 0xc7, 0xcb,
-	0xa5, 0x7f, 0x4e, 0xfc, 0x8e, 0xae, 0xf7, 0x8b, 0x4b, 0xaf, 0xe4, 0x6f, 0xa0, 0xf4, 0x4a, 0x9f,
-	0x2b, 0xbd, 0xea, 0xb0, 0xce, 0xd3, 0x94, 0xe7, 0x27, 0x21, 0xb6, 0xc1, 0x82, 0xa1, 0x2e, 0x27,
-	0x28, 0xf1, 0x8d, 0xfc, 0x68, 0x39, 0x67, 0x7e, 0x15, 0x72, 0x06, 0xb6, 0x88, 0xa6, 0xce, 0xa6,
-	0x86, 0x46, 0xb0, 0xe7, 0xdf, 0x0f, 0x31, 0xe2, 0x11, 0xa7, 0xa1, 0x21, 0x20, 0x03, 0x4f, 0x5d,
-	0xac, 0x6b, 0x04, 0x1b, 0xaa, 0x28, 0xb0, 0xc4, 0x62, 0xf8, 0xf0, 0xca, 0x45, 0x59, 0x69, 0x04,
-	0xba, 0xa2, 0x00, 0x50, 0x36, 0x8c, 0xf3, 0xa4, 0x37, 0x55, 0x4f, 0xa9, 0xeb, 0x55, 0x4f, 0xf0,
-	0xa5, 0xab, 0xa7, 0xcc, 0x97, 0xac, 0x9e, 0xb2, 0x97, 0x61, 0x59, 0x6e, 0x19, 0xcb, 0x8a, 0x7f,
-	0x22, 0xc1, 0xc6, 0x8a, 0x17, 0xd0, 0x3d, 0x80, 0xb1, 0xeb, 0xcc, 0xa6, 0xea, 0x02, 0x9b, 0x94,
-	0x34, 0xa3, 0x74, 0x28, 0x16, 0xfd, 0x46, 0x8b, 0xf8, 0xf2, 0xe3, 0xeb, 0xc1, 0x69, 0x6a, 0x01,
-	0xa7, 0x69, 0xda, 0x6c, 0x34, 0x9f, 0x55, 0x0f, 0x64, 0x28, 0xff, 0x77, 0x1a, 0x12, 0xdd, 0xea,
-	0x8c, 0x1c, 0xef, 0x8b, 0x6b, 0x92, 0x53, 0xd3, 0xc0, 0xfe, 0x35, 0x66, 0xd0, 0x47, 0xef, 0xc2,
-	0x86, 0x36, 0x23, 0xc7, 0xea, 0xcc, 0xb5, 0x16, 0xa8, 0xc1, 0x2b, 0x94, 0x75, 0xca, 0x38, 0x72,
-	0xad, 0x00, 0x1a, 0x1e, 0xc0, 0x5d, 0x4d, 0xd7, 0xb1, 0xe7, 0xa9, 0xc4, 0x39, 0xc1, 0xf6, 0xb2,
-	0x0e, 0xaf, 0xc9, 0x6e, 0x73, 0x81, 0x01, 0xe5, 0x87, 0x55, 0x2b, 0xb0, 0xb9, 0xa4, 0x3a, 0xc1,
-	0xe4, 0xd8, 0x31, 0xf8, 0xdd, 0x87, 0xb2, 0x11, 0x52, 0x3a, 0x64, 0x0c, 0x36, 0xac, 0xb0, 0xfc,
-	0xd0, 0x31, 0xe6, 0xe2, 0x6e, 0x63, 0x3d, 0x24, 0x5d, 0x73, 0x8c, 0x39, 0xfa, 0x33, 0x09, 0xee,
-	0x2d, 0x09, 0x1f, 0x63, 0xcd, 0xc0, 0xae, 0xb7, 0x28, 0x03, 0xe3, 0x0c, 0xc2, 0xc4, 0x69, 0x96,
-	0x3b, 0xa5, 0x52, 0x5d, 0x58, 0xf9, 0x98, 0xcb, 0xdf, 0xa0, 0x0a, 0x44, 0xda, 0x8a, 0x36, 0xfa,
-	0x54, 0x82, 0xed, 0xa5, 0x61, 0xb8, 0xd8, 0x9b, 0x3a, 0xb6, 0x87, 0x17, 0xe3, 0x48, 0x84, 0xcb,
-	0xd1, 0xd5, 0x71, 0x28, 0x42, 0xe1, 0x06, 0x03, 0x09, 0xfb, 0xdb, 0x57, 0x3f, 0xd4, 0xa6, 0xe8,
-	0x11, 0x14, 0x5d, 0x3c, 0x72, 0xb1, 0x77, 0x7c, 0x51, 0xac, 0xf8, 0x5d, 0xe3, 0x1d, 0x21, 0xb1,
-	0x12, 0xac, 0x0f, 0x28, 0x7c, 0x87, 0x95, 0x45, 0xb4, 0xb2, 0x4c, 0x0d, 0x85, 0xd5, 0x44, 0xb8,
-	0xd8, 0xa1, 0x32, 0xac, 0xc1, 0xe2, 0xc5, 0xa1, 0x40, 0x0e, 0xcb, 0xb3, 0x80, 0xfd, 0xb9, 0x04,
-	0xdb, 0xcb, 0xe2, 0x2b, 0x11, 0x4b, 0x5f, 0xe0, 0x29, 0x25, 0x64, 0xe7, 0xe6, 0x21, 0xdb, 0x74,
-	0x57, 0xd5, 0xd1, 0x5f, 0x4a, 0x50, 0x5a, 0x1e, 0xc9, 0x6a, 0xd0, 0x80, 0x0d, 0xe5, 0xfd, 0x37,
-	0x0e, 0xe5, 0x4b, 0x44, 0x6d, 0xc9, 0xf3, 0xa1, 0xb0, 0x15, 0xdb, 0xb0, 0x7d, 0xf9, 0xba, 0xbc,
-	0xc9, 0xb9, 0xa2, 0x78, 0x08, 0xa5, 0x2b, 0x56, 0xd7, 0x4d, 0xcd, 0x5d, 0x11, 0x82, 0x1b, 0x99,
-	0xeb, 0xc0, 0xce, 0x55, 0x6e, 0xbc, 0xd1, 0x29, 0xea, 0xef, 0xa2, 0x90, 0x1a, 0x60, 0x8f, 0xb0,
-	0xfa, 0x11, 0x85, 0xeb, 0x47, 0x51, 0x2a, 0xee, 0x41, 0xdc, 0x23, 0x78, 0xca, 0x0f, 0x96, 0xb4,
-	0xae, 0x62, 0x11, 0xf5, 0x55, 0x2a, 0x7d, 0x82, 0xa7, 0x02, 0x8d, 0xb9, 0x5c, 0xf1, 0x97, 0x11,
-	0x88, 0x51, 0x2a, 0xfa, 0x26, 0x4d, 0x15, 0x78, 0x1a, 0xbe, 0xff, 0x2b, 0x5c, 0xa0, 0x5d, 0x61,
-	0x05, 0x65, 0x8a, 0x8a, 0x32, 0xc0, 0xbe, 0x07, 0xc0, 0xd4, 0x4c, 0xdb, 0xc0, 0x67, 0xe2, 0x7d,
-	0x8b, 0x19, 0x6a, 0x51, 0xc2, 0xf9, 0x57, 0x92, 0xe8, 0xea, 0x2b, 0x89, 0x6f, 0xc0, 0xd3, 0x9d,
-	0xa9, 0xff, 0xc4, 0xc2, 0x0c, 0xf4, 0x29, 0x01, 0x55, 0x96, 0xaa, 0x9e, 0xf8, 0x85, 0xb7, 0xd7,
-	0xe1, 0x4a, 0xe7, 0x01, 0xe4, 0x0c, 0x47, 0x17, 0xbb, 0xec, 0x14, 0xeb, 0xfc, 0x3a, 0xe7, 0x8a,
-	0x7a, 0x2c, 0x46, 0x55, 0xd0, 0x63, 0x80, 0x20, 0x43, 0x79, 0xd7, 0x2b, 0x8e, 0x42, 0x0a, 0xe5,
-	0x6d, 0x88, 0x31, 0x8f, 0x00, 0x24, 0x5a, 0x9d, 0x27, 0xcd, 0xfe, 0x80, 0x5f, 0x48, 0x7c, 0xa7,
-	0xa9, 0xb4, 0x0e, 0x9e, 0xcb, 0x52, 0xf9, 0x47, 0x12, 0xa4, 0x15, 0xcd, 0x1e, 0xf3, 0xf3, 0xef,
-	0x57, 0x20, 0x7d, 0x82, 0xe7, 0x2a, 0x7f, 0x2a, 0xa4, 0x6e, 0x4b, 0x2a, 0xa9, 0x13, 0x3c, 0xaf,
-	0xb1, 0xd7, 0xc2, 0x3b, 0x90, 0xa4, 0x4c, 0x6c, 0x1b, 0xcc, 0x63, 0x49, 0x25, 0x71, 0x82, 0xe7,
-	0x4d, 0xdb, 0x40, 0x65, 0xc8, 0xb9, 0x2a, 0x7b, 0xd3, 0x13, 0x9a, 0x31, 0xc6, 0xce, 0xb8, 0xec,
-	0xb9, 0x8f, 0x2b, 0x6f, 0x43, 0xc6, 0x97, 0xa1, 0x06, 0xe2, 0x4c, 0x22, 0xcd, 0x25, 0x9a, 0xb6,
-	0xf1, 0x50, 0xfe, 0xf1, 0x67, 0xa5, 0xb5, 0x73, 0x8f, 0x8f, 0xf9, 0x20, 0x0b, 0xf7, 0x69, 0x75,
-	0x80, 0x1e, 0x41, 0x96, 0x97, 0x5e, 0xc6, 0xf5, 0x8b, 0xda, 0xa4, 0xd0, 0x40, 0x25, 0xc8, 0x4c,
-	0xb0, 0x3b, 0x66, 0xa5, 0xac, 0x7e, 0xcc, 0x26, 0x97, 0x52, 0x80, 0x91, 0x7a, 0x94, 0x52, 0xfe,
-	0x5b, 0x09, 0xd2, 0x7d, 0xec, 0xf6, 0x1c, 0xcb, 0xd4, 0xd9, 0x65, 0x9d, 0x47, 0x5c, 0x95, 0xb8,
-	0x33, 0x9b, 0x16, 0x22, 0xa2, 0x02, 0xe2, 0x8f, 0x20, 0xb2, 0x47, 0xdc, 0x81, 0x60, 0xf0, 0x6a,
-	0xe7, 0x01, 0xdc, 0xb5, 0xb1, 0x47, 0x07, 0xe6, 0x0c, 0x5f, 0x9c, 0x57, 0xe2, 0xcb, 0xef, 0x36,
-	0x17, 0xe8, 0x0e, 0x5f, 0x2c, 0xab, 0x7e, 0x00, 0x5b, 0x9a, 0xeb, 0x6a, 0xf3, 0xf3, 0x5a, 0x51,
-	0xa6, 0x85, 0x18, 0x6f, 0x49, 0xa3, 0xfc, 0x37, 0x11, 0x80, 0xe6, 0x19, 0x71, 0x35, 0x9d, 0x54,
-	0x7b, 0xad, 0xe2, 0x3f, 0x48, 0x90, 0x10, 0x15, 0x7b, 0xf8, 0x39, 0x42, 0x5a, 0x7e, 0x8e, 0x78,
-	0x0c, 0x99, 0xf0, 0xd3, 0xc7, 0x75, 0xee, 0xbd, 0x81, 0x2b, 0x50, 0x58, 0xa0, 0xfb, 0x81, 0x17,
-	0xd0, 0x53, 0xe2, 0x7a, 0xe2, 0x05, 0x24, 0xcd, 0x28, 0x3d, 0xe2, 0xae, 0xbc, 0x60, 0xc4, 0xae,
-	0xfd, 0x82, 0x51, 0x7e, 0x0e, 0xb1, 0xba, 0x63, 0x9c, 0x2b, 0xb1, 0x72, 0x90, 0xae, 0x77, 0x3b,
-	0x07, 0xad, 0x27, 0x47, 0x4a, 0x93, 0x5f, 0x6c, 0x35, 0xbf, 0x3b, 0x50, 0xaa, 0xf5, 0x81, 0x1c,
-	0x41, 0x08, 0xf2, 0xa2, 0xd3, 0x6c, 0xa8, 0x47, 0x47, 0xad, 0x86, 0x1c, 0x45, 0x5b, 0x20, 0x2f,
-	0x68, 0x07, 0xad, 0x66, 0xbb, 0xd1, 0x97, 0x63, 0xe5, 0x5f, 0x4a, 0x90, 0xaa, 0xcd, 0x4c, 0xcb,
-	0xa0, 0x5e, 0xfa, 0x8f, 0x25, 0x2f, 0x0d, 0x29, 0x59, 0x35, 0x0d, 0xdf, 0x4b, 0xac, 0xdf, 0x32,
-	0x16, 0x2c, 0x63, 0x28, 0x60, 0x8e, 0xb3, 0x1a, 0x43, 0xf6, 0xf4, 0xc9, 0x8e, 0x3d, 0x02, 0x2e,
-	0x44, 0x0f, 0xed, 0x43, 0x46, 0x9c, 0x91, 0x18, 0x46, 0xc5, 0x18, 0x46, 0x6d, 0xf8, 0x27, 0xa0,
-	0xe0, 0x45, 0x52, 0x01, 0x2e, 0xc5, 0x36, 0xe3, 0x7b, 0xb0, 0xb1, 0x38, 0x3a, 0x8b, 0xb3, 0x9c,
-	0x38, 0xd4, 0xcb, 0x01, 0x43, 0x1c, 0xf8, 0xd0, 0x7d, 0xc8, 0x0a, 0x87, 0xa9, 0xae, 0xe3, 0x10,
-	0xfe, 0x60, 0x1b, 0x38, 0x51, 0x71, 0x1c, 0x52, 0xde, 0x84, 0x0d, 0x05, 0x7b, 0x98, 0xb0, 0x6d,
-	0xa2, 0xe0, 0x3f, 0x9a, 0x61, 0x8f, 0x94, 0xb7, 0x00, 0x85, 0x89, 0x1c, 0xe3, 0xcb, 0x8f, 0x01,
-	0x55, 0x8d, 0x53, 0xcd, 0xd6, 0x31, 0xad, 0xda, 0x85, 0x2c, 0x7a, 0x1b, 0xd6, 0x35, 0x4e, 0x0d,
-	0xee, 0x86, 0xa8, 0x67, 0x62, 0x4a, 0x5e, 0x90, 0xc5, 0xd5, 0x50, 0xf9, 0x16, 0x6c, 0x2e, 0xa9,
-	0x0b, 0xab, 0x1f, 0x41, 0xb6, 0xe1, 0xe8, 0x5e, 0xd5, 0x36, 0x6a, 0x73, 0x7a, 0x0a, 0x42, 0xc0,
-	0xc0, 0x4b, 0xec, 0x17, 0x0e, 0x64, 0x5b, 0x10, 0x1f, 0x52, 0x26, 0x73, 0x6c, 0x4c, 0xe1, 0x9d,
-	0xf2, 0x5f, 0x49, 0x90, 0x6b, 0xd9, 0x63, 0xec, 0x11, 0x7f, 0x2c, 0xcb, 0xd8, 0x2a, 0x5d, 0x89,
-	0xad, 0xe1, 0x70, 0x46, 0x96, 0xc3, 0xb9, 0x02, 0xbb, 0xd1, 0xeb, 0xc2, 0x6e, 0xf9, 0x5f, 0x22,
-	0x90, 0xf7, 0xc7, 0xc5, 0x27, 0x89, 0xfe, 0x5e, 0x5a, 0x9c, 0xbb, 0xf8, 0x8b, 0x24, 0x2d, 0x9e,
-	0xf8, 0x1d, 0x6a, 0x50, 0xe3, 0x2e, 0xeb, 0xf8, 0x87, 0xfe, 0x4f, 0xa8, 0x38, 0x4d, 0xdb, 0x1e,
-	0x2f, 0x52, 0xbe, 0xff, 0xa7, 0x5f, 0x94, 0xde, 0xbb, 0xe8, 0x8f, 0x12, 0xe7, 0xfe, 0x28, 0xe2,
-	0xeb, 0x7f, 0xfa, 0x45, 0xe9, 0xdd, 0xeb, 0x88, 0x77, 0x47, 0x23, 0x0f, 0x93, 0xe0, 0x0c, 0xb8,
-	0xf8, 0x2c, 0xfa, 0x03, 0xc8, 0xfa, 0x63, 0xc7, 0x44, 0x37, 0xc4, 0x23, 0x49, 0xe8, 0x7a, 0x82,
-	0x17, 0x15, 0x95, 0x26, 0xd1, 0x0d, 0x7f, 0x9f, 0x0a, 0x05, 0x4a, 0x2a, 0x36, 0xe0, 0xf6, 0xc5,
-	0x73, 0xb9, 0xaa, 0x52, 0x88, 0x86, 0x2b, 0x85, 0x9f, 0x45, 0xe0, 0xce, 0x40, 0xf3, 0x4e, 0xfc,
-	0xcb, 0x0c, 0xd7, 0x39, 0x9b, 0xfb, 0x71, 0xff, 0x16, 0xc4, 0x9c, 0x29, 0xe6, 0x11, 0xcf, 0xec,
-	0x97, 0x45, 0x96, 0xbf, 0x58, 0xb8, 0xd2, 0x9d, 0x62, 0x5b, 0x61, 0xf2, 0x6c, 0xad, 0x69, 0x44,
-	0x63, 0x1f, 0xcb, 0x2a, 0xac, 0x5d, 0xfc, 0x47, 0x09, 0x62, 0x54, 0x04, 0xed, 0x42, 0x82, 0x57,
-	0xb8, 0xc2, 0xac, 0x7c, 0x7e, 0xc2, 0x8a, 0xe0, 0xa3, 0x36, 0xa4, 0xf8, 0x25, 0x90, 0xbf, 0x8c,
-	0x6a, 0xdf, 0x78, 0xfd, 0xaa, 0xf4, 0xfe, 0x45, 0x5e, 0x5f, 0xf9, 0xb7, 0x0e, 0xbf, 0x1e, 0x6a,
-	0x35, 0x94, 0x24, 0x33, 0xd1, 0x62, 0xd9, 0x86, 0x68, 0xee, 0x18, 0x13, 0x76, 0x95, 0x23, 0xc0,
-	0x1c, 0x38, 0x89, 0x3d, 0xac, 0x97, 0x20, 0xa3, 0x5b, 0x26, 0xb6, 0x89, 0xaa, 0x19, 0x86, 0xff,
-	0x78, 0x0c, 0x9c, 0x54, 0x35, 0x0c, 0xb7, 0xfc, 0x17, 0x51, 0x28, 0xac, 0xce, 0x5e, 0x2c, 0xc5,
-	0x1e, 0xe4, 0xe8, 0xdc, 0x83, 0x9a, 0x59, 0xcc, 0xee, 0xbd, 0x37, 0x39, 0x4d, 0xac, 0x46, 0xe6,
-	0x35, 0xd1, 0x51, 0xb2, 0x4e, 0xa8, 0x77, 0xa1, 0x17, 0x5f, 0x42, 0x36, 0xac, 0x81, 0x1e, 0x43,
-	0x82, 0x1e, 0xd3, 0x67, 0x9e, 0xa8, 0xc4, 0xbe, 0x76, 0xc5, 0xe7, 0xfa, 0x4c, 0x58, 0x11, 0x4a,
-	0xa1, 0x58, 0x44, 0x2e, 0x8f, 0x45, 0xf9, 0x87, 0xec, 0xbf, 0x1e, 0x4c, 0x29, 0x01, 0x91, 0xee,
-	0x33, 0x79, 0x0d, 0x6d, 0xc2, 0x7a, 0xff, 0xe3, 0xaa, 0xd2, 0x50, 0x3b, 0xdd, 0x81, 0x7a, 0xd0,
-	0x3d, 0xea, 0x34, 0x64, 0x89, 0xe2, 0x7e, 0xa7, 0xab, 0x72, 0x7a, 0x4f, 0x69, 0x1d, 0x56, 0x95,
-	0xe7, 0x72, 0x04, 0xdd, 0x82, 0x0d, 0x2a, 0xb4, 0x4c, 0x8e, 0xd2, 0xc4, 0xd1, 0xea, 0x0c, 0x9a,
-	0x4a, 0xa7, 0xda, 0x56, 0x9b, 0x8a, 0xd2, 0x55, 0xe4, 0x18, 0xda, 0x80, 0x1c, 0x17, 0xeb, 0x0f,
-	0xba, 0xbd, 0x5e, 0xb3, 0x21, 0xc7, 0xd1, 0x2d, 0x90, 0x7b, 0x5d, 0x65, 0xc0, 0xbe, 0x53, 0x6d,
-	0xb7, 0xbb, 0x9f, 0x34, 0x1b, 0xf2, 0xaf, 0x92, 0xef, 0x76, 0x20, 0x13, 0x42, 0x73, 0x9a, 0x92,
-	0xea, 0xd5, 0x41, 0xb5, 0xdd, 0x7d, 0x22, 0xaf, 0xa1, 0x75, 0xc8, 0x3c, 0xed, 0x77, 0x3b, 0x6a,
-	0xbf, 0xfe, 0x71, 0xf3, 0xb0, 0x2a, 0x4b, 0xec, 0x0f, 0x15, 0x2c, 0x7f, 0xc9, 0x31, 0x3a, 0x9a,
-	0x46, 0xb7, 0x7e, 0x74, 0xd8, 0xec, 0x0c, 0xfa, 0xea, 0x41, 0xeb, 0xbb, 0x03, 0x9a, 0xd3, 0xe2,
-	0xfb, 0xff, 0x24, 0x41, 0x92, 0x96, 0xb0, 0xa6, 0x3d, 0x46, 0xdf, 0x06, 0x58, 0x20, 0x35, 0x12,
-	0x7f, 0x85, 0x59, 0x01, 0xf4, 0x62, 0x61, 0x95, 0x21, 0x02, 0x53, 0x83, 0x4c, 0x08, 0x95, 0x91,
-	0x10, 0x5c, 0xc5, 0xf9, 0xe2, 0xdd, 0x0b, 0x38, 0xc2, 0xc6, 0x87, 0x90, 0xe0, 0xd8, 0x85, 0x36,
-	0x97, 0x91, 0x8c, 0x6b, 0x6e, 0x5d, 0x04, 0x6f, 0xfb, 0xdf, 0x83, 0x6c, 0x38, 0xf2, 0xe8, 0x29,
-	0xc4, 0x79, 0xe3, 0xde, 0xa5, 0xdb, 0xb7, 0xb8, 0x7d, 0xf9, 0xca, 0xd9, 0x95, 0x3e, 0x90, 0x6a,
-	0x8f, 0x3e, 0xff, 0xcf, 0xed, 0xb5, 0xcf, 0x7f, 0xb1, 0x2d, 0xfd, 0xfc, 0x17, 0xdb, 0xd2, 0x67,
-	0xff, 0xb5, 0x2d, 0x7d, 0xef, 0x9d, 0xb1, 0x49, 0x8e, 0x67, 0xc3, 0x8a, 0xee, 0x4c, 0xf6, 0xb0,
-	0x47, 0x66, 0x9a, 0x3b, 0xe7, 0x7f, 0xb8, 0x5b, 0xf9, 0x0b, 0xde, 0x30, 0xc1, 0xfa, 0x1f, 0xfe,
-	0x7f, 0x00, 0x00, 0x00, 0xff, 0xff, 0xe7, 0xa6, 0x88, 0x39, 0x9e, 0x27, 0x00, 0x00,
+	// 3613 bytes of a gzipped FileDescriptorProto
+	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xd5, 0x5a, 0xcd, 0x6f, 0x1b, 0xc7,
+	0x15, 0x37, 0xbf, 0xc9, 0x47, 0x8a, 0xa2, 0x46, 0xfe, 0x90, 0xd9, 0x44, 0x72, 0xd8, 0x06, 0x71,
+	0xe2, 0x84, 0x4a, 0x64, 0x34, 0x4d, 0x1c, 0xb8, 0x01, 0xbf, 0x64, 0xd3, 0xa1, 0x48, 0x76, 0x49,
+	0xc5, 0x71, 0x80, 0x82, 0x58, 0x92, 0x4b, 0x6a, 0x6d, 0x92, 0xcb, 0xee, 0x2e, 0x65, 0x29, 0x97,
+	0x16, 0x45, 0x81, 0x02, 0x41, 0x03, 0xe4, 0x52, 0x34, 0xb7, 0xa4, 0x05, 0x5a, 0xe4, 0x5f, 0x68,
+	0x7b, 0x29, 0xd0, 0x4b, 0x4e, 0x45, 0x80, 0x1e, 0x0a, 0xf4, 0x90, 0xa0, 0xed, 0x21, 0xb9, 0xb4,
+	0xbd, 0x16, 0x3d, 0xf5, 0xcd, 0x9b, 0xd9, 0xe5, 0x52, 0x94, 0x25, 0x2a, 0x49, 0x0f, 0x39, 0x48,
+	0xd8, 0x79, 0xf3, 0xde, 0x9b, 0x99, 0xf7, 0xe6, 0xfd, 0xde, 0x9b, 0x19, 0xc2, 0x63, 0x7d, 0x63,
+	0x73, 0x6c, 0x1a, 0xb6, 0xd1, 0x31, 0x06, 0xd6, 0x66, 0x6f, 0x60, 0x3c, 0xa4, 0x7f, 0x59, 0xa2,
+	0xb1, 0x20, 0xff, 0x4e, 0xaf, 0xb7, 0x4d, 0xe3, 0x81, 0x66, 0xba, 0x7c, 0xee, 0x87, 0xe0, 0x4a,
+	0x5f, 0xe9, 0x18, 0x23, 0x6b, 0x32, 0x3c, 0x81, 0xe3, 0x7c, 0xdf, 0xe8, 0x1b, 0xf4, 0xb9, 0xc9,
+	0xbf, 0x24, 0x75, 0xa3, 0x6f, 0x18, 0xfd, 0x81, 0x26, 0x98, 0xdb, 0x93, 0xde, 0xa6, 0xad, 0x0f,
+	0x35, 0xcb, 0x56, 0x87, 0x63, 0xc1, 0x90, 0xb9, 0x09, 0xa1, 0xc6, 0x40, 0xef, 0x68, 0xec, 0x3c,
+	0x84, 0xda, 0x5a, 0x5f, 0x1f, 0xad, 0xf9, 0xae, 0xf8, 0xae, 0x2e, 0x29, 0xa2, 0xc1, 0x52, 0x10,
+	0xd0, 0x46, 0xdd, 0x35, 0x3f, 0xd1, 0xf8, 0xe7, 0x8d, 0xc4, 0xc7, 0xbf, 0xdc, 0x38, 0xf7, 0xee,
+	0xaf, 0x36, 0xce, 0xbd, 0x87, 0x7f, 0x99, 0x11, 0xc4, 0x76, 0x77, 0xcb, 0xc5, 0xba, 0x6a, 0xda,
+	0x16, 0x63, 0x10, 0x1c, 0x19, 0x5d, 0x8d, 0x34, 0x84, 0x15, 0xfa, 0x66, 0xaf, 0x40, 0xa8, 0x33,
+	0x30, 0x3a, 0x0f, 0x48, 0x45, 0x38, 0xff, 0xe4, 0x7f, 0x3f, 0xd9, 0x78, 0x02, 0x67, 0xd7, 0x57,
+	0xdf, 0xd2, 0x6c, 0x5b, 0xcb, 0x76, 0xb5, 0xfd, 0xcd, 0x8e, 0x61, 0x6a, 0x9b, 0x38, 0x2b, 0x4b,
+	0xed, 0x6b, 0xd9, 0x02, 0x67, 0x56, 0x84, 0xcc, 0x8d, 0xd4, 0xe7, 0x1f, 0x6c, 0xf8, 0x66, 0xc6,
+	0x7b, 0xcf, 0x0f, 0x50, 0x37, 0x8d, 0xfb, 0x5a, 0xc7, 0xd6, 0x0d, 0x9a, 0xde, 0xd8, 0x36, 0x69,
+	0xc0, 0x98, 0xc2, 0x3f, 0xf9, 0x32, 0x7a, 0xba, 0x36, 0x10, 0x53, 0x8e, 0x29, 0xa2, 0xc1, 0xd2,
+	0x10, 0xd5, 0x0e, 0xc6, 0xb8, 0x4e, 0xdd, 0x5e, 0x0b, 0x60, 0x47, 0x54, 0x71, 0xdb, 0xec, 0x2a,
+	0xa4, 0x74, 0xab, 0x35, 0xc6, 0x15, 0xe8, 0x5c, 0x67, 0xeb, 0x81, 0x76, 0xb8, 0x16, 0x24, 0x9e,
+	0xa4, 0x6e, 0xd5, 0x1d, 0xf2, 0x6b, 0xda, 0x21, 0xfb, 0x16, 0x24, 0x39, 0xa7, 0xa9, 0x0f, 0x55,
+	0xf3, 0x90, 0xf8, 0x42, 0xc4, 0x97, 0x40, 0x3e, 0x41, 0xe4, 0x5c, 0xd7, 0x21, 0xa6, 0x8f, 0x7a,
+	0x9a, 0xa9, 0x8d, 0x3a, 0xda, 0x5a, 0x18, 0x19, 0xe2, 0x5b, 0xcb, 0x59, 0x72, 0x78, 0xd9, 0x21,
+	0xe7, 0x83, 0x1f, 0x7d, 0xb2, 0x71, 0x4e, 0x99, 0xf2, 0xb1, 0x1c, 0x2c, 0xe3, 0x84, 0x4c, 0x34,
+	0x02, 0x9f, 0xc2, 0x7d, 0xcb, 0x18, 0xad, 0x45, 0xf8, 0x02, 0xf2, 0x8f, 0xa1, 0xc1, 0xd6, 0x90,
+	0xc5, 0xe8, 0xea, 0xa3, 0xfe, 0x26, 0xef, 0xc8, 0x2a, 0xea, 0xc3, 0x1d, 0x61, 0x2d, 0x05, 0xa6,
+	0x42, 0x99, 0x0f, 0x43, 0x10, 0x73, 0x47, 0xe0, 0x76, 0xb0, 0x0f, 0xc7, 0x9a, 0x85, 0xb6, 0x09,
+	0x70, 0x3b, 0x50, 0x83, 0x65, 0x21, 0x6c, 0xd9, 0x26, 0x6a, 0x22, 0x2b, 0xc4, 0xb7, 0x2e, 0x1e,
+	0x99, 0x58, 0xb6, 0x41, 0xbd, 0x8a, 0xe4, 0x22, 0x2d, 0xba, 0x3d, 0xd0, 0xc8, 0x20, 0x5c, 0x0b,
+	0x6f, 0xb0, 0x2b, 0x10, 0xef, 0x6a, 0x56, 0xc7, 0xd4, 0xc7, 0xdc, 0x32, 0x64, 0x84, 0x98, 0xe2,
+	0x25, 0xa1, 0xd7, 0x13, 0x5d, 0xad, 0xa7, 0x4e, 0x06, 0xb6, 0x58, 0x4b, 0x78, 0x81, 0xb5, 0x44,
+	0xa4, 0x04, 0xbb, 0x88, 0x93, 0xd4, 0x3a, 0xa6, 0x66, 0x93, 0x09, 0xa2, 0x8a, 0x6c, 0xf1, 0xc9,
+	0x6b, 0x07, 0xba, 0x65, 0x5b, 0x6b, 0x51, 0xa4, 0x27, 0xe7, 0x27, 0x5f, 0xa2, 0x5e, 0x45, 0x72,
+	0xb1, 0x17, 0x20, 0x32, 0xe2, 0x21, 0xa3, 0x77, 0xd6, 0x62, 0xb4, 0xda, 0x4b, 0x47, 0x05, 0xaa,
+	0xa2, 0x5b, 0x71, 0xf8, 0xd2, 0xef, 0xfb, 0x20, 0x2c, 0x4c, 0xc0, 0x9e, 0x80, 0x04, 0xc6, 0x9c,
+	0xad, 0x8d, 0xec, 0x16, 0xb7, 0x1d, 0x19, 0x0c, 0x57, 0x29, 0x69, 0x4d, 0x24, 0xf1, 0x89, 0xf6,
+	0x0c, 0x73, 0xa8, 0xda, 0xd2, 0x3c, 0xb2, 0xc5, 0x9e, 0x86, 0x94, 0x23, 0xea, 0xac, 0x56, 0x78,
+	0x53, 0x59, 0x96, 0xf4, 0x92, 0x24, 0xb3, 0xc7, 0x01, 0x86, 0xea, 0x41, 0x6b, 0xa0, 0x8d, 0xfa,
+	0xf6, 0x1e, 0x99, 0x69, 0x49, 0x89, 0x21, 0xa5, 0x42, 0x84, 0x3b, 0xc1, 0xa8, 0x2f, 0xe5, 0xc7,
+	0xff, 0xfe, 0x54, 0x00, 0xff, 0x87, 0x52, 0xe1, 0xf4, 0x0f, 0x21, 0x22, 0x67, 0xcd, 0x36, 0x20,
+	0xbe, 0xa7, 0x5a, 0xad, 0xa1, 0x3e, 0xd2, 0x87, 0x93, 0x21, 0x05, 0x41, 0x54, 0x01, 0x24, 0xed,
+	0x08, 0x0a, 0x5b, 0x83, 0x88, 0xd3, 0xc9, 0xa3, 0xc1, 0xa7, 0x38, 0x4d, 0x57, 0x54, 0x3d, 0xa0,
+	0xde, 0xc0, 0x54, 0x54, 0x50, 0x48, 0x54, 0x76, 0x06, 0xa5, 0xa8, 0x68, 0x66, 0xf2, 0x10, 0x16,
+	0x76, 0x66, 0x71, 0x88, 0x94, 0xab, 0xaf, 0xe7, 0x2a, 0xe5, 0x62, 0xea, 0x1c, 0x8b, 0x42, 0x70,
+	0x67, 0xb7, 0xd1, 0x4c, 0xf9, 0x58, 0x04, 0x02, 0x3b, 0xb9, 0x7b, 0x29, 0x3f, 0x4b, 0x40, 0xb4,
+	0xbc, 0x53, 0xaf, 0x94, 0x0b, 0xe5, 0x66, 0x2a, 0xc0, 0x00, 0xc2, 0x85, 0x5c, 0xb5, 0x5a, 0x6b,
+	0xa6, 0x82, 0x99, 0x7b, 0x10, 0xaf, 0x6a, 0xf6, 0x43, 0xc3, 0x7c, 0x50, 0x37, 0x4c, 0x72, 0x38,
+	0x3a, 0xa0, 0xad, 0x99, 0x12, 0x7b, 0x64, 0x8b, 0x47, 0xad, 0x03, 0x72, 0x32, 0x9c, 0xdd, 0x36,
+	0x97, 0x19, 0x4f, 0xda, 0x18, 0xc1, 0x72, 0xf2, 0xb2, 0x95, 0x79, 0x67, 0x19, 0x92, 0x05, 0x63,
+	0x30, 0x10, 0x00, 0xd1, 0x18, 0x6b, 0x1d, 0x96, 0x41, 0x58, 0x52, 0x87, 0x02, 0x96, 0x62, 0xf9,
+	0x24, 0x6e, 0x42, 0x98, 0x72, 0x28, 0xd4, 0xc7, 0x8a, 0xb0, 0xf2, 0xd0, 0xd4, 0x6d, 0xad, 0x65,
+	0x75, 0xf6, 0xb4, 0xa1, 0x2a, 0x76, 0x6d, 0x74, 0x81, 0x5d, 0x1b, 0x27, 0xb1, 0x06, 0x49, 0xb1,
+	0x3c, 0xa4, 0x4c, 0x4d, 0xed, 0xce, 0x28, 0x89, 0x2f, 0x12, 0xc6, 0x5c, 0x4a, 0xea, 0x40, 0x48,
+	0xe3, 0xc8, 0x12, 0xa0, 0xb0, 0xe5, 0x9f, 0xec, 0x32, 0x44, 0x27, 0x13, 0xbd, 0xdb, 0xe2, 0x48,
+	0x27, 0x36, 0x5a, 0x84, 0xb7, 0xeb, 0x88, 0x76, 0xb8, 0xd3, 0xa6, 0xc0, 0x45, 0x50, 0x67, 0x61,
+	0x38, 0x72, 0xc9, 0x65, 0x97, 0xbe, 0x4d, 0x64, 0xf6, 0x12, 0xc4, 0xc7, 0x2e, 0x70, 0x5a, 0xb8,
+	0xd5, 0x02, 0x18, 0x11, 0x29, 0x11, 0x11, 0x53, 0x44, 0x95, 0xc8, 0xe4, 0x65, 0xe5, 0xb6, 0x51,
+	0x3b, 0x0f, 0x5a, 0xb6, 0x36, 0x1c, 0x0f, 0x54, 0x34, 0xd1, 0xc2, 0xe8, 0x14, 0x47, 0xb1, 0xa6,
+	0x94, 0x42, 0x2d, 0x6c, 0x3a, 0x55, 0x47, 0x97, 0x0c, 0xcc, 0x0b, 0x59, 0x37, 0x99, 0xdd, 0x31,
+	0x26, 0xe6, 0x48, 0x1d, 0x70, 0xc7, 0x29, 0x2b, 0xae, 0x80, 0xab, 0xe5, 0x55, 0x80, 0x2e, 0x6e,
+	0xfe, 0x7d, 0x95, 0x90, 0x27, 0x41, 0xd2, 0x1b, 0x62, 0x11, 0xb3, 0x5e, 0xcf, 0x16, 0x5d, 0x36,
+	0xc5, 0x23, 0x92, 0xfe, 0x27, 0x00, 0x4c, 0xbb, 0x58, 0x13, 0x92, 0x18, 0x92, 0x23, 0x94, 0x33,
+	0x4c, 0x11, 0xe7, 0x3e, 0xc2, 0x96, 0xe7, 0x4e, 0xd1, 0x89, 0x3d, 0x52, 0x8a, 0x23, 0x81, 0xb2,
+	0xd4, 0xf1, 0x36, 0xd9, 0xcb, 0xc0, 0x71, 0xa2, 0xa7, 0xf7, 0x85, 0xad, 0xfc, 0x0b, 0xd8, 0x2a,
+	0x2c, 0x04, 0x58, 0x0d, 0xc0, 0x36, 0xd5, 0x91, 0xc5, 0xa1, 0xc4, 0xa2, 0x5d, 0x10, 0xdf, 0x7a,
+	0xfa, 0xb4, 0xc9, 0x34, 0x1d, 0x09, 0xe9, 0x3e, 0x8f, 0x0a, 0x76, 0x17, 0x56, 0xac, 0xbd, 0x49,
+	0xaf, 0x37, 0xd0, 0x78, 0xc6, 0x6a, 0x89, 0xa4, 0x10, 0x44, 0xbd, 0xc9, 0xad, 0x6b, 0xa7, 0xe9,
+	0x6d, 0x08, 0x41, 0x5a, 0xe2, 0xb2, 0xd4, 0x82, 0x29, 0xae, 0x49, 0xb9, 0xe4, 0x06, 0x24, 0xad,
+	0x3d, 0xd5, 0xec, 0x4e, 0x9d, 0x19, 0x22, 0x77, 0xac, 0x66, 0x9d, 0x5a, 0x05, 0xe5, 0xb1, 0x9f,
+	0x5c, 0xb9, 0x44, 0xac, 0xae, 0x1b, 0xcb, 0x70, 0xc1, 0xd4, 0x3a, 0xc6, 0xbe, 0x86, 0x79, 0x74,
+	0x60, 0xf4, 0xa7, 0x2a, 0xc2, 0x27, 0xed, 0x87, 0x55, 0x47, 0xa6, 0x62, 0xf4, 0x5d, 0x55, 0x2f,
+	0xc2, 0xd2, 0x48, 0x60, 0x49, 0x6b, 0x8c, 0x60, 0x62, 0xe1, 0xce, 0xe4, 0x36, 0x5b, 0x11, 0x6b,
+	0xf3, 0xc0, 0x8c, 0x92, 0x18, 0x4d, 0x1b, 0x56, 0xfa, 0x7d, 0x4c, 0x97, 0xae, 0xdd, 0x10, 0xed,
+	0xbd, 0x18, 0xb1, 0x84, 0xae, 0x9a, 0x76, 0x4a, 0x88, 0xb8, 0x01, 0xd0, 0x71, 0x2d, 0x45, 0x3e,
+	0x8d, 0x6f, 0x9d, 0x3f, 0xce, 0x82, 0x8e, 0x13, 0xa6, 0xdc, 0xac, 0xe2, 0xdd, 0xfc, 0x96, 0x36,
+	0xa0, 0xad, 0x22, 0x73, 0xf0, 0xa5, 0xe9, 0x62, 0x2b, 0x6a, 0x5b, 0x1b, 0x34, 0x64, 0xb7, 0x54,
+	0x33, 0x0d, 0x02, 0xa7, 0x43, 0xe0, 0xa2, 0x6e, 0x20, 0xf0, 0x88, 0x4a, 0x65, 0x49, 0x71, 0xdb,
+	0xec, 0x59, 0x60, 0x04, 0x41, 0x5d, 0x6d, 0xa0, 0x1e, 0xe2, 0x50, 0xe8, 0x0b, 0xc2, 0x04, 0xce,
+	0x45, 0xe0, 0x54, 0xe4, 0x1d, 0x0d, 0x41, 0xe7, 0x79, 0xc0, 0xb3, 0x39, 0x08, 0x14, 0x62, 0x0a,
+	0x4c, 0x3d, 0xcd, 0x76, 0x21, 0xed, 0x30, 0x0c, 0xd4, 0x61, 0xbb, 0xab, 0xb6, 0xbc, 0x1b, 0x7b,
+	0x11, 0x10, 0x58, 0x95, 0xf2, 0x15, 0x12, 0x2f, 0x88, 0x5d, 0xbe, 0x0d, 0xec, 0x18, 0x75, 0x8b,
+	0xe0, 0x6d, 0x62, 0xe0, 0xd5, 0xf3, 0x0d, 0x88, 0xd1, 0x6a, 0x8d, 0xd1, 0xe0, 0x90, 0xb0, 0x04,
+	0x0b, 0x3b, 0x4e, 0xa8, 0x61, 0x1b, 0xeb, 0x85, 0xd5, 0xfb, 0x62, 0xf7, 0xb4, 0x04, 0x2a, 0xe3,
+	0x3c, 0xf4, 0x83, 0x35, 0x20, 0x08, 0x5d, 0x91, 0x5d, 0x0a, 0x47, 0x5e, 0xea, 0xc0, 0xa8, 0x85,
+	0x91, 0x61, 0xb7, 0xda, 0x1a, 0x3a, 0x5d, 0x23, 0xdc, 0x8e, 0x6f, 0xa5, 0xb3, 0xa2, 0x80, 0xce,
+	0x3a, 0x05, 0x74, 0xb6, 0xe9, 0x14, 0xd0, 0x4a, 0x0c, 0xb9, 0xf3, 0xc4, 0xcc, 0xbe, 0x03, 0xbc,
+	0xd1, 0x52, 0x7b, 0x36, 0x26, 0xb1, 0xc4, 0xa9, 0x92, 0x51, 0x64, 0xce, 0x71, 0x5e, 0xee, 0xca,
+	0x36, 0xa2, 0x64, 0x4f, 0x1f, 0x0c, 0xd6, 0x96, 0x84, 0x2b, 0x9d, 0x76, 0xc6, 0x86, 0xa5, 0x19,
+	0x94, 0x41, 0xe6, 0x8b, 0x32, 0xe1, 0xb6, 0x0a, 0xb5, 0x6a, 0xb5, 0x54, 0x68, 0xd6, 0x94, 0x56,
+	0xf3, 0x5e, 0xbd, 0x84, 0xf9, 0x17, 0xd3, 0x6b, 0xe3, 0x7b, 0x95, 0x72, 0xb3, 0x84, 0x19, 0x38,
+	0x09, 0xc0, 0xa9, 0x8d, 0x82, 0x52, 0xae, 0x37, 0x31, 0x11, 0xc7, 0x20, 0x54, 0xde, 0xc9, 0xdd,
+	0x2a, 0x61, 0x16, 0xc6, 0xcf, 0x4a, 0xad, 0x90, 0xab, 0xa4, 0x82, 0x3c, 0x63, 0xdf, 0xcd, 0x35,
+	0x76, 0x52, 0x21, 0x9e, 0xc8, 0x77, 0x72, 0xf5, 0x7a, 0xb9, 0x7a, 0x2b, 0x15, 0xce, 0xec, 0x40,
+	0xdc, 0x13, 0xf6, 0x58, 0x08, 0x9c, 0x77, 0xc6, 0x6c, 0xdc, 0xde, 0xdd, 0xde, 0xae, 0x94, 0x9c,
+	0x11, 0x51, 0x2a, 0x5f, 0xab, 0x55, 0x4a, 0xb9, 0x2a, 0x0e, 0x49, 0xb5, 0x40, 0xb3, 0x74, 0xab,
+	0xa4, 0xe0, 0x78, 0x7c, 0x2e, 0x4d, 0x85, 0xab, 0x0b, 0x64, 0x7e, 0xe1, 0x87, 0x24, 0x65, 0x20,
+	0xb1, 0x7b, 0x79, 0x30, 0xe0, 0x31, 0x01, 0x37, 0x9b, 0x53, 0x99, 0xd2, 0x37, 0x4f, 0xe7, 0xfb,
+	0xea, 0x60, 0x82, 0xd0, 0xe4, 0x27, 0xaa, 0x6c, 0x71, 0xfb, 0x74, 0x8d, 0x0e, 0x82, 0xc9, 0xc8,
+	0x96, 0x15, 0x98, 0xdb, 0x66, 0xfb, 0x70, 0x81, 0x52, 0x9e, 0x77, 0x0f, 0x61, 0x49, 0x33, 0x26,
+	0x74, 0x8b, 0x6f, 0x3d, 0x2b, 0x62, 0x73, 0x76, 0x70, 0xd1, 0x14, 0xbb, 0xe7, 0x0e, 0xf2, 0xef,
+	0xa8, 0xe3, 0xd2, 0xc8, 0x36, 0x0f, 0xf3, 0x8f, 0xbd, 0xfd, 0xe9, 0x49, 0x99, 0xac, 0x37, 0x15,
+	0x4b, 0x97, 0xe0, 0xd2, 0x23, 0xb4, 0x38, 0xc9, 0x5b, 0x9e, 0x47, 0x78, 0xf2, 0xc6, 0x0a, 0x9a,
+	0x96, 0xe2, 0x9c, 0x47, 0xa8, 0x71, 0xc3, 0xff, 0x92, 0x2f, 0xf3, 0x9f, 0x10, 0xc4, 0x0b, 0xea,
+	0xd8, 0x9e, 0x98, 0x1a, 0x95, 0x29, 0x1b, 0x33, 0x10, 0x14, 0xc7, 0x28, 0x88, 0xc8, 0x6e, 0x09,
+	0x40, 0xdb, 0x73, 0xb9, 0xca, 0x4f, 0xb9, 0xca, 0xc9, 0x7f, 0x53, 0x5d, 0x67, 0xca, 0x4e, 0x81,
+	0x33, 0x64, 0xa7, 0x6f, 0xe3, 0x76, 0xd5, 0x47, 0x9c, 0xc5, 0x92, 0x56, 0xbe, 0x3c, 0x3f, 0x78,
+	0x5e, 0x70, 0x28, 0x2e, 0x2b, 0x2f, 0x53, 0x74, 0xac, 0x7b, 0x4d, 0x5c, 0xfc, 0x11, 0x48, 0x5a,
+	0x76, 0xe8, 0x0e, 0x22, 0xcd, 0x67, 0x95, 0xf0, 0x97, 0xcf, 0x2a, 0x91, 0x2f, 0x9f, 0x55, 0xa2,
+	0x8b, 0x65, 0x95, 0x7f, 0xfb, 0x30, 0x2a, 0xc4, 0xb2, 0xd9, 0x1d, 0x38, 0x8f, 0x67, 0x33, 0x1c,
+	0xa9, 0xa3, 0xcd, 0xc0, 0x9c, 0x6f, 0x01, 0x83, 0x27, 0x1d, 0x49, 0x09, 0x74, 0xdf, 0x84, 0x25,
+	0x57, 0xd7, 0x58, 0xc5, 0xa3, 0x82, 0x08, 0x93, 0x84, 0x43, 0xac, 0x23, 0xed, 0x48, 0x86, 0x0a,
+	0x9c, 0x29, 0x43, 0x79, 0x81, 0x28, 0x38, 0x0b, 0x44, 0x1c, 0x65, 0x11, 0xb7, 0x6c, 0xcd, 0x3d,
+	0xf2, 0x62, 0x14, 0x12, 0x01, 0x33, 0x44, 0x66, 0xeb, 0x28, 0x4a, 0xcd, 0x1c, 0x0b, 0x5c, 0xe8,
+	0x89, 0x4c, 0xa1, 0x27, 0x9a, 0xf9, 0x0b, 0xc0, 0xea, 0x0e, 0xca, 0x9b, 0xba, 0x3a, 0xd0, 0xdf,
+	0x52, 0xdd, 0x4a, 0xfd, 0xa9, 0x99, 0x10, 0x58, 0x45, 0x0b, 0x2d, 0x1f, 0x61, 0x93, 0xa1, 0x50,
+	0x7d, 0x44, 0x28, 0x3c, 0x25, 0x56, 0x7b, 0x8c, 0xee, 0xff, 0x5b, 0x48, 0xdc, 0x9c, 0x0b, 0x89,
+	0x27, 0x1e, 0x3d, 0x89, 0xf9, 0xd0, 0xf8, 0x9a, 0x57, 0x51, 0x7f, 0x0a, 0x7f, 0x4d, 0xf7, 0xfb,
+	0xf1, 0x15, 0x59, 0xe4, 0x2b, 0xa8, 0xc8, 0x62, 0x47, 0x2a, 0xb2, 0x02, 0x2c, 0x8b, 0x34, 0x65,
+	0x39, 0x49, 0x88, 0x02, 0xcc, 0x9d, 0xea, 0x6c, 0x82, 0x92, 0x63, 0x24, 0x7b, 0xb3, 0x39, 0x13,
+	0xed, 0x81, 0x15, 0x9d, 0xad, 0xb6, 0x26, 0xe3, 0x2e, 0xfa, 0xcb, 0x72, 0x6e, 0x9e, 0x88, 0xb8,
+	0x2b, 0x68, 0xac, 0x0d, 0xac, 0xab, 0x8d, 0xd1, 0xbd, 0xd8, 0xc2, 0xe1, 0x44, 0x16, 0x97, 0x9b,
+	0xe1, 0xfa, 0xa9, 0x9b, 0x12, 0x0b, 0x7f, 0x47, 0x56, 0x16, 0x00, 0xca, 0x4a, 0xf7, 0x28, 0xe9,
+	0x51, 0x45, 0x55, 0x74, 0xb1, 0xa2, 0x0a, 0xbe, 0x70, 0x51, 0x15, 0xff, 0x82, 0x45, 0x55, 0xe2,
+	0x24, 0x2c, 0x5b, 0x9a, 0xc5, 0xb2, 0xf4, 0x8f, 0x7c, 0xb0, 0x32, 0x67, 0x05, 0x7e, 0x47, 0xd3,
+	0x37, 0x8d, 0xc9, 0xb8, 0x35, 0xc5, 0x26, 0x25, 0x46, 0x94, 0x2a, 0xc7, 0xa2, 0xaf, 0xb4, 0xb6,
+	0xcf, 0xdc, 0x5c, 0x0c, 0x4e, 0xa3, 0x53, 0x38, 0x8d, 0xf1, 0xcf, 0x62, 0xe9, 0xb5, 0xdc, 0x76,
+	0x0a, 0x32, 0xff, 0x8a, 0x41, 0xb8, 0x96, 0x9b, 0xd8, 0x7b, 0x5b, 0xf2, 0xf6, 0x64, 0x5f, 0xef,
+	0x6a, 0xce, 0x05, 0xa9, 0xdb, 0x66, 0xcf, 0xe0, 0x91, 0x1e, 0x99, 0x5a, 0x13, 0x73, 0x30, 0x45,
+	0x0d, 0x51, 0xa1, 0x2c, 0xf3, 0x8e, 0x5d, 0x73, 0xe0, 0x42, 0xc3, 0xcb, 0x70, 0x59, 0xed, 0x74,
+	0x30, 0x4e, 0x5b, 0xb6, 0xf1, 0x40, 0x1b, 0xcd, 0xca, 0x88, 0x9a, 0xec, 0xa2, 0x60, 0x68, 0xf2,
+	0x7e, 0xaf, 0x28, 0x6e, 0x96, 0x19, 0xd1, 0xa1, 0x66, 0xef, 0x19, 0x5d, 0x71, 0x25, 0xa2, 0xac,
+	0x78, 0x84, 0x76, 0xa8, 0x83, 0xa6, 0xe5, 0xe5, 0x6f, 0x1b, 0xdd, 0x43, 0x79, 0xe5, 0xb1, 0xec,
+	0xe1, 0xce, 0x23, 0x99, 0xfd, 0xc4, 0x07, 0x8f, 0xcf, 0x30, 0xef, 0xe1, 0xa6, 0xd3, 0x4c, 0x6b,
+	0x5a, 0x06, 0x86, 0x08, 0xc2, 0xe4, 0x21, 0x57, 0x18, 0x25, 0x9b, 0x9b, 0x6a, 0xb9, 0x2d, 0xf8,
+	0xcf, 0x50, 0x05, 0x32, 0x75, 0x4e, 0x9a, 0xbd, 0xed, 0x83, 0xf5, 0x99, 0x69, 0x20, 0x42, 0x8d,
+	0x11, 0xb9, 0xb5, 0xe9, 0x3c, 0xc2, 0xde, 0x72, 0x74, 0x7e, 0x1e, 0x8a, 0x14, 0x38, 0xc3, 0x44,
+	0xbc, 0xf6, 0x76, 0xc4, 0x51, 0x94, 0xbd, 0x02, 0x69, 0x53, 0xeb, 0xe1, 0x0c, 0xf6, 0x8e, 0xf3,
+	0x95, 0xb8, 0x82, 0xbc, 0x24, 0x39, 0xe6, 0x9c, 0xf5, 0x3c, 0x87, 0x6f, 0xaf, 0xb0, 0xf4, 0x56,
+	0x82, 0xc4, 0x98, 0x57, 0x4c, 0xba, 0x8b, 0xce, 0x9a, 0x5e, 0x09, 0xf2, 0x97, 0x80, 0x82, 0x94,
+	0x97, 0x9f, 0x1c, 0xf6, 0x53, 0xb4, 0xd4, 0x2c, 0xfb, 0x9c, 0xc7, 0x62, 0xc7, 0x58, 0x4a, 0xf1,
+	0xe8, 0x39, 0xbb, 0xcb, 0x56, 0xcd, 0x79, 0x71, 0xf6, 0x8e, 0x0f, 0x36, 0x66, 0x67, 0x32, 0xef,
+	0x34, 0xa0, 0xa9, 0x3c, 0xf7, 0xc8, 0xa9, 0x7c, 0x01, 0xaf, 0xcd, 0x58, 0xde, 0xe3, 0xb6, 0x74,
+	0x05, 0xd6, 0x4f, 0xde, 0x97, 0x67, 0x39, 0x57, 0xa4, 0x77, 0x60, 0xe3, 0x94, 0xdd, 0x75, 0x56,
+	0x75, 0xa7, 0xb8, 0xe0, 0x4c, 0xea, 0xaa, 0x70, 0xe5, 0x34, 0x33, 0x9e, 0xe9, 0x14, 0xf5, 0xdb,
+	0x00, 0x44, 0x9b, 0x98, 0x01, 0xa8, 0x7e, 0x64, 0xde, 0xfa, 0x51, 0x96, 0x8a, 0x9b, 0x10, 0xb2,
+	0x6c, 0x6d, 0x2c, 0x0e, 0x96, 0xbc, 0xae, 0x22, 0x8f, 0x3a, 0x22, 0xd9, 0x06, 0xf6, 0x49, 0x34,
+	0x16, 0x7c, 0xe9, 0xcf, 0xfc, 0x10, 0xe4, 0x54, 0x3c, 0xec, 0xc4, 0x38, 0xc5, 0x7b, 0x2d, 0xb8,
+	0x76, 0x8c, 0x74, 0x96, 0x0a, 0xca, 0x28, 0x67, 0x25, 0xc0, 0xc6, 0x74, 0x41, 0x62, 0x98, 0x55,
+	0xb5, 0x03, 0xf9, 0x72, 0x46, 0x8a, 0xca, 0x9c, 0x70, 0xf4, 0xf1, 0x24, 0x30, 0xff, 0x78, 0xe2,
+	0x28, 0xb0, 0x3a, 0xc6, 0xd8, 0x79, 0x79, 0x21, 0x05, 0x0d, 0x4e, 0x40, 0x50, 0xf5, 0x56, 0x3d,
+	0xa1, 0x63, 0x2f, 0xb5, 0xbd, 0x95, 0xce, 0xcb, 0x58, 0x3a, 0x18, 0x1d, 0x19, 0x65, 0xfb, 0x5a,
+	0x47, 0xdc, 0xf2, 0x9c, 0x52, 0x8f, 0x05, 0xb9, 0x08, 0xd6, 0xb6, 0xe0, 0x66, 0x28, 0x6b, 0xb1,
+	0xe2, 0xc8, 0x23, 0x90, 0x59, 0x87, 0x20, 0x59, 0x04, 0x20, 0x5c, 0xae, 0xde, 0x2a, 0x35, 0x9a,
+	0xe2, 0x9e, 0xe2, 0xf5, 0x92, 0x52, 0xde, 0xbe, 0x97, 0xf2, 0x65, 0x7e, 0xe1, 0x83, 0x98, 0xa2,
+	0x8e, 0xfa, 0xe2, 0xfc, 0x8b, 0x99, 0x99, 0x5f, 0x50, 0x8a, 0x47, 0x48, 0x6e, 0xb6, 0x88, 0x12,
+	0x45, 0x42, 0x9e, 0xde, 0x21, 0x2f, 0x41, 0x84, 0x77, 0xf2, 0xb7, 0xc8, 0x00, 0x75, 0x85, 0xb1,
+	0x59, 0x1a, 0x75, 0x59, 0x06, 0x0b, 0xc5, 0x16, 0xbd, 0x16, 0x4a, 0xc9, 0x20, 0x75, 0xc7, 0x4d,
+	0x7a, 0x48, 0x14, 0xc2, 0xeb, 0x10, 0x77, 0x78, 0xb8, 0x82, 0x10, 0x71, 0xc4, 0x04, 0x07, 0xea,
+	0xb8, 0x91, 0x7a, 0xef, 0x83, 0x8d, 0x73, 0x47, 0x9e, 0x35, 0x93, 0x6e, 0x16, 0x6e, 0xf0, 0xea,
+	0x80, 0xbf, 0x68, 0x89, 0xd2, 0xab, 0xbb, 0x78, 0x51, 0x1b, 0x91, 0x12, 0xfc, 0x9a, 0x0d, 0x0b,
+	0xf9, 0x3e, 0x95, 0xb2, 0x9d, 0x3d, 0x5a, 0x5c, 0x54, 0x01, 0x22, 0xd5, 0x39, 0x25, 0xf3, 0x1b,
+	0xb4, 0x44, 0x43, 0x33, 0xeb, 0xc6, 0x40, 0xef, 0xd0, 0x1d, 0x9e, 0x65, 0xe3, 0xb9, 0xc6, 0x9c,
+	0x8c, 0x78, 0x21, 0x22, 0x2b, 0x20, 0xf1, 0x36, 0x92, 0xc2, 0x9e, 0xa6, 0xec, 0x10, 0xd5, 0x0e,
+	0xe6, 0xe7, 0x11, 0xee, 0x47, 0x9c, 0x98, 0xd1, 0xbe, 0x7f, 0x54, 0x48, 0x6c, 0xbf, 0x8b, 0x82,
+	0xa1, 0xd6, 0xbe, 0x3f, 0x2b, 0x8a, 0x90, 0xaf, 0x9a, 0xa6, 0x7a, 0x78, 0x54, 0x2a, 0x40, 0x52,
+	0x8c, 0xfa, 0x66, 0x24, 0x32, 0xbf, 0xf6, 0x03, 0x94, 0x0e, 0x6c, 0x53, 0xed, 0xd8, 0xb9, 0x7a,
+	0x39, 0xfd, 0x7b, 0x1f, 0x84, 0x65, 0xc5, 0xee, 0x7d, 0xa5, 0xf0, 0xcd, 0xbe, 0x52, 0xdc, 0x84,
+	0xb8, 0xf7, 0x45, 0x64, 0x91, 0xeb, 0x70, 0x10, 0x02, 0x1c, 0x16, 0x78, 0x3c, 0x88, 0x02, 0x1a,
+	0x55, 0x5b, 0xf2, 0x61, 0x24, 0x46, 0x14, 0x54, 0x3e, 0xf7, 0xb0, 0x11, 0x5c, 0xf8, 0x61, 0x23,
+	0x73, 0x0f, 0x82, 0x05, 0xfe, 0x46, 0x3d, 0x53, 0x62, 0x2d, 0x41, 0xac, 0x50, 0xab, 0x6e, 0x97,
+	0x6f, 0xed, 0x2a, 0x25, 0x71, 0xb1, 0x55, 0x7a, 0xa3, 0xa9, 0xe4, 0x0a, 0xfc, 0x22, 0x8d, 0x41,
+	0x52, 0x36, 0x4a, 0xc5, 0x16, 0x7f, 0xf7, 0x4e, 0x05, 0x10, 0x9a, 0x52, 0x53, 0xda, 0x76, 0xb9,
+	0x54, 0x29, 0x36, 0x52, 0xc1, 0xcc, 0x67, 0x3e, 0x88, 0xe6, 0x27, 0xfa, 0xa0, 0xcb, 0xad, 0xf4,
+	0xd7, 0x19, 0x2b, 0xb5, 0x39, 0xb9, 0xa5, 0x77, 0x1d, 0x2b, 0x51, 0xbb, 0xdc, 0x9d, 0x76, 0x75,
+	0xdb, 0x12, 0xe6, 0x44, 0x57, 0xb1, 0x4d, 0x2f, 0xa2, 0x74, 0xec, 0x91, 0x70, 0x21, 0x5b, 0x6c,
+	0x0b, 0x0d, 0x2b, 0xce, 0x48, 0x84, 0x51, 0x41, 0xc2, 0xa8, 0x15, 0xe7, 0x04, 0xe4, 0x3e, 0x54,
+	0xa2, 0x35, 0x89, 0x8b, 0x82, 0xf1, 0x1a, 0xac, 0x4c, 0x8f, 0xce, 0xf2, 0x2c, 0x27, 0x0f, 0xf5,
+	0x29, 0xb7, 0x43, 0x1e, 0xf8, 0xf8, 0x23, 0xa8, 0x34, 0x58, 0xcb, 0x34, 0x0c, 0x5b, 0xbc, 0xe3,
+	0xba, 0x46, 0x54, 0x90, 0x94, 0x59, 0x85, 0x15, 0x04, 0x71, 0xcd, 0xa6, 0x30, 0x51, 0xb4, 0x1f,
+	0x4c, 0x70, 0xab, 0x65, 0xce, 0x03, 0xf3, 0x12, 0x05, 0xc6, 0x63, 0x6d, 0xcb, 0x72, 0xdd, 0x7d,
+	0x75, 0x84, 0x33, 0xc1, 0xaa, 0x5d, 0xf2, 0xe2, 0xa1, 0x7f, 0x59, 0x15, 0x54, 0xf7, 0x6e, 0x88,
+	0x5b, 0x26, 0xa8, 0x24, 0x25, 0x59, 0x5e, 0x0d, 0x65, 0x2e, 0xc0, 0xea, 0x8c, 0xb8, 0xd4, 0xfa,
+	0x12, 0x24, 0x8a, 0x08, 0x56, 0xb9, 0x51, 0x37, 0x7f, 0xc8, 0x4f, 0x41, 0x98, 0x04, 0x38, 0x78,
+	0xc9, 0x78, 0x11, 0x40, 0xc6, 0x7f, 0xdc, 0xc0, 0x3b, 0xc9, 0xb0, 0x41, 0x45, 0x34, 0x32, 0x3f,
+	0xf7, 0xc1, 0x52, 0x19, 0xe1, 0xc7, 0xb2, 0x9d, 0xb9, 0xcc, 0x62, 0xab, 0xef, 0x54, 0x6c, 0xf5,
+	0xba, 0xd3, 0x3f, 0xeb, 0xce, 0x39, 0xd8, 0x0d, 0x2c, 0x0a, 0xbb, 0x99, 0x3f, 0xfb, 0x21, 0xe9,
+	0xcc, 0x4b, 0x2c, 0x92, 0xfd, 0xce, 0x37, 0x3d, 0x77, 0x89, 0x87, 0x4a, 0x5e, 0x3c, 0x89, 0x3b,
+	0x54, 0xb7, 0xc6, 0x9d, 0x95, 0x71, 0x0e, 0xfd, 0x77, 0x39, 0x3b, 0x4f, 0xdb, 0x96, 0x28, 0x52,
+	0xbe, 0xff, 0xe3, 0x4f, 0x37, 0xae, 0x1d, 0xf7, 0x13, 0x8c, 0x23, 0x3f, 0x41, 0x71, 0xe4, 0xb1,
+	0xa6, 0x79, 0x66, 0x11, 0xf6, 0x5a, 0xaf, 0x87, 0x1e, 0x77, 0xcf, 0x80, 0xd3, 0x61, 0xd9, 0x77,
+	0x21, 0xe1, 0xcc, 0x5d, 0xb3, 0x3b, 0x5d, 0xf9, 0x76, 0xe2, 0xb9, 0x9e, 0x10, 0x45, 0x45, 0xb6,
+	0x84, 0x9d, 0x4e, 0x9c, 0x4a, 0x01, 0x4e, 0x4a, 0x17, 0xe1, 0xe2, 0xf1, 0x6b, 0x39, 0xad, 0x52,
+	0x08, 0x78, 0x2b, 0x85, 0x0f, 0xfd, 0x70, 0xa9, 0xa9, 0x5a, 0x0f, 0x9c, 0xcb, 0x0c, 0xd3, 0x38,
+	0x38, 0x74, 0xfc, 0xfe, 0x22, 0x04, 0x31, 0xb5, 0x0a, 0x8f, 0xc7, 0xb7, 0x32, 0x32, 0xcb, 0x1f,
+	0xcf, 0x9c, 0xad, 0x21, 0xa7, 0x42, 0xfc, 0xb4, 0xd7, 0x54, 0x5b, 0xa5, 0xc1, 0x12, 0x0a, 0x7d,
+	0xa7, 0xff, 0xe0, 0x83, 0x20, 0x67, 0x61, 0x57, 0x21, 0x2c, 0x2a, 0x5c, 0xa9, 0x36, 0x75, 0x74,
+	0xc1, 0x8a, 0xec, 0xc7, 0x23, 0x64, 0x54, 0x5c, 0x02, 0x39, 0xdb, 0x28, 0xff, 0x02, 0x6e, 0x93,
+	0xe7, 0x8e, 0xb3, 0xfa, 0xdc, 0xef, 0x80, 0xc4, 0xf5, 0x50, 0xb9, 0xa8, 0x44, 0x48, 0x45, 0x99,
+	0xb2, 0x8d, 0xad, 0x62, 0x6a, 0xb1, 0xe9, 0x2a, 0x47, 0x82, 0x39, 0x08, 0x12, 0xbd, 0xb7, 0x23,
+	0x43, 0x67, 0xa0, 0xf3, 0x9f, 0x27, 0xa8, 0xdd, 0xae, 0xf3, 0xa6, 0x0c, 0x82, 0x94, 0x43, 0x4a,
+	0xe6, 0x67, 0x01, 0x58, 0x9b, 0x5f, 0xbd, 0xdc, 0x8a, 0x75, 0x58, 0xe2, 0x6b, 0x77, 0x6b, 0x66,
+	0xb9, 0xba, 0x6b, 0x8f, 0x32, 0x9a, 0xdc, 0x8d, 0x64, 0x35, 0xd9, 0x50, 0x12, 0x86, 0xa7, 0x75,
+	0xac, 0x15, 0x1f, 0x42, 0xc2, 0x2b, 0x81, 0x39, 0x24, 0xcc, 0x8f, 0xe9, 0x13, 0x4b, 0x56, 0x62,
+	0x4f, 0x9e, 0x32, 0x5c, 0x83, 0x98, 0x15, 0x29, 0xe4, 0xf1, 0x85, 0xff, 0x64, 0x5f, 0x64, 0xde,
+	0xa5, 0x9f, 0x80, 0x90, 0x50, 0x18, 0xfc, 0xb5, 0xd7, 0x30, 0x25, 0xac, 0xc2, 0x72, 0xe3, 0x76,
+	0x4e, 0x29, 0xb6, 0xaa, 0xb5, 0x66, 0x6b, 0xbb, 0xb6, 0x5b, 0x2d, 0x62, 0x62, 0x40, 0xdc, 0xaf,
+	0xd6, 0x5a, 0x82, 0x5e, 0x57, 0xf0, 0x54, 0xae, 0xf0, 0xdf, 0x3c, 0x5c, 0x80, 0x15, 0xce, 0x34,
+	0x4b, 0x0e, 0xf0, 0xc4, 0xc1, 0x9f, 0x47, 0x94, 0x6a, 0xae, 0xd2, 0x2a, 0x29, 0x4a, 0x4d, 0x49,
+	0x05, 0xd9, 0x0a, 0x2c, 0x09, 0xb6, 0x46, 0xb3, 0x56, 0xaf, 0x97, 0x8a, 0xa9, 0x10, 0x4a, 0xa7,
+	0xea, 0x35, 0xa5, 0x49, 0xe3, 0xe4, 0x2a, 0x95, 0xda, 0x5d, 0xa4, 0x7e, 0x1e, 0x79, 0xa6, 0x0a,
+	0x71, 0x0f, 0x9a, 0xf3, 0x94, 0x54, 0xc8, 0x35, 0x73, 0x95, 0xda, 0x2d, 0x9c, 0xdb, 0x32, 0xc4,
+	0xef, 0x34, 0x6a, 0xd5, 0x56, 0xa3, 0x70, 0xbb, 0xb4, 0x93, 0xc3, 0x79, 0xf1, 0xdf, 0x59, 0x50,
+	0xfe, 0xc2, 0x21, 0x70, 0x36, 0xc5, 0x5a, 0x61, 0x77, 0xa7, 0x54, 0x6d, 0x36, 0x30, 0x37, 0xbd,
+	0xd1, 0xe4, 0x39, 0x2d, 0xb4, 0xf5, 0x47, 0x1f, 0x44, 0x78, 0x09, 0xcb, 0x2f, 0xed, 0x5e, 0x05,
+	0x98, 0x22, 0x35, 0x93, 0xbf, 0x90, 0x99, 0x03, 0xf4, 0xf4, 0xda, 0x7c, 0x87, 0x74, 0x4c, 0x1e,
+	0xe2, 0x1e, 0x54, 0x66, 0x92, 0x71, 0x1e, 0xe7, 0xd3, 0x97, 0x8f, 0xe9, 0x91, 0x3a, 0xae, 0x63,
+	0x81, 0x48, 0xd8, 0xc5, 0x56, 0x67, 0x91, 0x4c, 0x48, 0x9e, 0x3f, 0x0e, 0xde, 0xb6, 0xde, 0x84,
+	0x84, 0xd7, 0xf3, 0xec, 0x0e, 0x84, 0xc4, 0xc7, 0xe3, 0x27, 0x86, 0x6f, 0x7a, 0xfd, 0xe4, 0x9d,
+	0x73, 0xd5, 0xf7, 0xbc, 0x2f, 0xff, 0xca, 0x47, 0x7f, 0x5b, 0x3f, 0xf7, 0xd1, 0xdf, 0xd7, 0x7d,
+	0x1f, 0xe3, 0xdf, 0x07, 0xff, 0x58, 0xf7, 0xbd, 0xf9, 0x74, 0x5f, 0xb7, 0xf7, 0x26, 0xed, 0x6c,
+	0xc7, 0x18, 0x6e, 0xa2, 0x96, 0x89, 0x6a, 0x1e, 0x8a, 0x9f, 0xf2, 0xcd, 0xfd, 0xb8, 0xaf, 0x1d,
+	0xa6, 0xf6, 0xf5, 0xff, 0x01, 0x8c, 0x98, 0x7c, 0xc6, 0xf8, 0x27, 0x00, 0x00,
 }
 
 func (this *UUIDParts) Equal(that interface{}) bool {
@@ -2872,6 +2881,13 @@ func (m *Projection) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 		i -= len(m.XXX_unrecognized)
 		copy(dAtA[i:], m.XXX_unrecognized)
 	}
+	if len(m.ExpressionJson) > 0 {
+		i -= len(m.ExpressionJson)
+		copy(dAtA[i:], m.ExpressionJson)
+		i = encodeVarintFlow(dAtA, i, uint64(len(m.ExpressionJson)))
+		i--
+		dAtA[i] = 0x3a
+	}
 	{
 		size, err := m.Inference.MarshalToSizedBuffer(dAtA[:i])
 		if err != nil {
@@ -5186,6 +5202,10 @@ func (m *Projection) ProtoSize() (n int) {
 	}
 	l = m.Inference.ProtoSize()
 	n += 1 + l + sovFlow(uint64(l))
+	l = len(m.ExpressionJson)
+	if l > 0 {
+		n += 1 + l + sovFlow(uint64(l))
+	}
 	if m.XXX_unrecognized != nil {
 		n += len(m.XXX_unrecognized)
 	}
@@ -6543,6 +6563,38 @@ func (m *Projection) Unmarshal(dAtA []byte) error {
 				return err
 			}
 			iNdEx = postIndex
+		case 7:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ExpressionJson", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowFlow
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthFlow
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthFlow
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.ExpressionJson = encoding_json.RawMessage(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
 			skippy, err := skipFlow(dAtA[iNdEx:])
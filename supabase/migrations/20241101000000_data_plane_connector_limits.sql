@@ -0,0 +1,13 @@
+BEGIN;
+
+-- Upper bounds of the CPU and memory limits which tasks may request
+-- for their connector containers, enforced during publication validation.
+ALTER TABLE public.data_planes ADD connector_cpu_limit_max DOUBLE PRECISION NOT NULL DEFAULT 4;
+ALTER TABLE public.data_planes ADD connector_memory_limit_max BIGINT NOT NULL DEFAULT 8589934592; -- 8GB.
+
+COMMENT ON COLUMN public.data_planes.connector_cpu_limit_max IS
+  'Maximum number of CPUs which a task may request as the limit of its connector containers';
+COMMENT ON COLUMN public.data_planes.connector_memory_limit_max IS
+  'Maximum bytes of memory which a task may request as the limit of its connector containers';
+
+COMMIT;